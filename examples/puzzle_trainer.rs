@@ -0,0 +1,35 @@
+use chess::puzzles::{self, TrainingProfile};
+use std::error::Error;
+use std::io;
+use std::path::Path;
+use std::{env, fs};
+
+// Solve puzzles from a CSV file made by the puzzle generator:
+// cargo run --example puzzle_trainer -- puzzles.csv [profile.csv]
+fn main() -> Result<(), Box<dyn Error>> {
+    let arguments: Vec<String> = env::args().collect();
+    let puzzle_path = arguments
+        .get(1)
+        .ok_or("usage: puzzle_trainer <puzzles.csv> [profile.csv]")?;
+    let profile_path = Path::new(
+        arguments
+            .get(2)
+            .map_or("puzzle_profile.csv", String::as_str),
+    );
+
+    // Skip the puzzles that cannot be read, for example because the position is not valid
+    let puzzles: Vec<_> = puzzles::read_csv(&fs::read_to_string(puzzle_path)?)
+        .into_iter()
+        .filter_map(|puzzle| {
+            puzzle
+                .map_err(|error| eprintln!("Skipping puzzle: {}", error))
+                .ok()
+        })
+        .collect();
+    let mut profile = TrainingProfile::load(profile_path)?;
+
+    let result = puzzles::train(&puzzles, &mut profile, &mut io::stdin().lock(), &mut io::stdout());
+    profile.save(profile_path)?;
+
+    Ok(result?)
+}
@@ -17,7 +17,7 @@ fn main() -> Result<(), Box<dyn Error>> {
 
         // Just pick the first legal move
         // TODO: import Random crate
-        match legal_moves.into_iter().nth(0) {
+        match legal_moves.into_iter().next() {
             Some(legal_move) => {
                 println!("{}\n", legal_move);
                 board.make_move(legal_move)?;
@@ -0,0 +1,93 @@
+// Absolute imports within crate
+use crate::game::{Game, GameError, GameTree, NodeId};
+use crate::piece::Color;
+use crate::search::{is_same_move, Searcher};
+
+// Relative imports of sub modules
+pub use game_analysis::GameAnalysis;
+pub use move_analysis::MoveAnalysis;
+pub use move_classification::MoveClassification;
+mod game_analysis;
+mod move_analysis;
+mod move_classification;
+
+// Search every position of the game to the given depth in plies, and classify and annotate each move
+pub fn analyze(game: &Game, depth: u8) -> GameAnalysis {
+    let mut searcher = Searcher::new();
+    let mut tree = GameTree::from(game);
+    let mut moves = Vec::new();
+
+    // The search after a move is the search before the next move, so every position is only searched once
+    let mut before = searcher.search(game.initial_position(), depth);
+    let mut parent = tree.root();
+
+    for node in tree.mainline().into_iter().skip(1) {
+        let played_move = tree
+            .node(node)
+            .and_then(|node| node.played_move())
+            .expect("moves of the main line are part of the tree")
+            .clone();
+        let after = searcher.search(tree.node(node).expect("node is part of the tree").board(), depth);
+
+        let is_best = before
+            .best_move()
+            .is_some_and(|best_move| is_same_move(best_move, played_move.chess_move()));
+        let mut analysis = MoveAnalysis {
+            played_move,
+            classification: MoveClassification::Best,
+            score_before: before.score().clone(),
+            score_after: after.score().from_previous_ply(),
+            best_line: before.principal_variation().to_vec(),
+        };
+        if !is_best {
+            analysis.classification = MoveClassification::from_win_percent_loss(analysis.win_percent_loss());
+        }
+
+        annotate(&mut tree, parent, node, &analysis);
+        moves.push(analysis);
+        before = after;
+        parent = node;
+    }
+
+    GameAnalysis { moves, tree }
+}
+
+// Analyse a game written in portable game notation
+pub fn analyze_portable_game_notation(record: &str, depth: u8) -> Result<GameAnalysis, GameError> {
+    Ok(analyze(&Game::portable_game_notation(record)?, depth))
+}
+
+// Add the classification to a bad move, followed by the line the engine prefers as a variation
+fn annotate(tree: &mut GameTree, parent: NodeId, node: NodeId, analysis: &MoveAnalysis) {
+    let (nag, best_move) = match (analysis.classification().nag(), analysis.best_move()) {
+        (Some(nag), Some(best_move)) => (nag, best_move),
+        _ => {
+            return;
+        }
+    };
+    let best_move = tree
+        .node(parent)
+        .expect("parent is part of the tree")
+        .board()
+        .to_standard_algebraic_notation(best_move);
+
+    let annotation = tree.annotation_mut(node).expect("node is part of the tree");
+    annotation.add_nag(nag);
+    annotation.set_comment(Some(format!("{}. {} was best.", analysis.classification(), best_move)));
+
+    let mut current = parent;
+    for chess_move in analysis.best_line() {
+        current = tree
+            .add_move(current, chess_move.clone())
+            .expect("moves of the principal variation are legal");
+    }
+
+    // Evaluations in a game record are from the point of view of white
+    let score = match analysis.color() {
+        Color::White => analysis.score_before().clone(),
+        Color::Black => -analysis.score_before().clone(),
+    };
+    tree.annotation_mut(current)
+        .expect("node is part of the tree")
+        .set_comment(Some(score.to_string()));
+}
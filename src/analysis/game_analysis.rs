@@ -0,0 +1,58 @@
+// Absolute imports within crate
+use crate::game::GameTree;
+use crate::piece::Color;
+// Imports from parent
+use super::{MoveAnalysis, MoveClassification};
+
+// Analysis of every move of a game, with the game annotated by the engine
+#[derive(Debug, Clone)]
+pub struct GameAnalysis {
+    pub(super) moves: Vec<MoveAnalysis>,
+    pub(super) tree: GameTree,
+}
+
+impl GameAnalysis {
+    pub fn moves(&self) -> &[MoveAnalysis] {
+        &self.moves
+    }
+
+    // Game with the classifications as comments and glyphs, and the best line as a variation for bad moves
+    pub fn tree(&self) -> &GameTree {
+        &self.tree
+    }
+
+    pub fn to_portable_game_notation(&self) -> String {
+        self.tree.to_portable_game_notation()
+    }
+
+    // Average accuracy of the moves of the player from 0 to 100, which is 100 when they did not make any moves
+    pub fn accuracy(&self, color: &Color) -> f32 {
+        self.average(color, |analysis| analysis.accuracy())
+            .unwrap_or(100.0)
+    }
+
+    // Average number of centipawns the player lost per move
+    pub fn average_centipawn_loss(&self, color: &Color) -> f32 {
+        self.average(color, |analysis| analysis.centipawn_loss() as f32)
+            .unwrap_or(0.0)
+    }
+
+    // Number of moves of the player with the classification
+    pub fn count(&self, color: &Color, classification: &MoveClassification) -> usize {
+        self.moves_of(color)
+            .filter(|analysis| analysis.classification() == classification)
+            .count()
+    }
+
+    fn moves_of<'a>(&'a self, color: &'a Color) -> impl Iterator<Item = &'a MoveAnalysis> {
+        self.moves
+            .iter()
+            .filter(move |analysis| analysis.color() == color)
+    }
+
+    fn average(&self, color: &Color, value: impl Fn(&MoveAnalysis) -> f32) -> Option<f32> {
+        let values: Vec<f32> = self.moves_of(color).map(value).collect();
+
+        (!values.is_empty()).then(|| values.iter().sum::<f32>() / values.len() as f32)
+    }
+}
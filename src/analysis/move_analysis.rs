@@ -0,0 +1,69 @@
+// Absolute imports within crate
+use crate::board::ChessMove;
+use crate::game::PlayedMove;
+use crate::piece::Color;
+use crate::search::Score;
+// Imports from parent
+use super::MoveClassification;
+
+// Evaluation of a single move of a game, with the scores from the point of view of the player making the move
+#[derive(Debug, Clone)]
+pub struct MoveAnalysis {
+    pub(super) played_move: PlayedMove,
+    pub(super) classification: MoveClassification,
+    pub(super) score_before: Score,
+    pub(super) score_after: Score,
+    // Line the engine prefers in the position before the move
+    pub(super) best_line: Vec<ChessMove>,
+}
+
+impl MoveAnalysis {
+    pub fn played_move(&self) -> &PlayedMove {
+        &self.played_move
+    }
+
+    pub fn color(&self) -> &Color {
+        self.played_move.chess_move().piece().color()
+    }
+
+    pub fn classification(&self) -> &MoveClassification {
+        &self.classification
+    }
+
+    // Score of the best move
+    pub fn score_before(&self) -> &Score {
+        &self.score_before
+    }
+
+    // Score of the played move
+    pub fn score_after(&self) -> &Score {
+        &self.score_after
+    }
+
+    pub fn best_line(&self) -> &[ChessMove] {
+        &self.best_line
+    }
+
+    pub fn best_move(&self) -> Option<&ChessMove> {
+        self.best_line.first()
+    }
+
+    // Centipawns lost compared to the best move, which is never negative
+    // NOTE: mates count as a thousand centipawns, so a single move cannot dominate the average
+    pub fn centipawn_loss(&self) -> i32 {
+        let centipawns = |score: &Score| score.centipawns().clamp(-1_000, 1_000);
+
+        (centipawns(&self.score_before) - centipawns(&self.score_after)).max(0)
+    }
+
+    // Chance to win the game that was lost compared to the best move, from 0 to 100
+    pub fn win_percent_loss(&self) -> f32 {
+        (self.score_before.win_percent() - self.score_after.win_percent()).max(0.0)
+    }
+
+    // Accuracy of the move from 0 to 100
+    // Source: https://lichess.org/page/accuracy
+    pub fn accuracy(&self) -> f32 {
+        (103.1668 * (-0.04354 * self.win_percent_loss()).exp() - 3.1669).clamp(0.0, 100.0)
+    }
+}
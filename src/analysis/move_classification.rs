@@ -0,0 +1,52 @@
+// External imports
+use std::fmt;
+
+// Quality of a move, from the chance to win the game that was lost compared to the best move of the engine
+#[derive(Debug, PartialEq, Clone)]
+pub enum MoveClassification {
+    // The move the engine prefers
+    Best,
+    Excellent,
+    Good,
+    Inaccuracy,
+    Mistake,
+    Blunder,
+}
+
+impl MoveClassification {
+    // Classify a move that is not the best move by the loss in win percentage
+    pub fn from_win_percent_loss(loss: f32) -> MoveClassification {
+        match loss {
+            loss if loss < 2.0 => MoveClassification::Excellent,
+            loss if loss < 5.0 => MoveClassification::Good,
+            loss if loss < 10.0 => MoveClassification::Inaccuracy,
+            loss if loss < 20.0 => MoveClassification::Mistake,
+            _ => MoveClassification::Blunder,
+        }
+    }
+
+    // Numeric annotation glyph of the classification, only set for bad moves
+    pub fn nag(&self) -> Option<u8> {
+        match self {
+            MoveClassification::Inaccuracy => Some(6),
+            MoveClassification::Mistake => Some(2),
+            MoveClassification::Blunder => Some(4),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for MoveClassification {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            MoveClassification::Best => "Best move",
+            MoveClassification::Excellent => "Excellent",
+            MoveClassification::Good => "Good",
+            MoveClassification::Inaccuracy => "Inaccuracy",
+            MoveClassification::Mistake => "Mistake",
+            MoveClassification::Blunder => "Blunder",
+        };
+
+        write!(f, "{}", name)
+    }
+}
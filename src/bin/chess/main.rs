@@ -0,0 +1,134 @@
+// External imports
+use std::error::Error;
+use std::io::{self, BufRead, Write};
+use std::path::Path;
+use std::{env, fs, process};
+// Absolute imports within crate
+use chess::board::Board;
+#[cfg(feature = "tui")]
+use chess::clock::{Clock, TimeControl};
+#[cfg(feature = "tui")]
+use chess::game::Game;
+use chess::piece::Color;
+use chess::puzzles::{self, TrainingProfile};
+use chess::render::{Charset, Layout, TextRenderer};
+#[cfg(feature = "tui")]
+use chess::tui::{self, App};
+
+// Relative imports of sub modules
+use options::Options;
+use session::Session;
+mod options;
+mod session;
+
+const USAGE: &str =
+    "usage: chess [--fen <FEN>] [--engine <white|black>] [--depth <plies>] [--ascii] [--compact] [--colors]
+       chess --tui [--fen <FEN>] [--engine <white|black>] [--depth <plies>] [--time-control <300+2>]
+       chess --puzzles <puzzles.csv> [--profile <profile.csv>]";
+
+fn main() {
+    let options = match Options::parse(env::args().skip(1)) {
+        Ok(options) => options,
+        Err(error) => {
+            eprintln!("{}\n{}", error, USAGE);
+            process::exit(2);
+        }
+    };
+
+    if let Err(error) = run(options) {
+        eprintln!("{}", error);
+        process::exit(1);
+    }
+}
+
+fn run(options: Options) -> Result<(), Box<dyn Error>> {
+    let mut input = io::stdin().lock();
+    let mut output = io::stdout();
+
+    if options.help {
+        writeln!(output, "{}", USAGE)?;
+        return Ok(());
+    }
+
+    if let Some(puzzle_path) = &options.puzzles {
+        let profile_path = Path::new(options.profile.as_deref().unwrap_or("puzzle_profile.csv"));
+        let puzzles: Vec<_> = puzzles::read_csv(&fs::read_to_string(puzzle_path)?)
+            .into_iter()
+            .filter_map(|puzzle| {
+                puzzle
+                    .map_err(|error| eprintln!("Skipping puzzle: {}", error))
+                    .ok()
+            })
+            .collect();
+        let mut profile = TrainingProfile::load(profile_path)?;
+
+        let result = puzzles::train(&puzzles, &mut profile, &mut input, &mut output);
+        profile.save(profile_path)?;
+        return Ok(result?);
+    }
+
+    let board = match &options.fen {
+        Some(record) => Board::forsyth_edwards_notation(record)?,
+        None => Board::starting_position(),
+    };
+
+    if options.tui {
+        return run_tui(board, &options);
+    }
+
+    let renderer = TextRenderer::new()
+        .charset(if options.ascii {
+            Charset::Ascii
+        } else {
+            Charset::Unicode
+        })
+        .layout(if options.compact {
+            Layout::Compact
+        } else {
+            Layout::Boxed
+        })
+        .colors(options.colors)
+        .panel(true);
+    let mut session = Session::new(board, options.engine, options.depth, renderer);
+    play(&mut session, &mut input, &mut output)?;
+
+    Ok(())
+}
+
+#[cfg(feature = "tui")]
+fn run_tui(board: Board, options: &Options) -> Result<(), Box<dyn Error>> {
+    let mut game = Game::from_position(board);
+    if let Some(field) = &options.time_control {
+        game.set_clock(Clock::new(TimeControl::portable_game_notation(field)?));
+    }
+
+    let game = tui::run(App::new(game, options.engine.clone(), options.depth))?;
+    println!("{}", game.to_portable_game_notation());
+    Ok(())
+}
+
+#[cfg(not(feature = "tui"))]
+fn run_tui(_board: Board, _options: &Options) -> Result<(), Box<dyn Error>> {
+    Err("the terminal interface is not available, rebuild with --features tui".into())
+}
+
+// Read commands until the input ends or the player quits
+fn play(session: &mut Session, input: &mut impl BufRead, output: &mut impl Write) -> io::Result<()> {
+    writeln!(output, "Enter moves in SAN or UCI notation, or type help for all commands.")?;
+    session.engine_move(output)?;
+    session.show(output)?;
+
+    loop {
+        let color = match session.board().active_color() {
+            Color::White => "White",
+            Color::Black => "Black",
+        };
+        write!(output, "{}> ", color)?;
+        output.flush()?;
+
+        let mut line = String::new();
+        if input.read_line(&mut line)? == 0 || !session.execute(line.trim(), output)? {
+            return Ok(());
+        }
+    }
+}
@@ -0,0 +1,78 @@
+// Absolute imports within crate
+use chess::piece::Color;
+
+// Depth of the engine when it is not given, which takes about a second in a release build
+const DEFAULT_DEPTH: u8 = 3;
+
+// Options from the command line
+#[derive(Debug, Default)]
+pub struct Options {
+    pub help: bool,
+    pub fen: Option<String>,
+    // Color the engine plays, where both players are human without it
+    pub engine: Option<Color>,
+    pub depth: u8,
+    pub puzzles: Option<String>,
+    pub profile: Option<String>,
+    // Full-screen terminal interface instead of the command prompt
+    pub tui: bool,
+    // Value of the TimeControl tag of PGN, like 300+2
+    pub time_control: Option<String>,
+    // Style of the board in the command prompt
+    pub ascii: bool,
+    pub compact: bool,
+    pub colors: bool,
+}
+
+impl Options {
+    pub fn parse(mut arguments: impl Iterator<Item = String>) -> Result<Options, String> {
+        let mut options = Options {
+            depth: DEFAULT_DEPTH,
+            ..Options::default()
+        };
+
+        while let Some(argument) = arguments.next() {
+            let mut value = || {
+                arguments
+                    .next()
+                    .ok_or_else(|| format!("missing value for {}", argument))
+            };
+
+            match argument.as_str() {
+                "-h" | "--help" => options.help = true,
+                "--fen" => options.fen = Some(value()?),
+                "--engine" => {
+                    options.engine = match value()?.as_str() {
+                        "white" => Some(Color::White),
+                        "black" => Some(Color::Black),
+                        "none" => None,
+                        other => {
+                            return Err(format!("invalid engine color: {}", other));
+                        }
+                    }
+                }
+                "--depth" => {
+                    // The engine needs to search at least one ply to find a move
+                    let depth = value()?;
+                    options.depth = depth
+                        .parse()
+                        .ok()
+                        .filter(|depth| *depth >= 1)
+                        .ok_or_else(|| format!("invalid depth: {}", depth))?;
+                }
+                "--puzzles" => options.puzzles = Some(value()?),
+                "--profile" => options.profile = Some(value()?),
+                "--tui" => options.tui = true,
+                "--time-control" => options.time_control = Some(value()?),
+                "--ascii" => options.ascii = true,
+                "--compact" => options.compact = true,
+                "--colors" => options.colors = true,
+                _ => {
+                    return Err(format!("unknown argument: {}", argument));
+                }
+            }
+        }
+
+        Ok(options)
+    }
+}
@@ -0,0 +1,242 @@
+// External imports
+use std::io::{self, Write};
+// Absolute imports within crate
+use chess::board::{Board, ChessMove, Outcome};
+use chess::game::{Game, Termination};
+use chess::piece::Color;
+use chess::render::TextRenderer;
+use chess::search::{Score, SearchResult, Searcher};
+
+const HELP: &str = "Commands:
+  <move>     make a move in SAN or UCI notation, for example Nf3 or g1f3
+  undo       take back the last move, or the last two moves against the engine
+  flip       show the board from the other side
+  fen        show the position in Forsyth-Edwards notation
+  pgn        show the game in portable game notation
+  moves      list the legal moves
+  hint       show the move the engine would play
+  analyze    show the evaluation and the best line of the engine
+  claim      claim a draw by threefold repetition or the fifty-move rule
+  resign     resign the game
+  help       show this help
+  quit       stop playing";
+
+// Game played in the terminal, with the engine playing one of the colors
+pub struct Session {
+    game: Game,
+    engine: Option<Color>,
+    depth: u8,
+    searcher: Searcher,
+    is_flipped: bool,
+    renderer: TextRenderer,
+}
+
+impl Session {
+    pub fn new(board: Board, engine: Option<Color>, depth: u8, renderer: TextRenderer) -> Session {
+        // Show the board from the side of the human player
+        let is_flipped = engine == Some(Color::White);
+
+        Session {
+            game: Game::from_position(board),
+            engine,
+            depth,
+            searcher: Searcher::new(),
+            is_flipped,
+            renderer,
+        }
+    }
+
+    pub fn board(&self) -> &Board {
+        self.game.board()
+    }
+
+    // Run a command, returns false when the player quits
+    pub fn execute(&mut self, command: &str, output: &mut impl Write) -> io::Result<bool> {
+        match command {
+            "" => {}
+            "quit" | "exit" => {
+                return Ok(false);
+            }
+            "help" => writeln!(output, "{}", HELP)?,
+            "undo" => {
+                // Against the engine its moves are taken back as well, until a move of the player is taken back
+                let mut is_undone = false;
+                while self.game.undo() {
+                    is_undone = true;
+                    if self.engine.as_ref() != Some(self.board().active_color()) {
+                        break;
+                    }
+                }
+
+                if is_undone {
+                    self.show(output)?;
+                } else {
+                    writeln!(output, "There are no moves to undo.")?;
+                }
+            }
+            "flip" => {
+                self.is_flipped = !self.is_flipped;
+                self.show(output)?;
+            }
+            "fen" => writeln!(output, "{}", self.board().to_forsyth_edwards_notation())?,
+            "pgn" => write!(output, "{}", self.game.to_portable_game_notation())?,
+            "moves" => {
+                let mut moves: Vec<String> = self
+                    .board()
+                    .legal_moves()
+                    .iter()
+                    .map(|chess_move| self.board().to_standard_algebraic_notation(chess_move))
+                    .collect();
+                moves.sort();
+                writeln!(output, "{}", moves.join(" "))?;
+            }
+            "hint" => match self.search().best_move() {
+                Some(best_move) => writeln!(
+                    output,
+                    "The engine would play {}.",
+                    self.board().to_standard_algebraic_notation(best_move)
+                )?,
+                None => writeln!(output, "There are no legal moves.")?,
+            },
+            "analyze" => {
+                let result = self.search();
+                writeln!(
+                    output,
+                    "Evaluation: {} (depth {}, {} nodes)",
+                    self.white_score(result.score()),
+                    result.depth(),
+                    result.nodes()
+                )?;
+                writeln!(output, "Best line: {}", line(self.board(), result.principal_variation()))?;
+            }
+            "claim" => match self.game.claim_draw() {
+                Ok(()) => self.announce(output)?,
+                Err(error) => writeln!(output, "{}", error)?,
+            },
+            "resign" => {
+                let color = self.board().active_color().clone();
+                match self.game.resign(&color) {
+                    Ok(()) => self.announce(output)?,
+                    Err(error) => writeln!(output, "{}", error)?,
+                }
+            }
+            notation => match self.game.make_standard_algebraic_notation_move(notation) {
+                Ok(()) => {
+                    self.announce(output)?;
+                    self.engine_move(output)?;
+                    self.show(output)?;
+                }
+                Err(error) => writeln!(output, "{}", error)?,
+            },
+        }
+
+        Ok(true)
+    }
+
+    // Let the engine move when it is its turn
+    pub fn engine_move(&mut self, output: &mut impl Write) -> io::Result<()> {
+        if self.engine.as_ref() != Some(self.board().active_color()) || self.game.is_over() {
+            return Ok(());
+        }
+
+        if let Some(best_move) = self.search().best_move() {
+            let notation = self.board().to_standard_algebraic_notation(best_move);
+            self.game
+                .make_move(best_move.clone())
+                .expect("the engine only plays legal moves");
+            writeln!(output, "The engine plays {}.", notation)?;
+            self.announce(output)?;
+        }
+
+        Ok(())
+    }
+
+    // Board with the last move highlighted
+    pub fn show(&self, output: &mut impl Write) -> io::Result<()> {
+        let orientation = if self.is_flipped { Color::Black } else { Color::White };
+        let mut renderer = self.renderer.clone().orientation(orientation);
+
+        if let Some(played_move) = self
+            .game
+            .ply()
+            .checked_sub(1)
+            .map(|ply| &self.game.moves()[ply])
+        {
+            let chess_move = played_move.chess_move();
+            renderer = renderer
+                .highlight(chess_move.origin_square().copy())
+                .highlight(chess_move.destination_square().copy());
+        }
+        if let Some(clock) = self.game.clock() {
+            renderer = renderer.clock(clock);
+        }
+
+        write!(output, "{}", renderer.render(self.board()))
+    }
+
+    fn search(&mut self) -> SearchResult {
+        let board = self.game.board().clone();
+        self.searcher.search(&board, self.depth)
+    }
+
+    // Check, the end of the game and draws that can be claimed after the last move
+    fn announce(&self, output: &mut impl Write) -> io::Result<()> {
+        let board = self.board();
+
+        if self.game.is_over() {
+            let result = match self.game.outcome() {
+                Some(Outcome::Win(Color::White)) => "White wins",
+                Some(Outcome::Win(Color::Black)) => "Black wins",
+                Some(Outcome::Draw) => "Draw",
+                None => "Game over",
+            };
+            let reason = match self.game.termination() {
+                Some(Termination::Checkmate) => "checkmate",
+                Some(Termination::Stalemate) => "stalemate",
+                Some(Termination::InsufficientMaterial) => "insufficient material",
+                Some(Termination::SeventyFiveMoveRule) => "the seventy-five-move rule",
+                Some(Termination::FivefoldRepetition) => "fivefold repetition",
+                Some(Termination::ThreefoldRepetition) => "threefold repetition",
+                Some(Termination::FiftyMoveRule) => "the fifty-move rule",
+                Some(Termination::Resignation) => "resignation",
+                Some(Termination::DrawAgreement) => "agreement",
+                Some(Termination::TimeForfeit) => "time forfeit",
+                Some(Termination::Abandoned) => "abandonment",
+                Some(Termination::Adjudication) => "adjudication",
+                None => "an unknown reason",
+            };
+            writeln!(output, "{} by {}.", result, reason)?;
+        } else if board.is_in_check(board.active_color()) {
+            writeln!(output, "Check!")?;
+        }
+
+        if !self.game.is_over() && (self.game.is_threefold_repetition() || board.halfmove_clock() >= 100) {
+            writeln!(output, "A draw can be claimed with the claim command.")?;
+        }
+
+        Ok(())
+    }
+
+    // Scores of the search are from the point of view of the player to move
+    fn white_score(&self, score: &Score) -> Score {
+        match self.board().active_color() {
+            Color::White => score.clone(),
+            Color::Black => -score.clone(),
+        }
+    }
+}
+
+// Moves in standard algebraic notation, each made on the position after the move before it
+fn line(board: &Board, moves: &[ChessMove]) -> String {
+    let mut board = board.clone();
+    let mut notations = Vec::new();
+
+    for chess_move in moves {
+        notations.push(board.to_standard_algebraic_notation(chess_move));
+        board
+            .make_move(chess_move.clone())
+            .expect("moves of the line are legal");
+    }
+
+    notations.join(" ")
+}
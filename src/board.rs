@@ -1,20 +1,44 @@
 // Absolute imports within crate
-use crate::parser::{self, ParseError};
+use crate::endgame;
+use crate::parser::{self, CastlingNotation, ParseError, ParseMode};
 use crate::piece::{Color, Kind, Piece};
 // Relative imports of sub modules
-use board_error::BoardError;
+pub use board_builder::BoardBuilder;
+pub use board_error::BoardError;
 pub use castling_availability::CastlingAvailability;
 pub use chess_move::{Action, ChessMove, MoveStatus};
+pub use discovered_attack::DiscoveredAttack;
 pub use offset::Offset;
+pub use outcome::Outcome;
+pub use pin::Pin;
+pub use pocket::Pocket;
+pub use position_error::PositionError;
+pub use remaining_checks::RemainingChecks;
 pub use square::Square;
-pub use types::{File, MoveList, PiecePlacement, Rank, SquareList};
+pub use types::{AttackMap, File, MoveList, PiecePlacement, PromotedSquares, Rank, SquareList};
+mod attacks;
+mod board_builder;
 mod board_error;
 mod castling_availability;
 mod chess_move;
+mod discovered_attack;
 mod display;
+mod lines;
 mod offset;
+mod outcome;
+mod pin;
+mod pocket;
+mod position_error;
+mod remaining_checks;
+#[cfg(feature = "serde")]
+mod serialization;
 mod square;
+mod standard_algebraic_notation;
+mod static_exchange_evaluation;
+#[cfg(feature = "serde")]
+pub mod structured;
 mod types;
+mod validation;
 
 // Enum to indicate whether a square is taken by no-one, by the active color or by the opposite color
 enum OccupiedBy {
@@ -24,7 +48,7 @@ enum OccupiedBy {
 }
 
 // Chess board consisting of 64 squares and indicators for various special moves
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Board {
     piece_placement: PiecePlacement,
     active_color: Color,
@@ -32,6 +56,9 @@ pub struct Board {
     en_passant_target: Option<Square>,
     halfmove_clock: u16,
     fullmove_number: u16,
+    remaining_checks: Option<RemainingChecks>,
+    pocket: Option<Pocket>,
+    promoted_squares: PromotedSquares,
 }
 impl Board {
     // Public initializer
@@ -46,6 +73,9 @@ impl Board {
             en_passant_target,
             halfmove_clock,
             fullmove_number,
+            remaining_checks: None,
+            pocket: None,
+            promoted_squares: PromotedSquares::new(),
         }
     }
 
@@ -97,20 +127,207 @@ impl Board {
             en_passant_target: None,
             halfmove_clock: 0,
             fullmove_number: 1,
+            remaining_checks: None,
+            pocket: None,
+            promoted_squares: PromotedSquares::new(),
         }
     }
 
+    // Initialize a board with one of the 960 starting positions of Chess960
+    // Number 518 corresponds to the starting position of regular chess, numbers from 960 on have no position
+    pub fn chess960_starting_position(number: u16) -> Option<Board> {
+        if number >= 960 {
+            return None;
+        }
+
+        // Files of the first rank that have not been assigned a piece yet
+        let mut files: Vec<File> = (1..=8).collect();
+        let mut first_rank: [Option<Kind>; 8] = Default::default();
+        let mut place = |files: &mut Vec<File>, file: File, kind: Kind| {
+            files.retain(|f| *f != file);
+            first_rank[(file - 1) as usize] = Some(kind);
+        };
+
+        // Bishops on light squares (B, D, F, H) and dark squares (A, C, E, G)
+        place(&mut files, 2 * (number % 4) as File + 2, Kind::Bishop);
+        place(&mut files, 2 * (number / 4 % 4) as File + 1, Kind::Bishop);
+
+        // Queen on one of the six remaining squares
+        let queen_file = files[(number / 16 % 6) as usize];
+        place(&mut files, queen_file, Kind::Queen);
+
+        // Knights on two of the five remaining squares
+        let knight_positions = [
+            (0, 1),
+            (0, 2),
+            (0, 3),
+            (0, 4),
+            (1, 2),
+            (1, 3),
+            (1, 4),
+            (2, 3),
+            (2, 4),
+            (3, 4),
+        ];
+        let (first_knight, second_knight) = knight_positions[(number / 96) as usize];
+        let knight_files = [files[first_knight], files[second_knight]];
+        for file in knight_files {
+            place(&mut files, file, Kind::Knight);
+        }
+
+        // King in between the rooks on the three remaining squares
+        let (queenside_rook_file, king_file, kingside_rook_file) = (files[0], files[1], files[2]);
+        place(&mut files, queenside_rook_file, Kind::Rook);
+        place(&mut files, king_file, Kind::King);
+        place(&mut files, kingside_rook_file, Kind::Rook);
+
+        // Place pieces and pawns for both colors
+        let mut piece_placement = PiecePlacement::with_capacity(32);
+        for color in [Color::White, Color::Black] {
+            for (file, kind) in (1..=8).zip(first_rank.iter()) {
+                let kind = kind.clone().expect("all files have been assigned");
+                piece_placement.insert(Square::new(file, color.get_first_rank()), Piece::new(color.clone(), kind));
+                piece_placement
+                    .insert(Square::new(file, color.get_second_rank()), Piece::new(color.clone(), Kind::Pawn));
+            }
+        }
+
+        let castling_availability = CastlingAvailability::from_rook_files(
+            Some(kingside_rook_file),
+            Some(queenside_rook_file),
+            Some(kingside_rook_file),
+            Some(queenside_rook_file),
+        );
+
+        Some(Board {
+            piece_placement,
+            active_color: Color::White,
+            castling_availability,
+            en_passant_target: None,
+            halfmove_clock: 0,
+            fullmove_number: 1,
+            remaining_checks: None,
+            pocket: None,
+            promoted_squares: PromotedSquares::new(),
+        })
+    }
+
     pub fn active_color(&self) -> &Color {
         &self.active_color
     }
 
+    pub fn piece_placement(&self) -> &PiecePlacement {
+        &self.piece_placement
+    }
+
+    pub fn castling_availability(&self) -> &CastlingAvailability {
+        &self.castling_availability
+    }
+
+    pub fn en_passant_target(&self) -> Option<&Square> {
+        self.en_passant_target.as_ref()
+    }
+
+    // Forget the en passant target, for example after a double push from the first rank in Horde
+    pub(crate) fn clear_en_passant_target(&mut self) {
+        self.en_passant_target = None;
+    }
+
+    pub fn halfmove_clock(&self) -> u16 {
+        self.halfmove_clock
+    }
+
+    pub fn fullmove_number(&self) -> u16 {
+        self.fullmove_number
+    }
+
+    // Only used in Three-check, where each player needs to give a number of checks to win
+    pub fn remaining_checks(&self) -> Option<&RemainingChecks> {
+        self.remaining_checks.as_ref()
+    }
+
+    pub(crate) fn set_remaining_checks(&mut self, remaining_checks: Option<RemainingChecks>) {
+        self.remaining_checks = remaining_checks;
+    }
+
+    pub(crate) fn decrement_remaining_checks(&mut self, color: &Color) {
+        if let Some(remaining_checks) = &mut self.remaining_checks {
+            remaining_checks.decrement(color);
+        }
+    }
+
+    // Only used in Crazyhouse and Bughouse, where captured pieces can be dropped back onto the board
+    pub fn pocket(&self) -> Option<&Pocket> {
+        self.pocket.as_ref()
+    }
+
+    pub(crate) fn set_pocket(&mut self, pocket: Option<Pocket>) {
+        self.pocket = pocket;
+    }
+
+    pub(crate) fn add_to_pocket(&mut self, piece: Piece) -> Result<(), PositionError> {
+        match &mut self.pocket {
+            Some(pocket) => pocket.add(piece),
+            None => Ok(()),
+        }
+    }
+
+    // Whether the piece on the square was promoted from a pawn
+    pub fn is_promoted(&self, square: &Square) -> bool {
+        self.promoted_squares.contains(square)
+    }
+
+    pub(crate) fn set_promoted_squares(&mut self, promoted_squares: PromotedSquares) {
+        self.promoted_squares = promoted_squares;
+    }
+
+    // Remove a piece outside of a regular move, for example in an explosion in Atomic chess
+    pub(crate) fn remove_piece(&mut self, square: &Square) -> Option<Piece> {
+        let piece = self.piece_placement.remove(square)?;
+
+        // Castling is no longer possible without the king or with a rook from its original square
+        match piece.kind() {
+            Kind::King => {
+                self.castling_availability.disable_both(piece.color());
+            }
+            Kind::Rook if square.rank() == piece.color().get_first_rank() => {
+                self.castling_availability
+                    .disable_rook(piece.color(), square.file());
+            }
+            _ => {}
+        }
+
+        Some(piece)
+    }
+
     // Initialize a board from Forsyth–Edwards Notation
+    // NOTE: the castling availability can be written in either X-FEN or Shredder-FEN
     pub fn forsyth_edwards_notation(record: &str) -> Result<Board, ParseError> {
         parser::parse_forsyth_edwards_notation(record)
     }
 
+    // Parse a record, where a strict parser also rejects positions that cannot arise in a game
+    pub fn forsyth_edwards_notation_with_mode(record: &str, mode: &ParseMode) -> Result<Board, ParseError> {
+        parser::parse_forsyth_edwards_notation_with_mode(record, mode)
+    }
+
+    // Write the board in Forsyth–Edwards Notation, which is X-FEN for Chess960 positions
+    pub fn to_forsyth_edwards_notation(&self) -> String {
+        parser::format_forsyth_edwards_notation(self, &CastlingNotation::Standard)
+    }
+
+    // Write the board in Shredder-FEN, which uses the files of the castling rooks
+    pub fn to_shredder_forsyth_edwards_notation(&self) -> String {
+        parser::format_forsyth_edwards_notation(self, &CastlingNotation::Shredder)
+    }
+
     // Make a move and update the board
     pub fn make_move(&mut self, chess_move: ChessMove) -> Result<(), BoardError> {
+        // Drops do not have an origin square on the board
+        if let Action::Drop(kind) = chess_move.action() {
+            return self.make_drop(kind, chess_move.destination_square());
+        }
+
         // Get owned clones of the piece and destination square
         let mut piece = chess_move.piece().clone();
         let origin_square = chess_move.origin_square();
@@ -118,10 +335,18 @@ impl Board {
 
         // Get a borrowed reference to the color
         let color = &self.active_color;
+        let opposite_color = color.opposite();
 
         // Get first rank for active color
         let first_rank = color.get_first_rank();
 
+        // Look up the castling rook before the castling availability gets updated
+        let castling_rook_file = match chess_move.action() {
+            Action::ShortCastle => self.castling_availability.kingside_rook_file(color),
+            Action::LongCastle => self.castling_availability.queenside_rook_file(color),
+            _ => None,
+        };
+
         // Reset the halfmove clock is there was a pawn move or captures, and increment otherwise
         if *piece.kind() == Kind::Pawn || *chess_move.action() == Action::Capture {
             self.halfmove_clock = 0;
@@ -135,25 +360,37 @@ impl Board {
                 // Disable castling on both sides when king moves
                 self.castling_availability.disable_both(color);
             }
-            Kind::Rook => {
-                // NOTE: it does not matter if the rook has or hasn't moved.
-                // If it was moved back to a1/a8/h1/h8, it would have left the square before
-                // and castling would already be disabled.
-                if origin_square.rank() == first_rank {
-                    if origin_square.file() == 8 {
-                        // Disable king side castling if the rook on the H-file moves
-                        self.castling_availability.disable_kingside(color);
-                    } else if origin_square.file() == 1 {
-                        // Disable queen side castling if the rook on the A-file moves
-                        self.castling_availability.disable_queenside(color);
-                    }
-                }
+            // NOTE: it does not matter if the rook has or hasn't moved.
+            // If it was moved back to its original square, it would have left the square before
+            // and castling would already be disabled.
+            Kind::Rook if origin_square.rank() == first_rank => {
+                self.castling_availability
+                    .disable_rook(color, origin_square.file());
             }
             _ => {
                 // Irrelevant for castling
             }
         }
 
+        // Capturing a rook on its original square disables castling for the opponent
+        if destination_square.rank() == opposite_color.get_first_rank() {
+            if let Some(captured_piece) = self.piece_placement.get(&destination_square) {
+                if *captured_piece.color() == opposite_color && *captured_piece.kind() == Kind::Rook {
+                    self.castling_availability
+                        .disable_rook(&opposite_color, destination_square.file());
+                }
+            }
+        }
+
+        // En passant is only possible directly after a pawn moved two squares forward
+        self.en_passant_target = None;
+
+        // Keep track of promoted pieces, since they turn back into pawns when captured in Crazyhouse
+        self.promoted_squares.remove(&destination_square);
+        if self.promoted_squares.remove(origin_square) {
+            self.promoted_squares.insert(destination_square.clone());
+        }
+
         // Remove the piece from the origin square (always happens)
         let origin_piece = self.piece_placement.remove(origin_square);
 
@@ -178,21 +415,24 @@ impl Board {
 
                 // Promote the piece to the new kind
                 piece.promote(kind.clone());
+                self.promoted_squares.insert(destination_square.clone());
 
                 // And move it to the destination square
                 self.piece_placement.insert(destination_square, piece);
             }
-            Action::ShortCastle => {
+            Action::ShortCastle | Action::LongCastle => {
                 // Only king can castle
                 if *piece.kind() != Kind::King {
                     return Err(BoardError::InvalidCastlingKing(piece.kind().clone()));
                 }
 
-                // Move the king to the destination square
-                self.piece_placement.insert(destination_square, piece);
-
-                // Move rook from H-file, ...
-                let rook_origin_square = Square::new(8, first_rank);
+                // Remove the rook first, since the king might move onto its square in Chess960
+                let rook_origin_square = match castling_rook_file {
+                    Some(file) => Square::new(file, first_rank),
+                    None => {
+                        return Err(BoardError::CastlingUnavailable);
+                    }
+                };
                 let rook_piece = self
                     .piece_placement
                     .remove(&rook_origin_square)
@@ -203,34 +443,15 @@ impl Board {
                     return Err(BoardError::InvalidCastlingRook(rook_piece.kind().clone()));
                 }
 
-                // ... to F-file
-                let rook_destination_square = Square::new(6, first_rank);
-                self.piece_placement
-                    .insert(rook_destination_square, rook_piece);
-            }
-            Action::LongCastle => {
-                // Only king can castle
-                if *piece.kind() != Kind::King {
-                    return Err(BoardError::InvalidCastlingKing(piece.kind().clone()));
-                }
+                // Rook ends up on the F-file after short castle and on the D-file after long castle
+                let rook_destination_file = match chess_move.action() {
+                    Action::ShortCastle => 6,
+                    _ => 4,
+                };
+                let rook_destination_square = Square::new(rook_destination_file, first_rank);
 
-                // Move the king to the destination square
+                // Move both the king and the rook to their destination squares
                 self.piece_placement.insert(destination_square, piece);
-
-                // Move rook from A-file, ...
-                let rook_origin_square = Square::new(1, first_rank);
-                let rook_piece = self
-                    .piece_placement
-                    .remove(&rook_origin_square)
-                    .ok_or(BoardError::PieceMissing(rook_origin_square))?;
-
-                // Only rook can castle
-                if *rook_piece.kind() != Kind::Rook {
-                    return Err(BoardError::InvalidCastlingRook(rook_piece.kind().clone()));
-                }
-
-                // ... to D-file
-                let rook_destination_square = Square::new(4, first_rank);
                 self.piece_placement
                     .insert(rook_destination_square, rook_piece);
             }
@@ -240,18 +461,31 @@ impl Board {
                     return Err(BoardError::InvalidEnPassantPawn(piece.kind().clone()));
                 }
 
+                // The captured pawn is next to the origin square, on the file of the destination square
+                let captured_square = Square::new(destination_square.file(), origin_square.rank());
+                self.piece_placement
+                    .remove(&captured_square)
+                    .ok_or(BoardError::PieceMissing(captured_square))?;
+
                 // Move the pawn to the destination square
                 self.piece_placement.insert(destination_square, piece);
-
-                // TODO: calculate the square of the other pawn and remove it
             }
             Action::Move | Action::Capture => {
+                // A pawn that moves two squares forward can be captured en passant on the square it skipped
+                if *piece.kind() == Kind::Pawn && (destination_square.rank() - origin_square.rank()).abs() == 2 {
+                    let rank = (destination_square.rank() + origin_square.rank()) / 2;
+                    self.en_passant_target = Some(Square::new(destination_square.file(), rank));
+                }
+
                 // Simply place the piece on the destination square
                 // If the move was a capture, the piece that was originally on the square will automatically be removed
                 self.piece_placement.insert(destination_square, piece);
 
                 // TODO: validate that a piece has been captured
             }
+            Action::Drop(_) => {
+                unreachable!("drops are made before removing a piece from the origin square");
+            }
         }
 
         // Update active color and update fullmove clock
@@ -268,6 +502,64 @@ impl Board {
         Ok(())
     }
 
+    // Drop a piece from the pocket onto an empty square
+    fn make_drop(&mut self, kind: &Kind, destination_square: &Square) -> Result<(), BoardError> {
+        let color = self.active_color.clone();
+
+        // Validation
+        if !self.is_empty(destination_square) {
+            return Err(BoardError::SquareOccupied(destination_square.clone()));
+        }
+        let is_in_pocket = match &mut self.pocket {
+            Some(pocket) => pocket.remove(&color, kind),
+            None => false,
+        };
+        if !is_in_pocket {
+            return Err(BoardError::PieceNotInPocket(kind.clone()));
+        }
+
+        // Place the piece on the board
+        self.piece_placement
+            .insert(destination_square.clone(), Piece::new(color, kind.clone()));
+
+        // Dropping a pawn resets the halfmove clock, like any other pawn move
+        if *kind == Kind::Pawn {
+            self.halfmove_clock = 0;
+        } else {
+            self.halfmove_clock += 1;
+        }
+        self.en_passant_target = None;
+
+        // Update active color and update fullmove clock
+        match self.active_color {
+            Color::White => {
+                self.active_color = Color::Black;
+            }
+            Color::Black => {
+                self.active_color = Color::White;
+                self.fullmove_number += 1;
+            }
+        }
+
+        Ok(())
+    }
+
+    // Pass the turn to the opponent without moving, which is used to find the threat of a move
+    pub(crate) fn make_null_move(&mut self) {
+        self.halfmove_clock += 1;
+        self.en_passant_target = None;
+
+        match self.active_color {
+            Color::White => {
+                self.active_color = Color::Black;
+            }
+            Color::Black => {
+                self.active_color = Color::White;
+                self.fullmove_number += 1;
+            }
+        }
+    }
+
     // Returns all pieces
     pub fn pieces(&self) -> Vec<&Piece> {
         self.piece_placement.values().collect()
@@ -289,6 +581,61 @@ impl Board {
             .collect()
     }
 
+    // Returns the outcome of the position if it follows from basic endgame knowledge
+    pub fn known_outcome(&self) -> Option<Outcome> {
+        endgame::known_outcome(self)
+    }
+
+    pub fn is_known_draw(&self) -> bool {
+        self.known_outcome() == Some(Outcome::Draw)
+    }
+
+    pub fn is_known_win(&self) -> bool {
+        matches!(self.known_outcome(), Some(Outcome::Win(_)))
+    }
+
+    // Returns the outcome if the game has ended by checkmate, stalemate, insufficient material or the 75-move rule
+    pub fn outcome(&self) -> Option<Outcome> {
+        if self.legal_moves().is_empty() {
+            if self.is_in_check(&self.active_color) {
+                return Some(Outcome::Win(self.active_color.opposite()));
+            } else {
+                return Some(Outcome::Draw);
+            }
+        }
+
+        if self.is_insufficient_material() || self.halfmove_clock >= 150 {
+            return Some(Outcome::Draw);
+        }
+
+        None
+    }
+
+    // Whether both boards count as the same position for the repetition rules
+    // The same pieces are on the same squares, the same player is to move and the same moves are possible
+    pub fn is_same_position(&self, other: &Board) -> bool {
+        self.active_color == other.active_color
+            && self.castling_availability == other.castling_availability
+            && self.piece_placement == other.piece_placement
+            && self.pocket == other.pocket
+            && self.en_passant_capture_square() == other.en_passant_capture_square()
+    }
+
+    // En passant target square, but only when a pawn can actually capture en passant
+    fn en_passant_capture_square(&self) -> Option<&Square> {
+        self.en_passant_target.as_ref().filter(|_| {
+            self.legal_moves()
+                .iter()
+                .any(|chess_move| chess_move.action() == &Action::EnPassant)
+        })
+    }
+
+    // Neither player has enough pieces left to checkmate
+    pub fn is_insufficient_material(&self) -> bool {
+        endgame::has_insufficient_material(self, &Color::White)
+            && endgame::has_insufficient_material(self, &Color::Black)
+    }
+
     fn is_empty(&self, square: &Square) -> bool {
         !self.piece_placement.contains_key(square)
     }
@@ -307,33 +654,16 @@ impl Board {
     }
 
     pub fn is_in_check(&self, active_color: &Color) -> bool {
-        // Get color of opposite player
-        let opposite_color = match active_color {
-            Color::Black => &Color::White,
-            Color::White => &Color::Black,
-        };
-
-        // Get all pieces of the opponent
-        let opposite_pieces = self
-            .piece_placement
-            .iter()
-            .filter(|(_, piece)| piece.color() == opposite_color);
-
-        // Determine which chess moves the opponent can make
-        let mut opposite_moves =
-            opposite_pieces.flat_map(|(square, piece)| self.legal_piece_moves(square, piece, opposite_color));
-
         // Get the king of the active color
         let king = self
             .piece_placement
             .iter()
-            .filter(|(_, piece)| piece.color() == active_color && piece.kind() == &Kind::King)
-            .next();
+            .find(|(_, piece)| piece.color() == active_color && piece.kind() == &Kind::King);
 
         match king {
             Some((king_square, _piece)) => {
-                // Return true if any of the chess moves of the opponent could (theoretically) capture the kind
-                opposite_moves.any(|chess_move| chess_move.destination_square() == king_square)
+                // Return true if any of the pieces of the opponent could (theoretically) capture the king
+                self.is_attacked(king_square, &active_color.opposite())
             }
             None => {
                 // No king, so technically not in check
@@ -354,15 +684,93 @@ impl Board {
         ChessMove::new(piece, origin_square, action, destination_square, status)
     }
 
-    pub fn legal_moves(&self) -> MoveList {
-        self.piece_placement
+    // Returns all moves following the movement rules of the pieces, ignoring whether the king is left in check
+    pub fn pseudo_legal_moves(&self) -> MoveList {
+        let mut moves: MoveList = self
+            .piece_placement
             .iter()
             .filter(|(_, piece)| piece.color() == &self.active_color)
             .flat_map(|(square, piece)| self.legal_piece_moves(square, piece, &self.active_color))
-            // TODO: .filter() any moves that leave or bring the king in check
+            .collect();
+
+        moves.append(&mut self.legal_drop_moves(&self.active_color));
+        moves
+    }
+
+    // Pieces from the pocket can be dropped on any empty square, except for pawns on the first and last rank
+    // NOTE: the origin square of a drop is the same as its destination square
+    fn legal_drop_moves(&self, active_color: &Color) -> MoveList {
+        let mut moves = MoveList::new();
+
+        let pocket = match &self.pocket {
+            Some(pocket) => pocket,
+            None => {
+                return moves;
+            }
+        };
+
+        for kind in pocket.kinds(active_color) {
+            for rank in 1..=8 {
+                if kind == Kind::Pawn && (rank == 1 || rank == 8) {
+                    continue;
+                }
+
+                for file in 1..=8 {
+                    let square = Square::new(file, rank);
+                    if self.is_empty(&square) {
+                        let piece = Piece::new(active_color.clone(), kind.clone());
+                        let action = Action::Drop(kind.clone());
+                        moves.push(self.new_move(&piece, &square, action, square.copy()));
+                    }
+                }
+            }
+        }
+
+        moves
+    }
+
+    pub fn legal_moves(&self) -> MoveList {
+        self.pseudo_legal_moves()
+            .into_iter()
+            .filter(|chess_move| !self.leaves_king_in_check(chess_move))
             .collect()
     }
 
+    // Whether the move would leave or bring the king of the active color in check
+    fn leaves_king_in_check(&self, chess_move: &ChessMove) -> bool {
+        let mut board = self.clone();
+
+        match board.make_move(chess_move.clone()) {
+            Ok(()) => board.is_in_check(&self.active_color),
+            Err(_) => true,
+        }
+    }
+
+    // Count the number of leaf nodes of the move tree up to the given depth
+    pub fn perft(&self, depth: u8) -> u64 {
+        if depth == 0 {
+            return 1;
+        }
+
+        let legal_moves = self.legal_moves();
+
+        // No need to make the moves on the last level
+        if depth == 1 {
+            return legal_moves.len() as u64;
+        }
+
+        legal_moves
+            .into_iter()
+            .map(|chess_move| {
+                let mut board = self.clone();
+                board
+                    .make_move(chess_move)
+                    .expect("legal moves can always be made");
+                board.perft(depth - 1)
+            })
+            .sum()
+    }
+
     fn legal_piece_moves(&self, square: &Square, piece: &Piece, active_color: &Color) -> MoveList {
         match piece.kind() {
             Kind::Bishop => self.legal_bishop_moves(square, piece, active_color),
@@ -394,46 +802,60 @@ impl Board {
         let group: SquareList = origin_square.squares_on_king_move();
         moves.append(&mut self.legal_moves_for_group(origin_square, piece, active_color, group));
 
-        // Short castling
-        if self
-            .castling_availability
-            .is_short_castle_available(active_color)
-        {
-            // Check whether the two square right of the king are empty
-            let in_between_square_are_empty = origin_square
-                .squares_on_right_horizontal()
-                .into_iter()
-                .take(2)
-                .all(|square| self.is_empty(&square));
-
-            // TODO: verify in-between squares are not in check
-
-            // Can short castle
-            if in_between_square_are_empty {
-                let destination_square = Square::new(7, origin_square.rank());
-                let chess_move = self.new_move(piece, origin_square, Action::ShortCastle, destination_square);
-                moves.push(chess_move);
-            }
+        // Castling is only possible from the first rank
+        let first_rank = active_color.get_first_rank();
+        if origin_square.rank() != first_rank {
+            return moves;
         }
 
-        // Long castling
-        if self
-            .castling_availability
-            .is_long_castle_available(active_color)
-        {
-            // Check whether the three square left of the king are empty
-            let in_between_square_are_empty = origin_square
-                .squares_on_left_horizontal()
-                .into_iter()
-                .take(3)
-                .all(|square| self.is_empty(&square));
-
-            // TODO: verify in-between squares are not in check
-
-            // Can long castle
-            if in_between_square_are_empty {
-                let destination_square = Square::new(3, origin_square.rank());
-                let chess_move = self.new_move(piece, origin_square, Action::LongCastle, destination_square);
+        // King ends up on the G-file and rook on the F-file after short castle
+        // King ends up on the C-file and rook on the D-file after long castle
+        let castling_options = [
+            (Action::ShortCastle, self.castling_availability.kingside_rook_file(active_color), 7, 6),
+            (Action::LongCastle, self.castling_availability.queenside_rook_file(active_color), 3, 4),
+        ];
+
+        for (action, rook_file, king_destination_file, rook_destination_file) in castling_options {
+            let rook_file = match rook_file {
+                Some(rook_file) => rook_file,
+                None => {
+                    continue;
+                }
+            };
+
+            // The castling rook needs to be on its original square
+            let rook_square = Square::new(rook_file, first_rank);
+            match self.piece_placement.get(&rook_square) {
+                Some(rook) if rook.color() == active_color && rook.kind() == &Kind::Rook => {}
+                _ => {
+                    continue;
+                }
+            }
+
+            // All squares the king and rook travel over need to be empty, except for the king and rook themselves
+            let king_file = origin_square.file();
+            let files = [
+                king_file,
+                king_destination_file,
+                rook_file,
+                rook_destination_file,
+            ];
+            let first_file = *files.iter().min().expect("array is not empty");
+            let last_file = *files.iter().max().expect("array is not empty");
+            let in_between_square_are_empty = (first_file..=last_file)
+                .filter(|file| *file != king_file && *file != rook_file)
+                .all(|file| self.is_empty(&Square::new(file, first_rank)));
+
+            // The king cannot castle out of, through or into check
+            let opposite_color = active_color.opposite();
+            let in_between_squares_are_safe = (king_file.min(king_destination_file)
+                ..=king_file.max(king_destination_file))
+                .all(|file| !self.is_attacked(&Square::new(file, first_rank), &opposite_color));
+
+            // Can castle
+            if in_between_square_are_empty && in_between_squares_are_safe {
+                let destination_square = Square::new(king_destination_file, first_rank);
+                let chess_move = self.new_move(piece, origin_square, action, destination_square);
                 moves.push(chess_move);
             }
         }
@@ -520,15 +942,13 @@ impl Board {
                         }
                     }
                     OccupiedBy::None => match &self.en_passant_target {
-                        Some(square) => {
-                            // Check if the destination square matches en passant target square
-                            if destination_square == *square {
-                                let action = Action::EnPassant;
-                                let chess_move = self.new_move(piece, origin_square, action, destination_square);
-                                moves.push(chess_move);
-                            }
+                        // Check if the destination square matches en passant target square
+                        Some(square) if destination_square == *square => {
+                            let action = Action::EnPassant;
+                            let chess_move = self.new_move(piece, origin_square, action, destination_square);
+                            moves.push(chess_move);
                         }
-                        None => {
+                        _ => {
                             // No en passant possible
                         }
                     },
@@ -0,0 +1,227 @@
+// Absolute imports within crate
+use crate::piece::{Color, Kind};
+// Imports from parent
+use super::{AttackMap, Board, Square, SquareList};
+
+// Lines a sliding piece moves along, starting next to its square
+pub(super) type Line = fn(&Square) -> SquareList;
+
+pub(super) const ORTHOGONAL_LINES: [Line; 4] = [
+    Square::squares_on_up_vertical,
+    Square::squares_on_down_vertical,
+    Square::squares_on_left_horizontal,
+    Square::squares_on_right_horizontal,
+];
+
+pub(super) const DIAGONAL_LINES: [Line; 4] = [
+    Square::squares_on_top_right_diagonal,
+    Square::squares_on_top_left_diagonal,
+    Square::squares_on_bottom_right_diagonal,
+    Square::squares_on_bottom_left_diagonal,
+];
+
+// Kinds of pieces that attack along the given lines
+const ORTHOGONAL_SLIDERS: [Kind; 2] = [Kind::Rook, Kind::Queen];
+const DIAGONAL_SLIDERS: [Kind; 2] = [Kind::Bishop, Kind::Queen];
+
+// All lines with the sliding pieces that attack along them
+pub(super) fn sliding_lines() -> impl Iterator<Item = (Line, [Kind; 2])> {
+    ORTHOGONAL_LINES
+        .into_iter()
+        .map(|line| (line, ORTHOGONAL_SLIDERS))
+        .chain(
+            DIAGONAL_LINES
+                .into_iter()
+                .map(|line| (line, DIAGONAL_SLIDERS)),
+        )
+}
+
+// Lines the piece attacks along, which is none for pieces that do not slide
+pub(super) fn lines_of(kind: &Kind) -> Vec<Line> {
+    match kind {
+        Kind::Rook => ORTHOGONAL_LINES.to_vec(),
+        Kind::Bishop => DIAGONAL_LINES.to_vec(),
+        Kind::Queen => [ORTHOGONAL_LINES, DIAGONAL_LINES].concat(),
+        _ => Vec::new(),
+    }
+}
+
+impl Board {
+    // Squares of the pieces of the given color that attack the square, in no particular order
+    // NOTE: a piece defending a piece of its own color also attacks its square
+    pub fn attackers_of(&self, square: &Square, color: &Color) -> SquareList {
+        self.attackers(square, color).collect()
+    }
+
+    // Whether any piece of the given color attacks the square
+    pub fn is_attacked(&self, square: &Square, color: &Color) -> bool {
+        self.attackers(square, color).next().is_some()
+    }
+
+    pub fn attacker_count(&self, square: &Square, color: &Color) -> usize {
+        self.attackers(square, color).count()
+    }
+
+    // Number of pieces protecting the piece on the square, which is zero for an empty square
+    pub fn defender_count(&self, square: &Square) -> usize {
+        match self.piece_placement().get(square) {
+            Some(piece) => self.attacker_count(square, piece.color()),
+            None => 0,
+        }
+    }
+
+    // Lazily find the attackers, so a single attacker can be found without looking at all pieces
+    fn attackers<'a>(&'a self, square: &Square, color: &'a Color) -> impl Iterator<Item = Square> + 'a {
+        let is_piece = move |square: &Square, kinds: &[Kind]| {
+            self.piece_placement()
+                .get(square)
+                .is_some_and(|piece| piece.color() == color && kinds.contains(piece.kind()))
+        };
+
+        // Knights and kings attack a fixed group of squares
+        let knights = square
+            .squares_on_knight_moves()
+            .into_iter()
+            .filter(move |square| is_piece(square, &[Kind::Knight]));
+        let kings = square
+            .squares_on_king_move()
+            .into_iter()
+            .filter(move |square| is_piece(square, &[Kind::King]));
+
+        // Pawns attack diagonally forward, so look diagonally backward from the square
+        let pawn_diagonals: [Line; 2] = match color {
+            Color::White => [
+                Square::squares_on_bottom_left_diagonal,
+                Square::squares_on_bottom_right_diagonal,
+            ],
+            Color::Black => [
+                Square::squares_on_top_left_diagonal,
+                Square::squares_on_top_right_diagonal,
+            ],
+        };
+        let origin = square.copy();
+        let pawns = pawn_diagonals
+            .into_iter()
+            .filter_map(move |diagonal| diagonal(&origin).into_iter().next())
+            .filter(move |square| is_piece(square, &[Kind::Pawn]));
+
+        // Sliding pieces attack up to the first occupied square of a line
+        let origin = square.copy();
+        let sliders = sliding_lines().filter_map(move |(line, kinds)| {
+            line(&origin)
+                .into_iter()
+                .find(|square| !self.is_empty(square))
+                .filter(|square| is_piece(square, &kinds))
+        });
+
+        knights.chain(kings).chain(pawns).chain(sliders)
+    }
+
+    // Squares attacked by the piece on the square, including squares with pieces of its own color
+    pub fn attacked_squares(&self, square: &Square) -> SquareList {
+        let piece = match self.piece_placement().get(square) {
+            Some(piece) => piece,
+            None => {
+                return SquareList::new();
+            }
+        };
+
+        match piece.kind() {
+            Kind::Knight => square.squares_on_knight_moves(),
+            Kind::King => square.squares_on_king_move(),
+            Kind::Pawn => {
+                let diagonals: [Line; 2] = match piece.color() {
+                    Color::White => [
+                        Square::squares_on_top_left_diagonal,
+                        Square::squares_on_top_right_diagonal,
+                    ],
+                    Color::Black => [
+                        Square::squares_on_bottom_left_diagonal,
+                        Square::squares_on_bottom_right_diagonal,
+                    ],
+                };
+                diagonals
+                    .into_iter()
+                    .filter_map(|diagonal| diagonal(square).into_iter().next())
+                    .collect()
+            }
+            kind => lines_of(kind)
+                .into_iter()
+                .flat_map(|line| self.line_until_occupied(&line(square)))
+                .collect(),
+        }
+    }
+
+    // Squares of a line up to and including the first occupied square
+    pub(super) fn line_until_occupied(&self, line: &[Square]) -> SquareList {
+        let end = line
+            .iter()
+            .position(|square| !self.is_empty(square))
+            .map_or(line.len(), |index| index + 1);
+
+        line[..end].to_vec()
+    }
+
+    // Every square attacked by the given color, with the squares of the pieces attacking it
+    pub fn attack_map(&self, color: &Color) -> AttackMap {
+        let mut attack_map = AttackMap::new();
+
+        for (square, _) in self
+            .piece_placement()
+            .iter()
+            .filter(|(_, piece)| piece.color() == color)
+        {
+            for attacked in self.attacked_squares(square) {
+                attack_map.entry(attacked).or_default().push(square.copy());
+            }
+        }
+
+        attack_map
+    }
+
+    // Every piece of the given color that is protected, with the squares of the pieces protecting it
+    pub fn defence_map(&self, color: &Color) -> AttackMap {
+        let mut defence_map = self.attack_map(color);
+        defence_map.retain(|square, _| {
+            self.piece_placement()
+                .get(square)
+                .is_some_and(|piece| piece.color() == color)
+        });
+
+        defence_map
+    }
+
+    // Sliding pieces of the given color that attack the square through other attackers on the same line
+    // For example the rook behind a queen on the same file, or the bishop behind a pawn that attacks the square
+    pub fn xray_attackers_of(&self, square: &Square, color: &Color) -> SquareList {
+        let mut xray_attackers = SquareList::new();
+
+        for (line, kinds) in sliding_lines() {
+            let line = line(square);
+            let mut pieces = line
+                .iter()
+                .enumerate()
+                .filter_map(|(index, other)| Some((index, other, self.piece_placement().get(other)?)));
+
+            // The first piece on the line has to attack the square itself
+            let first_attacks = pieces.next().is_some_and(|(index, other, piece)| {
+                piece.color() == color
+                    && (kinds.contains(piece.kind())
+                        || (index == 0 && piece.kind() == &Kind::Pawn && self.attacked_squares(other).contains(square)))
+            });
+            if !first_attacks {
+                continue;
+            }
+
+            xray_attackers.extend(
+                pieces
+                    .map_while(|(_, other, piece)| {
+                        (piece.color() == color && kinds.contains(piece.kind())).then_some(other)
+                    })
+                    .map(Square::copy),
+            );
+        }
+
+        xray_attackers
+    }
+}
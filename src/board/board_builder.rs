@@ -0,0 +1,177 @@
+// Absolute imports within crate
+use crate::piece::{Color, Kind, Piece};
+// Imports from parent
+use super::{Board, CastlingAvailability, File, PiecePlacement, PositionError, Square};
+
+// Fluent editor to set up a position piece by piece
+// Castling availability is inferred from the placement of the kings and rooks, unless it is set explicitly
+#[derive(Debug, Clone)]
+pub struct BoardBuilder {
+    piece_placement: PiecePlacement,
+    active_color: Color,
+    castling_availability: Option<CastlingAvailability>,
+    en_passant_target: Option<Square>,
+    halfmove_clock: u16,
+    fullmove_number: u16,
+}
+
+impl Default for BoardBuilder {
+    fn default() -> Self {
+        BoardBuilder::new()
+    }
+}
+
+impl From<&Board> for BoardBuilder {
+    fn from(board: &Board) -> Self {
+        BoardBuilder {
+            piece_placement: board.piece_placement().clone(),
+            active_color: board.active_color().clone(),
+            castling_availability: Some(board.castling_availability().clone()),
+            en_passant_target: board.en_passant_target().map(Square::copy),
+            halfmove_clock: board.halfmove_clock(),
+            fullmove_number: board.fullmove_number(),
+        }
+    }
+}
+
+impl BoardBuilder {
+    // Empty board with white to move
+    pub fn new() -> BoardBuilder {
+        BoardBuilder {
+            piece_placement: PiecePlacement::with_capacity(32),
+            active_color: Color::White,
+            castling_availability: None,
+            en_passant_target: None,
+            halfmove_clock: 0,
+            fullmove_number: 1,
+        }
+    }
+
+    // Place a piece on a square, replacing any piece already there
+    pub fn put(mut self, square: Square, piece: Piece) -> BoardBuilder {
+        self.piece_placement.insert(square, piece);
+        self
+    }
+
+    pub fn remove(mut self, square: &Square) -> BoardBuilder {
+        self.piece_placement.remove(square);
+        self
+    }
+
+    // Remove all pieces from the board
+    pub fn clear(mut self) -> BoardBuilder {
+        self.piece_placement.clear();
+        self
+    }
+
+    pub fn active_color(mut self, active_color: Color) -> BoardBuilder {
+        self.active_color = active_color;
+        self
+    }
+
+    pub fn castling_availability(mut self, castling_availability: CastlingAvailability) -> BoardBuilder {
+        self.castling_availability = Some(castling_availability);
+        self
+    }
+
+    // Infer castling availability from the placement of the kings and rooks when building the board
+    pub fn infer_castling(mut self) -> BoardBuilder {
+        self.castling_availability = None;
+        self
+    }
+
+    pub fn en_passant_target(mut self, en_passant_target: Option<Square>) -> BoardBuilder {
+        self.en_passant_target = en_passant_target;
+        self
+    }
+
+    pub fn halfmove_clock(mut self, halfmove_clock: u16) -> BoardBuilder {
+        self.halfmove_clock = halfmove_clock;
+        self
+    }
+
+    pub fn fullmove_number(mut self, fullmove_number: u16) -> BoardBuilder {
+        self.fullmove_number = fullmove_number;
+        self
+    }
+
+    // Mirror the board vertically and swap the colors of all pieces, which gives the same position for the other player
+    pub fn mirror(mut self) -> BoardBuilder {
+        self.piece_placement = self
+            .piece_placement
+            .into_iter()
+            .map(|(square, piece)| {
+                let square = Square::new(square.file(), 9 - square.rank());
+                (square, Piece::new(piece.color().opposite(), piece.kind().clone()))
+            })
+            .collect();
+        self.active_color = self.active_color.opposite();
+        self.castling_availability = self.castling_availability.map(|castling| {
+            CastlingAvailability::from_rook_files(
+                castling.kingside_rook_file(&Color::Black),
+                castling.queenside_rook_file(&Color::Black),
+                castling.kingside_rook_file(&Color::White),
+                castling.queenside_rook_file(&Color::White),
+            )
+        });
+        self.en_passant_target = self
+            .en_passant_target
+            .map(|square| Square::new(square.file(), 9 - square.rank()));
+        self
+    }
+
+    // Swap the colors of all pieces without moving them, keeping the player to move
+    pub fn flip_colors(mut self) -> BoardBuilder {
+        self.piece_placement = self
+            .piece_placement
+            .into_iter()
+            .map(|(square, piece)| (square, Piece::new(piece.color().opposite(), piece.kind().clone())))
+            .collect();
+        // NOTE: Castling and en passant rarely remain possible, so both are left to be set again
+        self.castling_availability = None;
+        self.en_passant_target = None;
+        self
+    }
+
+    // Build the board, which fails if the position cannot arise in a game of regular chess
+    pub fn build(self) -> Result<Board, PositionError> {
+        let castling_availability = match self.castling_availability {
+            Some(castling_availability) => castling_availability,
+            None => infer_castling_availability(&self.piece_placement),
+        };
+
+        let board = Board::new(
+            self.piece_placement,
+            self.active_color,
+            castling_availability,
+            self.en_passant_target,
+            self.halfmove_clock,
+            self.fullmove_number,
+        );
+        board.validate()?;
+
+        Ok(board)
+    }
+}
+
+// Castling is available for a king on the E-file of its first rank, with a rook on the A- or H-file next to it
+fn infer_castling_availability(piece_placement: &PiecePlacement) -> CastlingAvailability {
+    let rook_file = |color: Color, file: File| -> Option<File> {
+        let rank = match color {
+            Color::White => 1,
+            Color::Black => 8,
+        };
+        let king = piece_placement.get(&Square::new(5, rank));
+        let rook = piece_placement.get(&Square::new(file, rank));
+
+        (king == Some(&Piece::new(color.clone(), Kind::King)) && rook == Some(&Piece::new(color, Kind::Rook)))
+            .then_some(file)
+    };
+
+    CastlingAvailability::from_rook_files(
+        rook_file(Color::White, 8),
+        rook_file(Color::White, 1),
+        rook_file(Color::Black, 8),
+        rook_file(Color::Black, 1),
+    )
+}
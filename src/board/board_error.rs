@@ -1,7 +1,7 @@
 // External imports
 use thiserror::Error;
 // Imports from parent
-use super::{Kind, Square};
+use super::{Kind, PositionError, Square};
 
 // Enum to represent the different error types
 #[derive(Error, Debug, PartialEq)]
@@ -16,4 +16,16 @@ pub enum BoardError {
     InvalidCastlingKing(Kind),
     #[error("expected castling kind, found: {0}")]
     InvalidCastlingRook(Kind),
+    #[error("castling is not available")]
+    CastlingUnavailable,
+    #[error("no piece in pocket to drop: {0}")]
+    PieceNotInPocket(Kind),
+    #[error("cannot drop piece on occupied square: {0}")]
+    SquareOccupied(Square),
+    #[error("illegal move: {0}")]
+    IllegalMove(String),
+    #[error("ambiguous move: {0}")]
+    AmbiguousMove(String),
+    #[error(transparent)]
+    InvalidPosition(#[from] PositionError),
 }
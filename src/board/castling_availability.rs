@@ -1,13 +1,21 @@
 // Absolute imports within crate
+use crate::board::File;
 use crate::piece;
 
+// Files of the rooks in the standard starting position
+const KINGSIDE_ROOK_FILE: File = 8;
+const QUEENSIDE_ROOK_FILE: File = 1;
+
 // Type to indicate whether castling is available for the either player on either king- or queenside
-#[derive(Debug)]
+// The file of the castling rook is stored, since it is not always on the A- or H-file in Chess960
+#[derive(Debug, PartialEq, Clone)]
+// Deserialize is implemented by hand, so the files of the rooks are checked
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct CastlingAvailability {
-    white_kingside: bool,
-    white_queenside: bool,
-    black_kingside: bool,
-    black_queenside: bool,
+    white_kingside: Option<File>,
+    white_queenside: Option<File>,
+    black_kingside: Option<File>,
+    black_queenside: Option<File>,
 }
 
 impl Default for CastlingAvailability {
@@ -17,8 +25,22 @@ impl Default for CastlingAvailability {
 }
 
 impl CastlingAvailability {
+    // Castling availability with the rooks on their standard files
     pub fn new(
         white_kingside: bool, white_queenside: bool, black_kingside: bool, black_queenside: bool,
+    ) -> CastlingAvailability {
+        CastlingAvailability::from_rook_files(
+            white_kingside.then_some(KINGSIDE_ROOK_FILE),
+            white_queenside.then_some(QUEENSIDE_ROOK_FILE),
+            black_kingside.then_some(KINGSIDE_ROOK_FILE),
+            black_queenside.then_some(QUEENSIDE_ROOK_FILE),
+        )
+    }
+
+    // Castling availability with the rooks on any file
+    pub fn from_rook_files(
+        white_kingside: Option<File>, white_queenside: Option<File>, black_kingside: Option<File>,
+        black_queenside: Option<File>,
     ) -> CastlingAvailability {
         CastlingAvailability {
             white_kingside,
@@ -29,13 +51,21 @@ impl CastlingAvailability {
     }
 
     pub fn is_short_castle_available(&self, color: &piece::Color) -> bool {
+        self.kingside_rook_file(color).is_some()
+    }
+
+    pub fn is_long_castle_available(&self, color: &piece::Color) -> bool {
+        self.queenside_rook_file(color).is_some()
+    }
+
+    pub fn kingside_rook_file(&self, color: &piece::Color) -> Option<File> {
         match color {
             piece::Color::White => self.white_kingside,
             piece::Color::Black => self.black_kingside,
         }
     }
 
-    pub fn is_long_castle_available(&self, color: &piece::Color) -> bool {
+    pub fn queenside_rook_file(&self, color: &piece::Color) -> Option<File> {
         match color {
             piece::Color::White => self.white_queenside,
             piece::Color::Black => self.black_queenside,
@@ -50,10 +80,10 @@ impl CastlingAvailability {
     pub fn disable_kingside(&mut self, color: &piece::Color) {
         match color {
             piece::Color::White => {
-                self.white_kingside = false;
+                self.white_kingside = None;
             }
             piece::Color::Black => {
-                self.black_kingside = false;
+                self.black_kingside = None;
             }
         }
     }
@@ -61,11 +91,21 @@ impl CastlingAvailability {
     pub fn disable_queenside(&mut self, color: &piece::Color) {
         match color {
             piece::Color::White => {
-                self.white_queenside = false;
+                self.white_queenside = None;
             }
             piece::Color::Black => {
-                self.black_queenside = false;
+                self.black_queenside = None;
             }
         }
     }
+
+    // Disable castling with the rook on the given file, because it moved or was captured
+    pub fn disable_rook(&mut self, color: &piece::Color, file: File) {
+        if self.kingside_rook_file(color) == Some(file) {
+            self.disable_kingside(color);
+        }
+        if self.queenside_rook_file(color) == Some(file) {
+            self.disable_queenside(color);
+        }
+    }
 }
@@ -5,6 +5,8 @@ use crate::board::Square;
 use crate::piece::{Kind, Piece};
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum Action {
     Move,
     Capture,
@@ -13,16 +15,20 @@ pub enum Action {
     CapturePromotion(Kind),
     ShortCastle,
     LongCastle,
+    Drop(Kind),
 }
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum MoveStatus {
     Checkmate,
     Check,
     None,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ChessMove {
     piece: Piece,
     origin_square: Square,
@@ -59,6 +65,29 @@ impl ChessMove {
     pub fn destination_square(&self) -> &Square {
         &self.destination_square
     }
+
+    pub fn status(&self) -> &MoveStatus {
+        &self.status
+    }
+
+    pub fn is_capture(&self) -> bool {
+        matches!(self.action, Action::Capture | Action::EnPassant | Action::CapturePromotion(_))
+    }
+
+    // Notation used by the Universal Chess Interface, for example e2e4, e7e8q or N@f3
+    // NOTE: castling is written as the king moving to its destination square
+    pub fn uci(&self) -> String {
+        match &self.action {
+            Action::Drop(kind) => match kind {
+                Kind::Pawn => format!("P@{}", self.destination_square),
+                _ => format!("{}@{}", kind, self.destination_square),
+            },
+            Action::MovePromotion(kind) | Action::CapturePromotion(kind) => {
+                format!("{}{}{}", self.origin_square, self.destination_square, kind.to_string().to_lowercase())
+            }
+            _ => format!("{}{}", self.origin_square, self.destination_square),
+        }
+    }
 }
 
 impl fmt::Display for ChessMove {
@@ -73,6 +102,13 @@ impl fmt::Display for ChessMove {
                 // Long castle notation
                 write!(f, "0-0-0")?;
             }
+            Action::Drop(kind) => {
+                // Letter of the dropped piece, which is explicit for pawns, and the destination square
+                match kind {
+                    Kind::Pawn => write!(f, "P@{}", self.destination_square)?,
+                    _ => write!(f, "{}@{}", kind, self.destination_square)?,
+                }
+            }
             _ => {
                 // Letter as used in algebraic notation
                 write!(f, "{}", self.piece.kind())?;
@@ -0,0 +1,40 @@
+// Imports from parent
+use super::Square;
+
+// Piece that uncovers an attack of a sliding piece of its own color when it moves off the line
+#[derive(Debug, PartialEq, Clone)]
+pub struct DiscoveredAttack {
+    piece: Square,
+    attacker: Square,
+    target: Square,
+    is_check: bool,
+}
+
+impl DiscoveredAttack {
+    pub fn new(piece: Square, attacker: Square, target: Square, is_check: bool) -> DiscoveredAttack {
+        DiscoveredAttack {
+            piece,
+            attacker,
+            target,
+            is_check,
+        }
+    }
+
+    // Piece that has to move to uncover the attack
+    pub fn piece(&self) -> &Square {
+        &self.piece
+    }
+
+    pub fn attacker(&self) -> &Square {
+        &self.attacker
+    }
+
+    pub fn target(&self) -> &Square {
+        &self.target
+    }
+
+    // Whether the uncovered attack is on the king of the opponent
+    pub fn is_check(&self) -> bool {
+        self.is_check
+    }
+}
@@ -0,0 +1,144 @@
+// Absolute imports within crate
+use crate::piece::{Color, Kind, Piece};
+// Imports from parent
+use super::attacks::{lines_of, sliding_lines};
+use super::{Board, DiscoveredAttack, Pin, Square, SquareList};
+
+// Piece on a line, with the index of its square on the line
+type LinePiece<'a> = (usize, &'a Square, &'a Piece);
+
+impl Board {
+    // First two pieces on a line, with the index of their square on the line
+    fn first_two_pieces<'a>(&'a self, line: &'a [Square]) -> Option<[LinePiece<'a>; 2]> {
+        let mut pieces = line
+            .iter()
+            .enumerate()
+            .filter_map(|(index, square)| Some((index, square, self.piece_placement().get(square)?)));
+
+        Some([pieces.next()?, pieces.next()?])
+    }
+
+    // All pieces of the given color that are pinned to their king or to a more valuable piece
+    pub fn pins(&self, color: &Color) -> Vec<Pin> {
+        let mut pins = Vec::new();
+
+        for (target, target_piece) in self
+            .piece_placement()
+            .iter()
+            .filter(|(_, piece)| piece.color() == color)
+        {
+            for (line, kinds) in sliding_lines() {
+                let line = line(target);
+                let [(_, pinned, pinned_piece), (index, pinner, pinner_piece)] = match self.first_two_pieces(&line) {
+                    Some(pieces) => pieces,
+                    None => {
+                        continue;
+                    }
+                };
+
+                let is_pin = pinned_piece.color() == color
+                    && pinner_piece.color() != color
+                    && kinds.contains(pinner_piece.kind())
+                    && (target_piece.kind() == &Kind::King
+                        || pinned_piece.kind().relative_value() < target_piece.kind().relative_value());
+                if is_pin {
+                    pins.push(Pin::new(pinned.copy(), pinner.copy(), target.copy(), line[..=index].to_vec()));
+                }
+            }
+        }
+
+        pins
+    }
+
+    // Pinned pieces that cannot leave the line, since that would expose the king
+    pub fn absolute_pins(&self, color: &Color) -> Vec<Pin> {
+        self.pins(color)
+            .into_iter()
+            .filter(|pin| self.is_king(pin.target()))
+            .collect()
+    }
+
+    // Pinned pieces that can leave the line, but would lose the more valuable piece behind it
+    pub fn relative_pins(&self, color: &Color) -> Vec<Pin> {
+        self.pins(color)
+            .into_iter()
+            .filter(|pin| !self.is_king(pin.target()))
+            .collect()
+    }
+
+    // Whether the piece on the square is pinned to its king
+    pub fn is_pinned(&self, square: &Square) -> bool {
+        match self.piece_placement().get(square) {
+            Some(piece) => self
+                .absolute_pins(piece.color())
+                .iter()
+                .any(|pin| pin.pinned() == square),
+            None => false,
+        }
+    }
+
+    fn is_king(&self, square: &Square) -> bool {
+        self.piece_placement()
+            .get(square)
+            .is_some_and(|piece| piece.kind() == &Kind::King)
+    }
+
+    // Squares of the pieces giving check to the king of the active color
+    pub fn checkers(&self) -> SquareList {
+        let king = Piece::new(self.active_color().clone(), Kind::King);
+
+        match self
+            .piece_placement()
+            .iter()
+            .find(|(_, piece)| **piece == king)
+        {
+            Some((square, _)) => self.attackers_of(square, &self.active_color().opposite()),
+            None => SquareList::new(),
+        }
+    }
+
+    // Pieces of the given color that uncover a check or an attack on a more valuable piece when they move off the line
+    // NOTE: a piece moving along the line of the attack does not uncover it
+    pub fn discovered_attacks(&self, color: &Color) -> Vec<DiscoveredAttack> {
+        let mut discovered_attacks = Vec::new();
+
+        for (attacker, attacker_piece) in self
+            .piece_placement()
+            .iter()
+            .filter(|(_, piece)| piece.color() == color)
+        {
+            for line in lines_of(attacker_piece.kind()) {
+                let line = line(attacker);
+                let [(_, piece, moving_piece), (_, target, target_piece)] = match self.first_two_pieces(&line) {
+                    Some(pieces) => pieces,
+                    None => {
+                        continue;
+                    }
+                };
+
+                let is_check = target_piece.kind() == &Kind::King;
+                let is_discovered_attack = moving_piece.color() == color
+                    && target_piece.color() != color
+                    && (is_check || target_piece.kind().relative_value() > attacker_piece.kind().relative_value());
+                if is_discovered_attack {
+                    discovered_attacks.push(DiscoveredAttack::new(
+                        piece.copy(),
+                        attacker.copy(),
+                        target.copy(),
+                        is_check,
+                    ));
+                }
+            }
+        }
+
+        discovered_attacks
+    }
+
+    // Pieces of the given color that give check by moving off the line of a sliding piece
+    pub fn discovered_checks(&self, color: &Color) -> Vec<DiscoveredAttack> {
+        self.discovered_attacks(color)
+            .into_iter()
+            .filter(DiscoveredAttack::is_check)
+            .collect()
+    }
+}
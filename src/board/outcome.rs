@@ -0,0 +1,9 @@
+// Absolute imports within crate
+use crate::piece::Color;
+
+// Result of a finished game
+#[derive(Debug, PartialEq, Clone)]
+pub enum Outcome {
+    Win(Color),
+    Draw,
+}
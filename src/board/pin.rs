@@ -0,0 +1,41 @@
+// Imports from parent
+use super::{Square, SquareList};
+
+// Piece that cannot move off a line without exposing a more valuable piece of its own color behind it
+#[derive(Debug, PartialEq, Clone)]
+pub struct Pin {
+    pinned: Square,
+    pinner: Square,
+    target: Square,
+    line: SquareList,
+}
+
+impl Pin {
+    pub fn new(pinned: Square, pinner: Square, target: Square, line: SquareList) -> Pin {
+        Pin {
+            pinned,
+            pinner,
+            target,
+            line,
+        }
+    }
+
+    pub fn pinned(&self) -> &Square {
+        &self.pinned
+    }
+
+    // Sliding piece of the opponent that pins the piece
+    pub fn pinner(&self) -> &Square {
+        &self.pinner
+    }
+
+    // Piece behind the pinned piece, which is the king for an absolute pin
+    pub fn target(&self) -> &Square {
+        &self.target
+    }
+
+    // Squares between the target and the pinner, including the pinner, where the pinned piece can still move to
+    pub fn line(&self) -> &[Square] {
+        &self.line
+    }
+}
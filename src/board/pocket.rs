@@ -0,0 +1,97 @@
+// Absolute imports within crate
+use crate::piece::{Color, Kind, Piece};
+// Imports from parent
+use super::PositionError;
+
+// Order in which pieces are listed in a record, from most to least valuable
+const POCKET_KINDS: [Kind; 5] = [
+    Kind::Queen,
+    Kind::Rook,
+    Kind::Bishop,
+    Kind::Knight,
+    Kind::Pawn,
+];
+
+// Captured pieces that can be dropped back onto the board in Crazyhouse and Bughouse
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Pocket {
+    pieces: Vec<Piece>,
+}
+
+impl Pocket {
+    // Kings are never captured, so they cannot be in a pocket
+    pub fn new(pieces: Vec<Piece>) -> Result<Pocket, PositionError> {
+        if let Some(king) = pieces.iter().find(|piece| piece.kind() == &Kind::King) {
+            return Err(PositionError::KingInPocket(king.color().clone()));
+        }
+
+        let mut pocket = Pocket { pieces };
+        pocket.sort();
+        Ok(pocket)
+    }
+
+    // Keep the pieces in a fixed order, so pockets with the same pieces are equal
+    fn sort(&mut self) {
+        self.pieces
+            .sort_by_key(|piece| (piece.color() == &Color::Black, kind_order(piece.kind())));
+    }
+
+    // Returns all pieces of one color, from most to least valuable
+    pub fn pieces(&self, color: &Color) -> Vec<&Piece> {
+        self.pieces
+            .iter()
+            .filter(|piece| piece.color() == color)
+            .collect()
+    }
+
+    // Returns the different kinds of pieces of one color that can be dropped
+    pub fn kinds(&self, color: &Color) -> Vec<Kind> {
+        POCKET_KINDS
+            .into_iter()
+            .filter(|kind| self.count(color, kind) > 0)
+            .collect()
+    }
+
+    pub fn count(&self, color: &Color, kind: &Kind) -> usize {
+        self.pieces
+            .iter()
+            .filter(|piece| piece.color() == color && piece.kind() == kind)
+            .count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pieces.is_empty()
+    }
+
+    pub fn add(&mut self, piece: Piece) -> Result<(), PositionError> {
+        if piece.kind() == &Kind::King {
+            return Err(PositionError::KingInPocket(piece.color().clone()));
+        }
+
+        self.pieces.push(piece);
+        self.sort();
+        Ok(())
+    }
+
+    // Take a piece out of the pocket, returns false if there was no such piece
+    pub fn remove(&mut self, color: &Color, kind: &Kind) -> bool {
+        match self
+            .pieces
+            .iter()
+            .position(|piece| piece.color() == color && piece.kind() == kind)
+        {
+            Some(index) => {
+                self.pieces.remove(index);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+fn kind_order(kind: &Kind) -> usize {
+    POCKET_KINDS
+        .iter()
+        .position(|pocket_kind| pocket_kind == kind)
+        .unwrap_or(POCKET_KINDS.len())
+}
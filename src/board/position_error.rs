@@ -0,0 +1,33 @@
+// External imports
+use thiserror::Error;
+// Absolute imports within crate
+use crate::piece::Color;
+// Imports from parent
+use super::{File, Square};
+
+// Enum to represent the reasons a position cannot arise in a game of chess
+#[derive(Error, Debug, PartialEq)]
+pub enum PositionError {
+    #[error("no king found for {0:?}")]
+    MissingKing(Color),
+    #[error("more than one king found for {0:?}")]
+    TooManyKings(Color),
+    #[error("pawn found on the first or last rank: {0}")]
+    PawnOnBackRank(Square),
+    #[error("more than eight pawns found for {0:?}")]
+    TooManyPawns(Color),
+    #[error("more than sixteen pieces found for {0:?}")]
+    TooManyPieces(Color),
+    #[error("more promoted pieces than missing pawns found for {0:?}")]
+    TooManyPromotedPieces(Color),
+    #[error("castling is available for {0:?}, but the king is not on the first rank")]
+    CastlingWithoutKing(Color),
+    #[error("castling is available for {0:?}, but there is no rook on file {1}")]
+    CastlingWithoutRook(Color, File),
+    #[error("no pawn can have moved two squares past the en passant target square: {0}")]
+    InvalidEnPassant(Square),
+    #[error("the player that is not to move is in check: {0:?}")]
+    OpponentInCheck(Color),
+    #[error("king found in the pocket of {0:?}")]
+    KingInPocket(Color),
+}
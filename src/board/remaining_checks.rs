@@ -0,0 +1,40 @@
+// Absolute imports within crate
+use crate::piece::Color;
+
+// Number of checks each player still needs to give to win a game of Three-check
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RemainingChecks {
+    white: u8,
+    black: u8,
+}
+
+impl Default for RemainingChecks {
+    fn default() -> Self {
+        RemainingChecks::new(3, 3)
+    }
+}
+
+impl RemainingChecks {
+    pub fn new(white: u8, black: u8) -> RemainingChecks {
+        RemainingChecks { white, black }
+    }
+
+    pub fn get(&self, color: &Color) -> u8 {
+        match color {
+            Color::White => self.white,
+            Color::Black => self.black,
+        }
+    }
+
+    pub fn decrement(&mut self, color: &Color) {
+        match color {
+            Color::White => {
+                self.white = self.white.saturating_sub(1);
+            }
+            Color::Black => {
+                self.black = self.black.saturating_sub(1);
+            }
+        }
+    }
+}
@@ -0,0 +1,69 @@
+// External imports
+use serde::de::{self, Deserialize, Deserializer};
+use serde::ser::{Serialize, Serializer};
+// Imports from parent
+use super::{Board, CastlingAvailability, File, Square};
+
+// Squares are written in coordinate notation, for example "e4"
+impl Serialize for Square {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Square {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Square, D::Error> {
+        let field = String::deserialize(deserializer)?;
+        field.parse().map_err(de::Error::custom)
+    }
+}
+
+// Boards are written as FEN, use `board::structured` for an object with a field for every part of the position
+impl Serialize for Board {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_forsyth_edwards_notation())
+    }
+}
+
+impl<'de> Deserialize<'de> for Board {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Board, D::Error> {
+        let record = String::deserialize(deserializer)?;
+        Board::forsyth_edwards_notation(&record).map_err(de::Error::custom)
+    }
+}
+
+// Castling availability as it is serialized, before the files of the rooks have been checked
+#[derive(serde::Deserialize)]
+struct RookFiles {
+    white_kingside: Option<File>,
+    white_queenside: Option<File>,
+    black_kingside: Option<File>,
+    black_queenside: Option<File>,
+}
+
+impl<'de> Deserialize<'de> for CastlingAvailability {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<CastlingAvailability, D::Error> {
+        let files = RookFiles::deserialize(deserializer)?;
+
+        let all_files = [
+            files.white_kingside,
+            files.white_queenside,
+            files.black_kingside,
+            files.black_queenside,
+        ];
+        if let Some(file) = all_files
+            .into_iter()
+            .flatten()
+            .find(|file| !(1..=8).contains(file))
+        {
+            return Err(de::Error::custom(format!("invalid file of a castling rook: {}", file)));
+        }
+
+        Ok(CastlingAvailability::from_rook_files(
+            files.white_kingside,
+            files.white_queenside,
+            files.black_kingside,
+            files.black_queenside,
+        ))
+    }
+}
@@ -1,5 +1,8 @@
 // External imports
 use std::fmt;
+use std::str::FromStr;
+// Absolute imports within crate
+use crate::parser::{self, ParseError};
 // Imports from parent
 use super::{File, Offset, Rank, SquareList};
 
@@ -36,11 +39,11 @@ pub struct Square {
 
 impl Square {
     fn is_valid_file(file: File) -> bool {
-        1 <= file && file <= 8
+        (1..=8).contains(&file)
     }
 
     fn is_valid_rank(rank: Rank) -> bool {
-        1 <= rank && rank <= 8
+        (1..=8).contains(&rank)
     }
 
     pub fn new(file: File, rank: Rank) -> Square {
@@ -80,9 +83,9 @@ impl Square {
     fn create_group_of_squares(&self, offsets: &[Offset]) -> SquareList {
         // Iterate through the list and keep all squares that are valid (within the 8×8 board)
         offsets
-            .into_iter()
-            .filter(|offset| self.is_valid_offset(&offset))
-            .map(|offset| self.copy_with_offset(&offset))
+            .iter()
+            .filter(|offset| self.is_valid_offset(offset))
+            .map(|offset| self.copy_with_offset(offset))
             .collect()
     }
 
@@ -168,3 +171,20 @@ impl fmt::Display for Square {
         write!(f, "{}{}", file, rank)
     }
 }
+
+// Parse a square in coordinate notation, for example e4
+impl FromStr for Square {
+    type Err = ParseError;
+
+    fn from_str(field: &str) -> Result<Square, ParseError> {
+        let mut characters = field.chars();
+
+        let file = parser::parse_file(characters.next().ok_or(ParseError::UnexpectedEnd)?)?;
+        let rank = parser::parse_rank(characters.next().ok_or(ParseError::UnexpectedEnd)?)?;
+        if let Some(character) = characters.next() {
+            return Err(ParseError::InvalidRank(character));
+        }
+
+        Ok(Square::new(file, rank))
+    }
+}
@@ -0,0 +1,121 @@
+// Absolute imports within crate
+use crate::parser;
+use crate::piece::Kind;
+// Imports from parent
+use super::{Action, Board, BoardError, ChessMove};
+
+impl Board {
+    // Short notation of a legal move as used in PGN, for example Nbd7, exd6, e8=Q+ or O-O#
+    pub fn to_standard_algebraic_notation(&self, chess_move: &ChessMove) -> String {
+        let mut notation = self.format_move(chess_move);
+
+        // Check or checkmate after the move has been made
+        let mut board = self.clone();
+        if board.make_move(chess_move.clone()).is_ok() && board.is_in_check(board.active_color()) {
+            if board.legal_moves().is_empty() {
+                notation.push('#');
+            } else {
+                notation.push('+');
+            }
+        }
+
+        notation
+    }
+
+    // Find the legal move for the notation, which can also be in long algebraic or UCI notation
+    pub fn parse_standard_algebraic_notation(&self, notation: &str) -> Result<ChessMove, BoardError> {
+        // Check, checkmate and annotations like ?! do not matter to find the move
+        let normalized = normalize(notation.trim_end_matches(['+', '#', '!', '?']));
+
+        let mut candidates = self.legal_moves().into_iter().filter(|chess_move| {
+            let long_notation = chess_move.to_string();
+            let long_notation = long_notation.trim_end_matches(['+', '#']);
+
+            normalize(&self.format_move(chess_move)) == normalized
+                || normalize(long_notation) == normalized
+                || chess_move.uci() == normalized
+        });
+
+        match (candidates.next(), candidates.next()) {
+            (Some(chess_move), None) => Ok(chess_move),
+            (Some(_), Some(_)) => Err(BoardError::AmbiguousMove(notation.to_string())),
+            (None, _) => Err(BoardError::IllegalMove(notation.to_string())),
+        }
+    }
+
+    // Notation without the check or checkmate suffix
+    fn format_move(&self, chess_move: &ChessMove) -> String {
+        let origin_square = chess_move.origin_square();
+        let destination_square = chess_move.destination_square();
+        let kind = chess_move.piece().kind();
+
+        match chess_move.action() {
+            Action::ShortCastle => {
+                return String::from("O-O");
+            }
+            Action::LongCastle => {
+                return String::from("O-O-O");
+            }
+            Action::Drop(_) => {
+                return chess_move.uci();
+            }
+            _ => {}
+        }
+
+        let mut notation = String::new();
+        if *kind == Kind::Pawn {
+            // Pawn captures are identified by the file they came from
+            if chess_move.is_capture() {
+                notation.push(parser::format_file(origin_square.file()));
+            }
+        } else {
+            notation.push_str(&kind.to_string());
+
+            // Other pieces of the same kind that can move to the same square
+            let others: Vec<ChessMove> = self
+                .legal_moves()
+                .into_iter()
+                .filter(|other| {
+                    other.piece().kind() == kind
+                        && other.destination_square() == destination_square
+                        && other.origin_square() != origin_square
+                        && !matches!(other.action(), Action::ShortCastle | Action::LongCastle)
+                })
+                .collect();
+
+            // Prefer the file, then the rank and only use the full square when both are needed
+            if !others.is_empty() {
+                let same_file = others
+                    .iter()
+                    .any(|other| other.origin_square().file() == origin_square.file());
+                let same_rank = others
+                    .iter()
+                    .any(|other| other.origin_square().rank() == origin_square.rank());
+
+                if !same_file {
+                    notation.push(parser::format_file(origin_square.file()));
+                } else if !same_rank {
+                    notation.push_str(&origin_square.rank().to_string());
+                } else {
+                    notation.push_str(&origin_square.to_string());
+                }
+            }
+        }
+
+        if chess_move.is_capture() {
+            notation.push('x');
+        }
+        notation.push_str(&destination_square.to_string());
+
+        if let Action::MovePromotion(kind) | Action::CapturePromotion(kind) = chess_move.action() {
+            notation.push_str(&format!("={}", kind));
+        }
+
+        notation
+    }
+}
+
+// Castling with zeros and promotions without equals sign are accepted as well
+fn normalize(notation: &str) -> String {
+    notation.replace('0', "O").replace('=', "")
+}
@@ -0,0 +1,139 @@
+// Absolute imports within crate
+use crate::piece::{Color, Kind, Piece};
+// Imports from parent
+use super::{Action, Board, ChessMove, MoveStatus, Square, SquareList};
+
+// Value of the king in an exchange, which is finite so gains can still be subtracted
+const KING_VALUE: f32 = 1000.0;
+
+// Material a capture has to win for the captured piece to count as hanging, so even trades are ignored
+const HANGING_THRESHOLD: f32 = 0.5;
+
+fn value(kind: &Kind) -> f32 {
+    match kind {
+        Kind::King => KING_VALUE,
+        kind => kind.relative_value(),
+    }
+}
+
+impl Board {
+    // Static exchange evaluation: the material balance for the player making the move after all captures on the
+    // destination square, where each player always captures with their least valuable piece or stops capturing
+    // NOTE: pins and checks are ignored, castling and drops have an evaluation of zero
+    pub fn see(&self, chess_move: &ChessMove) -> f32 {
+        let destination = chess_move.destination_square();
+        let mut board = self.clone();
+
+        // Material won by the move itself and the value of the piece left on the destination square
+        let (mut gain, mut piece) = match chess_move.action() {
+            Action::ShortCastle | Action::LongCastle | Action::Drop(_) => {
+                return 0.0;
+            }
+            Action::EnPassant => {
+                let captured = Square::new(destination.file(), chess_move.origin_square().rank());
+                board.piece_placement.remove(&captured);
+                (value(&Kind::Pawn), chess_move.piece().clone())
+            }
+            Action::MovePromotion(kind) | Action::CapturePromotion(kind) => {
+                let captured = board
+                    .piece_placement
+                    .get(destination)
+                    .map_or(0.0, |piece| value(piece.kind()));
+                let promoted = Piece::new(chess_move.piece().color().clone(), kind.clone());
+                (captured + value(kind) - value(&Kind::Pawn), promoted)
+            }
+            Action::Move | Action::Capture => {
+                let captured = board
+                    .piece_placement
+                    .get(destination)
+                    .map_or(0.0, |piece| value(piece.kind()));
+                (captured, chess_move.piece().clone())
+            }
+        };
+        board.piece_placement.remove(chess_move.origin_square());
+        board
+            .piece_placement
+            .insert(destination.copy(), piece.clone());
+
+        let mut gains = vec![gain];
+        let mut color = chess_move.piece().color().opposite();
+
+        // Removing a piece from its square uncovers the x-ray attackers behind it
+        while let Some(attacker) = board.least_valuable_attacker(destination, &color) {
+            let mut capturing = board
+                .piece_placement
+                .remove(&attacker)
+                .expect("attacker is on the board");
+
+            // The king can only capture when the square is no longer defended
+            if capturing.kind() == &Kind::King && board.is_attacked(destination, &color.opposite()) {
+                break;
+            }
+
+            gain = value(piece.kind()) - gain;
+            if capturing.kind() == &Kind::Pawn && destination.rank() == color.get_eight_rank() {
+                gain += value(&Kind::Queen) - value(&Kind::Pawn);
+                capturing.promote(Kind::Queen);
+            }
+
+            gains.push(gain);
+            board
+                .piece_placement
+                .insert(destination.copy(), capturing.clone());
+            piece = capturing;
+            color = color.opposite();
+        }
+
+        // Each player can stop capturing when continuing would lose material
+        while gains.len() > 1 {
+            let last = gains.pop().expect("gains has more than one element");
+            let previous = gains.last_mut().expect("gains is not empty");
+            *previous = -f32::max(-*previous, last);
+        }
+
+        gains[0]
+    }
+
+    // Whether the static exchange evaluation of the move is at least the threshold
+    pub fn see_ge(&self, chess_move: &ChessMove, threshold: f32) -> bool {
+        self.see(chess_move) >= threshold
+    }
+
+    fn least_valuable_attacker(&self, square: &Square, color: &Color) -> Option<Square> {
+        self.attackers_of(square, color).into_iter().min_by(|a, b| {
+            let value_of = |square: &Square| value(self.piece_placement[square].kind());
+            value_of(a).total_cmp(&value_of(b))
+        })
+    }
+
+    // Pieces of the given color, except the king, that the opponent can capture while winning material
+    pub fn hanging_pieces(&self, color: &Color) -> SquareList {
+        let opponent = color.opposite();
+
+        self.piece_placement
+            .iter()
+            .filter(|(_, piece)| piece.color() == color && piece.kind() != &Kind::King)
+            .filter(|(square, _)| {
+                self.attackers_of(square, &opponent)
+                    .into_iter()
+                    .any(|attacker| {
+                        let capture = self.new_capture(&attacker, square);
+                        self.see_ge(&capture, HANGING_THRESHOLD)
+                    })
+            })
+            .map(|(square, _)| square.copy())
+            .collect()
+    }
+
+    // Capture of the piece on the destination square, promoting to a queen when a pawn reaches the last rank
+    fn new_capture(&self, origin_square: &Square, destination_square: &Square) -> ChessMove {
+        let piece = self.piece_placement[origin_square].clone();
+        let action = if piece.kind() == &Kind::Pawn && destination_square.rank() == piece.color().get_eight_rank() {
+            Action::CapturePromotion(Kind::Queen)
+        } else {
+            Action::Capture
+        };
+
+        ChessMove::new(piece, origin_square.copy(), action, destination_square.copy(), MoveStatus::None)
+    }
+}
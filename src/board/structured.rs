@@ -0,0 +1,101 @@
+// External imports
+use serde::ser::{Serialize, Serializer};
+use serde::{de, Deserialize, Deserializer};
+// Absolute imports within crate
+use crate::piece::{Color, Kind, Piece};
+// Imports from parent
+use super::{Board, CastlingAvailability, PiecePlacement, Pocket, RemainingChecks, Square};
+
+// Board with a field for every part of the position, instead of the FEN that is written by default
+// The parts that only variants use are left out for other boards
+#[derive(serde::Serialize, serde::Deserialize)]
+struct StructuredBoard {
+    #[serde(serialize_with = "sorted")]
+    piece_placement: PiecePlacement,
+    active_color: Color,
+    castling_availability: CastlingAvailability,
+    en_passant_target: Option<Square>,
+    halfmove_clock: u16,
+    fullmove_number: u16,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    remaining_checks: Option<RemainingChecks>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pocket: Option<Vec<Piece>>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    promoted_squares: Vec<Square>,
+}
+
+// Use with #[serde(with = "chess::board::structured")] on a field of type Board
+pub fn serialize<S: Serializer>(board: &Board, serializer: S) -> Result<S::Ok, S::Error> {
+    let pocket = board.pocket().map(|pocket| {
+        [Color::White, Color::Black]
+            .iter()
+            .flat_map(|color| pocket.pieces(color))
+            .cloned()
+            .collect()
+    });
+    let mut promoted_squares: Vec<Square> = board.promoted_squares.iter().cloned().collect();
+    promoted_squares.sort_by_key(|square| (-square.rank(), square.file()));
+
+    StructuredBoard {
+        piece_placement: board.piece_placement().clone(),
+        active_color: board.active_color().clone(),
+        castling_availability: board.castling_availability().clone(),
+        en_passant_target: board.en_passant_target().cloned(),
+        halfmove_clock: board.halfmove_clock(),
+        fullmove_number: board.fullmove_number(),
+        remaining_checks: board.remaining_checks().cloned(),
+        pocket,
+        promoted_squares,
+    }
+    .serialize(serializer)
+}
+
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Board, D::Error> {
+    let board = StructuredBoard::deserialize(deserializer)?;
+
+    // Castling needs a rook of the same color on the first rank, which moves and FEN rely on
+    for color in [Color::White, Color::Black] {
+        let castling_availability = &board.castling_availability;
+        let files = [
+            castling_availability.kingside_rook_file(&color),
+            castling_availability.queenside_rook_file(&color),
+        ];
+
+        for file in files.into_iter().flatten() {
+            let square = Square::new(file, color.get_first_rank());
+            let rook = Piece::new(color.clone(), Kind::Rook);
+            if board.piece_placement.get(&square) != Some(&rook) {
+                return Err(de::Error::custom(format!("castling without a rook on {}", square)));
+            }
+        }
+    }
+
+    let pocket = board
+        .pocket
+        .map(Pocket::new)
+        .transpose()
+        .map_err(de::Error::custom)?;
+
+    let mut deserialized = Board::new(
+        board.piece_placement,
+        board.active_color,
+        board.castling_availability,
+        board.en_passant_target,
+        board.halfmove_clock,
+        board.fullmove_number,
+    );
+    deserialized.set_remaining_checks(board.remaining_checks);
+    deserialized.set_pocket(pocket);
+    deserialized.set_promoted_squares(board.promoted_squares.into_iter().collect());
+
+    Ok(deserialized)
+}
+
+// Pieces from a8 to h1 as in FEN, so the same position is always written the same way
+fn sorted<S: Serializer>(piece_placement: &PiecePlacement, serializer: S) -> Result<S::Ok, S::Error> {
+    let mut pieces: Vec<_> = piece_placement.iter().collect();
+    pieces.sort_by_key(|(square, _)| (-square.rank(), square.file()));
+
+    serializer.collect_map(pieces)
+}
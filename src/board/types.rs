@@ -10,8 +10,14 @@ pub type Rank = i8;
 // Custom type alias for 8×8 board of squares
 pub type PiecePlacement = collections::HashMap<Square, Piece>;
 
+// Custom type alias for squares with pieces that were promoted from a pawn
+pub type PromotedSquares = collections::HashSet<Square>;
+
 // Custom type alias for list of chess moves
 pub type MoveList = Vec<ChessMove>;
 
 // Custom type alias for list of squares
-pub type SquareList<'a> = Vec<Square>;
+pub type SquareList = Vec<Square>;
+
+// Custom type alias for squares with the squares of the pieces attacking them
+pub type AttackMap = collections::HashMap<Square, SquareList>;
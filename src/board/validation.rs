@@ -0,0 +1,167 @@
+// Absolute imports within crate
+use crate::piece::{Color, Kind, Piece};
+// Imports from parent
+use super::{Board, PositionError, Square};
+
+// Number of pieces of each kind in the starting position, excluding pawns and the king
+const STARTING_PIECES: [(Kind, usize); 4] = [
+    (Kind::Queen, 1),
+    (Kind::Rook, 2),
+    (Kind::Bishop, 2),
+    (Kind::Knight, 2),
+];
+
+impl Board {
+    // Check whether the position can arise in a game of regular chess
+    pub fn validate(&self) -> Result<(), PositionError> {
+        for color in [Color::White, Color::Black] {
+            self.validate_material(&color)?;
+        }
+
+        self.validate_pawns()?;
+
+        for color in [Color::White, Color::Black] {
+            self.validate_castling(&color)?;
+        }
+
+        self.validate_en_passant()?;
+
+        // The player to move could otherwise capture the king
+        let opponent = self.active_color().opposite();
+        if self.is_in_check(&opponent) {
+            return Err(PositionError::OpponentInCheck(opponent));
+        }
+
+        Ok(())
+    }
+
+    fn count(&self, color: &Color, kind: &Kind) -> usize {
+        self.piece_placement()
+            .values()
+            .filter(|piece| piece.color() == color && piece.kind() == kind)
+            .count()
+    }
+
+    fn validate_material(&self, color: &Color) -> Result<(), PositionError> {
+        match self.count(color, &Kind::King) {
+            0 => {
+                return Err(PositionError::MissingKing(color.clone()));
+            }
+            1 => {}
+            _ => {
+                return Err(PositionError::TooManyKings(color.clone()));
+            }
+        }
+
+        let pawns = self.count(color, &Kind::Pawn);
+        if pawns > 8 {
+            return Err(PositionError::TooManyPawns(color.clone()));
+        }
+
+        let pieces = self
+            .piece_placement()
+            .values()
+            .filter(|piece| piece.color() == color)
+            .count();
+        if pieces > 16 {
+            return Err(PositionError::TooManyPieces(color.clone()));
+        }
+
+        // Every piece more than in the starting position needs a promoted pawn
+        let promoted_pieces: usize = STARTING_PIECES
+            .iter()
+            .map(|(kind, starting)| self.count(color, kind).saturating_sub(*starting))
+            .sum();
+        if promoted_pieces > 8 - pawns {
+            return Err(PositionError::TooManyPromotedPieces(color.clone()));
+        }
+
+        Ok(())
+    }
+
+    fn validate_pawns(&self) -> Result<(), PositionError> {
+        for rank in [1, 8] {
+            for file in 1..=8 {
+                let square = Square::new(file, rank);
+                if self
+                    .piece_placement()
+                    .get(&square)
+                    .is_some_and(|piece| piece.kind() == &Kind::Pawn)
+                {
+                    return Err(PositionError::PawnOnBackRank(square));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    // The king and the castling rooks need to be on the first rank, with the rooks on either side of the king
+    fn validate_castling(&self, color: &Color) -> Result<(), PositionError> {
+        let castling_availability = self.castling_availability();
+        let kingside = castling_availability.kingside_rook_file(color);
+        let queenside = castling_availability.queenside_rook_file(color);
+        if kingside.is_none() && queenside.is_none() {
+            return Ok(());
+        }
+
+        let first_rank = color.get_first_rank();
+        let king = Piece::new(color.clone(), Kind::King);
+        let king_file = (1..=8)
+            .find(|file| self.piece_placement().get(&Square::new(*file, first_rank)) == Some(&king))
+            .ok_or(PositionError::CastlingWithoutKing(color.clone()))?;
+
+        let rook = Piece::new(color.clone(), Kind::Rook);
+        for (rook_file, is_kingside) in [(kingside, true), (queenside, false)] {
+            if let Some(rook_file) = rook_file {
+                let is_on_correct_side = if is_kingside {
+                    rook_file > king_file
+                } else {
+                    rook_file < king_file
+                };
+
+                if !is_on_correct_side
+                    || self
+                        .piece_placement()
+                        .get(&Square::new(rook_file, first_rank))
+                        != Some(&rook)
+                {
+                    return Err(PositionError::CastlingWithoutRook(color.clone(), rook_file));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    // The opponent needs to have just moved a pawn two squares forward, passing the target square
+    fn validate_en_passant(&self) -> Result<(), PositionError> {
+        let target = match self.en_passant_target() {
+            Some(target) => target,
+            None => {
+                return Ok(());
+            }
+        };
+
+        let opponent = self.active_color().opposite();
+        let (target_rank, origin_rank, pawn_rank) = match opponent {
+            Color::White => (3, 2, 4),
+            Color::Black => (6, 7, 5),
+        };
+
+        let pawn = Piece::new(opponent, Kind::Pawn);
+        let is_valid = target.rank() == target_rank
+            && self.is_empty(target)
+            && self.is_empty(&Square::new(target.file(), origin_rank))
+            && self
+                .piece_placement()
+                .get(&Square::new(target.file(), pawn_rank))
+                == Some(&pawn);
+
+        if is_valid {
+            Ok(())
+        } else {
+            Err(PositionError::InvalidEnPassant(target.clone()))
+        }
+    }
+}
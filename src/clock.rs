@@ -0,0 +1,177 @@
+// External imports
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+// Absolute imports within crate
+use crate::piece::Color;
+
+// Relative imports of sub modules
+pub use time_control::{Delay, Stage, TimeControl};
+pub use time_source::{ManualTime, MonotonicTime, TimeSource};
+mod time_control;
+mod time_source;
+
+// Time left for one player and how far they are in the time control
+#[derive(Debug, Clone)]
+struct PlayerTime {
+    remaining: Duration,
+    stage: usize,
+    moves_in_stage: u16,
+}
+
+// Chess clock for two players, where only the clock of the player to move is running
+#[derive(Clone)]
+pub struct Clock {
+    time_control: TimeControl,
+    // Shared between threads, so games with a clock can be played on a server
+    time_source: Arc<dyn TimeSource + Send + Sync>,
+    white: PlayerTime,
+    black: PlayerTime,
+    // Player whose clock is running, with the time their turn started
+    running: Option<(Color, Duration)>,
+}
+
+impl fmt::Debug for Clock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Clock")
+            .field("time_control", &self.time_control)
+            .field("white", &self.white)
+            .field("black", &self.black)
+            .field("running", &self.running)
+            .finish()
+    }
+}
+
+impl Clock {
+    // Clock using the time of the system
+    pub fn new(time_control: TimeControl) -> Clock {
+        Clock::with_time_source(time_control, MonotonicTime::new())
+    }
+
+    pub fn with_time_source(time_control: TimeControl, time_source: impl TimeSource + Send + Sync + 'static) -> Clock {
+        let player_time = PlayerTime {
+            remaining: time_control.stage(0).time(),
+            stage: 0,
+            moves_in_stage: 0,
+        };
+
+        Clock {
+            time_control,
+            time_source: Arc::new(time_source),
+            white: player_time.clone(),
+            black: player_time,
+            running: None,
+        }
+    }
+
+    pub fn time_control(&self) -> &TimeControl {
+        &self.time_control
+    }
+
+    fn player_time(&self, color: &Color) -> &PlayerTime {
+        match color {
+            Color::White => &self.white,
+            Color::Black => &self.black,
+        }
+    }
+
+    fn player_time_mut(&mut self, color: &Color) -> &mut PlayerTime {
+        match color {
+            Color::White => &mut self.white,
+            Color::Black => &mut self.black,
+        }
+    }
+
+    // Player whose clock is running
+    pub fn running(&self) -> Option<&Color> {
+        self.running.as_ref().map(|(color, _)| color)
+    }
+
+    // Time the player has left, including the time used for the current move
+    pub fn remaining(&self, color: &Color) -> Duration {
+        let player_time = self.player_time(color);
+
+        match &self.running {
+            Some((running, started)) if running == color => {
+                let used = self.charged_time(player_time, self.time_source.now().saturating_sub(*started));
+                player_time.remaining.saturating_sub(used)
+            }
+            _ => player_time.remaining,
+        }
+    }
+
+    // Time used for a move that counts against the clock, which excludes a simple delay
+    fn charged_time(&self, player_time: &PlayerTime, elapsed: Duration) -> Duration {
+        match self.time_control.stage(player_time.stage).delay() {
+            Delay::Simple(delay) => elapsed.saturating_sub(*delay),
+            _ => elapsed,
+        }
+    }
+
+    // Player that has run out of time
+    pub fn flag_fallen(&self) -> Option<Color> {
+        [Color::White, Color::Black]
+            .into_iter()
+            .find(|color| self.remaining(color).is_zero())
+    }
+
+    // Start the clock of the given player, without changing the time of the other player
+    pub fn start(&mut self, color: Color) {
+        self.pause();
+        self.running = Some((color, self.time_source.now()));
+    }
+
+    // Stop the running clock, without adding any increment
+    pub fn pause(&mut self) {
+        if let Some((color, _)) = self.running.clone() {
+            let remaining = self.remaining(&color);
+            self.player_time_mut(&color).remaining = remaining;
+        }
+        self.running = None;
+    }
+
+    // The player has made their move, which stops their clock and starts the clock of the opponent
+    // Does not start the clock of the opponent if the flag of the player has fallen
+    pub fn press(&mut self, color: &Color) {
+        let now = self.time_source.now();
+
+        // The first move of the game is made without a running clock
+        let started = match self.running.take() {
+            Some((running, started)) if running == *color => started,
+            _ => now,
+        };
+
+        let elapsed = now.saturating_sub(started);
+        let charged = self.charged_time(self.player_time(color), elapsed);
+        let time_control = self.time_control.clone();
+        let player_time = self.player_time_mut(color);
+
+        if charged >= player_time.remaining {
+            player_time.remaining = Duration::ZERO;
+            return;
+        }
+        player_time.remaining -= charged;
+
+        // Time added after the move
+        let stage = time_control.stage(player_time.stage);
+        match stage.delay() {
+            Delay::Increment(increment) => {
+                player_time.remaining += *increment;
+            }
+            Delay::Bronstein(delay) => {
+                player_time.remaining += elapsed.min(*delay);
+            }
+            Delay::None | Delay::Simple(_) => {}
+        }
+
+        // Time of the next stage is added once all moves of a stage have been made
+        player_time.moves_in_stage += 1;
+        if stage.moves() == Some(player_time.moves_in_stage) {
+            player_time.stage += 1;
+            player_time.moves_in_stage = 0;
+            player_time.remaining += time_control.stage(player_time.stage).time();
+        }
+
+        self.running = Some((color.opposite(), now));
+    }
+}
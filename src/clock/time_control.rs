@@ -0,0 +1,137 @@
+// External imports
+use std::fmt;
+use std::time::Duration;
+// Absolute imports within crate
+use crate::parser::ParseError;
+
+// Time added for each move, or time that passes before the clock starts counting down
+#[derive(Debug, PartialEq, Clone)]
+pub enum Delay {
+    None,
+    // Fixed time added after each move (Fischer)
+    Increment(Duration),
+    // Time used for the move is added back after the move, up to a maximum (Bronstein)
+    Bronstein(Duration),
+    // Clock only starts counting down after the delay has passed (simple or US delay)
+    Simple(Duration),
+}
+
+// Period of a game, for example the first 40 moves in 90 minutes
+#[derive(Debug, PartialEq, Clone)]
+pub struct Stage {
+    // Number of moves to make in this stage, or None if it lasts until the end of the game
+    moves: Option<u16>,
+    time: Duration,
+    delay: Delay,
+}
+
+impl Stage {
+    pub fn new(moves: Option<u16>, time: Duration, delay: Delay) -> Stage {
+        Stage { moves, time, delay }
+    }
+
+    pub fn moves(&self) -> Option<u16> {
+        self.moves
+    }
+
+    pub fn time(&self) -> Duration {
+        self.time
+    }
+
+    pub fn delay(&self) -> &Delay {
+        &self.delay
+    }
+}
+
+// Time each player gets for the game, in one or more stages
+#[derive(Debug, PartialEq, Clone)]
+pub struct TimeControl {
+    stages: Vec<Stage>,
+}
+
+impl TimeControl {
+    // NOTE: the last stage should last until the end of the game, or the clock stays in the last stage anyway
+    pub fn new(stages: Vec<Stage>) -> TimeControl {
+        if stages.is_empty() {
+            panic!("time control needs at least one stage");
+        }
+
+        TimeControl { stages }
+    }
+
+    pub fn sudden_death(time: Duration) -> TimeControl {
+        TimeControl::new(vec![Stage::new(None, time, Delay::None)])
+    }
+
+    pub fn increment(time: Duration, increment: Duration) -> TimeControl {
+        TimeControl::new(vec![Stage::new(None, time, Delay::Increment(increment))])
+    }
+
+    pub fn bronstein(time: Duration, delay: Duration) -> TimeControl {
+        TimeControl::new(vec![Stage::new(None, time, Delay::Bronstein(delay))])
+    }
+
+    pub fn simple_delay(time: Duration, delay: Duration) -> TimeControl {
+        TimeControl::new(vec![Stage::new(None, time, Delay::Simple(delay))])
+    }
+
+    pub fn stages(&self) -> &[Stage] {
+        &self.stages
+    }
+
+    // Stage with the given index, where the last stage repeats
+    pub fn stage(&self, index: usize) -> &Stage {
+        &self.stages[index.min(self.stages.len() - 1)]
+    }
+
+    // Parse the value of the TimeControl tag of PGN, for example 300+2 or 40/5400+30:1800+30
+    pub fn portable_game_notation(field: &str) -> Result<TimeControl, ParseError> {
+        let stages = field
+            .split(':')
+            .map(|stage| {
+                let (moves, rest) = match stage.split_once('/') {
+                    Some((moves, rest)) => (Some(parse_number(moves)?), rest),
+                    None => (None, stage),
+                };
+                let (time, delay) = match rest.split_once('+') {
+                    Some((time, increment)) => (time, Delay::Increment(parse_seconds(increment)?)),
+                    None => (rest, Delay::None),
+                };
+
+                Ok(Stage::new(moves, parse_seconds(time)?, delay))
+            })
+            .collect::<Result<Vec<Stage>, ParseError>>()?;
+
+        Ok(TimeControl::new(stages))
+    }
+}
+
+// Value of the TimeControl tag of PGN
+// NOTE: PGN has no notation for delays, so only the time of a stage with a delay is written
+impl fmt::Display for TimeControl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, stage) in self.stages.iter().enumerate() {
+            if index > 0 {
+                write!(f, ":")?;
+            }
+            if let Some(moves) = stage.moves {
+                write!(f, "{}/", moves)?;
+            }
+            write!(f, "{}", stage.time.as_secs())?;
+            if let Delay::Increment(increment) = stage.delay {
+                write!(f, "+{}", increment.as_secs())?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn parse_number(field: &str) -> Result<u16, ParseError> {
+    field.parse().map_err(|_| ParseError::InvalidNumber)
+}
+
+fn parse_seconds(field: &str) -> Result<Duration, ParseError> {
+    let seconds = field.parse().map_err(|_| ParseError::InvalidNumber)?;
+    Ok(Duration::from_secs(seconds))
+}
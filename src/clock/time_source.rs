@@ -0,0 +1,59 @@
+// External imports
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+// Source of the current time for a clock, which can be replaced to control time in tests
+pub trait TimeSource {
+    // Time elapsed since some fixed moment, which only needs to be consistent for the same source
+    fn now(&self) -> Duration;
+}
+
+// Time of the system, which never goes backwards
+#[derive(Debug, Clone)]
+pub struct MonotonicTime {
+    start: Instant,
+}
+
+impl Default for MonotonicTime {
+    fn default() -> Self {
+        MonotonicTime::new()
+    }
+}
+
+impl MonotonicTime {
+    pub fn new() -> MonotonicTime {
+        MonotonicTime {
+            start: Instant::now(),
+        }
+    }
+}
+
+impl TimeSource for MonotonicTime {
+    fn now(&self) -> Duration {
+        self.start.elapsed()
+    }
+}
+
+// Time that only moves when it is advanced, where clones share the same time, also across threads
+#[derive(Debug, Clone, Default)]
+pub struct ManualTime {
+    now: Arc<Mutex<Duration>>,
+}
+
+impl ManualTime {
+    pub fn new() -> ManualTime {
+        ManualTime::default()
+    }
+
+    pub fn advance(&self, duration: Duration) {
+        // The time is only ever added to, so it is still valid after a panic of another thread
+        let mut now = self.now.lock().unwrap_or_else(PoisonError::into_inner);
+        *now += duration;
+    }
+}
+
+impl TimeSource for ManualTime {
+    fn now(&self) -> Duration {
+        *self.now.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
@@ -0,0 +1,353 @@
+// Absolute imports within crate
+use crate::board::{Action, Board, CastlingAvailability, ChessMove, PiecePlacement, Square};
+use crate::game::Game;
+use crate::piece::{Color, Kind, Piece};
+
+// Relative imports of sub modules
+pub use encoding_error::EncodingError;
+mod encoding_error;
+
+pub const POSITION_VERSION: u8 = 1;
+pub const GAME_VERSION: u8 = 1;
+
+// Piece codes besides the kinds of the pieces, with the highest bit set for black
+const CASTLING_ROOK: u8 = 6;
+const EN_PASSANT_PAWN: u8 = 7;
+const BLACK: u8 = 8;
+
+// Game flag for an initial position that is not the regular starting position
+const CUSTOM_POSITION: u8 = 1;
+
+// Version, bitmap of the occupied squares from a1 to h8, the pieces on these squares with four bits each,
+// the color to move, the halfmove clock and the fullmove number, which is 29 bytes for the starting position
+// Rooks that can still castle and pawns that can be captured en passant have their own piece codes
+pub fn encode_position(board: &Board) -> Result<Vec<u8>, EncodingError> {
+    if board.pocket().is_some() || board.remaining_checks().is_some() {
+        return Err(EncodingError::Unsupported(String::from("variants with pockets or checks")));
+    }
+    let halfmove_clock = u8::try_from(board.halfmove_clock())
+        .map_err(|_| EncodingError::Unsupported(format!("halfmove clock of {}", board.halfmove_clock())))?;
+
+    let castling_rooks = castling_rooks(board)?;
+    let en_passant_pawn = en_passant_pawn(board)?;
+
+    let mut occupancy: u64 = 0;
+    let mut codes = Vec::new();
+    for index in 0..64 {
+        let square = square(index);
+        if let Some(piece) = board.piece_placement().get(&square) {
+            occupancy |= 1 << index;
+
+            let kind_code = if castling_rooks.contains(&square) {
+                CASTLING_ROOK
+            } else if en_passant_pawn.as_ref() == Some(&square) {
+                EN_PASSANT_PAWN
+            } else {
+                kind_code(piece.kind())
+            };
+            let color_code = match piece.color() {
+                Color::White => 0,
+                Color::Black => BLACK,
+            };
+            codes.push(kind_code | color_code);
+        }
+    }
+
+    let mut bytes = vec![POSITION_VERSION];
+    bytes.extend_from_slice(&occupancy.to_le_bytes());
+    // Two pieces per byte, where the first piece is in the low bits
+    bytes.extend(
+        codes
+            .chunks(2)
+            .map(|pair| pair[0] | pair.get(1).map_or(0, |code| code << 4)),
+    );
+    bytes.push(match board.active_color() {
+        Color::White => 0,
+        Color::Black => 1,
+    });
+    bytes.push(halfmove_clock);
+    bytes.extend_from_slice(&board.fullmove_number().to_le_bytes());
+
+    Ok(bytes)
+}
+
+pub fn decode_position(bytes: &[u8]) -> Result<Board, EncodingError> {
+    let mut reader = Reader::new(bytes);
+    read_position(&mut reader)
+}
+
+// Version, flags and the initial position if it is not the regular starting position, followed by one byte per move
+// Every move is its index in the legal moves sorted by their squares
+// The moves after the current ply are kept, so the decoded game is at its last move
+pub fn encode_game(game: &Game) -> Result<Vec<u8>, EncodingError> {
+    let initial_position = game.initial_position();
+    let is_custom =
+        initial_position.to_forsyth_edwards_notation() != Board::starting_position().to_forsyth_edwards_notation();
+
+    let mut bytes = vec![GAME_VERSION, if is_custom { CUSTOM_POSITION } else { 0 }];
+    if is_custom {
+        bytes.extend(encode_position(initial_position)?);
+    }
+
+    let mut board = initial_position.clone();
+    for played_move in game.moves() {
+        let chess_move = played_move.chess_move();
+        let index = ordered_moves(&board)
+            .iter()
+            .position(|legal_move| move_key(legal_move) == move_key(chess_move))
+            .ok_or_else(|| EncodingError::Unsupported(format!("move {}", played_move.standard_algebraic_notation())))?;
+
+        bytes.push(index as u8);
+        board
+            .make_move(chess_move.clone())
+            .map_err(|error| EncodingError::Game(error.into()))?;
+    }
+
+    Ok(bytes)
+}
+
+pub fn decode_game(bytes: &[u8]) -> Result<Game, EncodingError> {
+    let mut reader = Reader::new(bytes);
+
+    let version = reader.byte()?;
+    if version != GAME_VERSION {
+        return Err(EncodingError::UnsupportedVersion(version));
+    }
+    let mut game = if reader.byte()? & CUSTOM_POSITION != 0 {
+        Game::from_position(read_position(&mut reader)?)
+    } else {
+        Game::new()
+    };
+
+    while let Some(index) = reader.next() {
+        let chess_move = ordered_moves(game.board())
+            .into_iter()
+            .nth(index as usize)
+            .ok_or(EncodingError::InvalidMoveIndex(index))?;
+        game.make_move(chess_move)?;
+    }
+
+    Ok(game)
+}
+
+fn read_position(reader: &mut Reader) -> Result<Board, EncodingError> {
+    let version = reader.byte()?;
+    if version != POSITION_VERSION {
+        return Err(EncodingError::UnsupportedVersion(version));
+    }
+
+    let occupancy = u64::from_le_bytes(reader.array()?);
+    let count = occupancy.count_ones() as usize;
+    let packed = reader.bytes(count.div_ceil(2))?;
+    let codes = packed
+        .iter()
+        .flat_map(|byte| [byte & 0x0f, byte >> 4])
+        .take(count);
+
+    let mut piece_placement = PiecePlacement::new();
+    let mut castling_rooks = Vec::new();
+    let mut en_passant_pawn = None;
+    let occupied = (0..64).filter(|index| occupancy & (1 << index) != 0);
+    for (index, code) in occupied.zip(codes) {
+        let square = square(index);
+        let color = if code & BLACK == 0 { Color::White } else { Color::Black };
+        let kind = match code & !BLACK {
+            // Rooks can only castle from the first rank of their color
+            CASTLING_ROOK if square.rank() == color.get_first_rank() => {
+                castling_rooks.push((color.clone(), square.file(), code));
+                Kind::Rook
+            }
+            EN_PASSANT_PAWN => {
+                en_passant_pawn = Some((square.copy(), code));
+                Kind::Pawn
+            }
+            kind_code => kind(kind_code).ok_or(EncodingError::InvalidPiece(code))?,
+        };
+        piece_placement.insert(square, Piece::new(color, kind));
+    }
+
+    let active_color = match reader.byte()? {
+        0 => Color::White,
+        1 => Color::Black,
+        byte => return Err(EncodingError::InvalidActiveColor(byte)),
+    };
+    let halfmove_clock = reader.byte()? as u16;
+    let fullmove_number = u16::from_le_bytes(reader.array()?);
+
+    // Castling rooks on the side of the king towards the h-file are for castling kingside
+    let rook_file = |color: &Color, is_kingside: bool| {
+        let king_file = piece_placement
+            .iter()
+            .find(|(_, piece)| piece.color() == color && piece.kind() == &Kind::King)
+            .map(|(square, _)| square.file())?;
+        castling_rooks
+            .iter()
+            .find(|(rook_color, file, _)| rook_color == color && (*file > king_file) == is_kingside)
+            .map(|(_, file, _)| *file)
+    };
+    let rook_files = [
+        rook_file(&Color::White, true),
+        rook_file(&Color::White, false),
+        rook_file(&Color::Black, true),
+        rook_file(&Color::Black, false),
+    ];
+
+    // Every castling rook needs a king and is the only one on its side of the king, so nothing is lost
+    for (color, file, code) in &castling_rooks {
+        let rook_files = match color {
+            Color::White => &rook_files[..2],
+            Color::Black => &rook_files[2..],
+        };
+        if !rook_files.contains(&Some(*file)) {
+            return Err(EncodingError::InvalidPiece(*code));
+        }
+    }
+    let [white_kingside, white_queenside, black_kingside, black_queenside] = rook_files;
+    let castling_availability =
+        CastlingAvailability::from_rook_files(white_kingside, white_queenside, black_kingside, black_queenside);
+
+    // The target square is behind the pawn that has just moved two squares, which is on rank 5 or 4
+    let en_passant_target = match en_passant_pawn {
+        Some((pawn, code)) => match (&active_color, pawn.rank()) {
+            (Color::White, 5) => Some(Square::new(pawn.file(), 6)),
+            (Color::Black, 4) => Some(Square::new(pawn.file(), 3)),
+            _ => return Err(EncodingError::InvalidPiece(code)),
+        },
+        None => None,
+    };
+
+    Ok(Board::new(
+        piece_placement,
+        active_color,
+        castling_availability,
+        en_passant_target,
+        halfmove_clock,
+        fullmove_number,
+    ))
+}
+
+// Squares of the rooks that can still castle
+fn castling_rooks(board: &Board) -> Result<Vec<Square>, EncodingError> {
+    let castling_availability = board.castling_availability();
+    let mut squares = Vec::new();
+
+    for color in [Color::White, Color::Black] {
+        let files = [
+            castling_availability.kingside_rook_file(&color),
+            castling_availability.queenside_rook_file(&color),
+        ];
+        for file in files.into_iter().flatten() {
+            let square = Square::new(file, color.get_first_rank());
+            if board.piece_placement().get(&square) != Some(&Piece::new(color.clone(), Kind::Rook)) {
+                return Err(EncodingError::Unsupported(format!("castling without a rook on {}", square)));
+            }
+            squares.push(square);
+        }
+    }
+
+    Ok(squares)
+}
+
+// Square of the pawn that has just moved two squares, which is in front of the en passant target square
+fn en_passant_pawn(board: &Board) -> Result<Option<Square>, EncodingError> {
+    let target = match board.en_passant_target() {
+        Some(target) => target,
+        None => return Ok(None),
+    };
+
+    let (rank, color) = match board.active_color() {
+        Color::White => (target.rank() - 1, Color::Black),
+        Color::Black => (target.rank() + 1, Color::White),
+    };
+    let square = Square::new(target.file(), rank);
+    if board.piece_placement().get(&square) != Some(&Piece::new(color, Kind::Pawn)) {
+        return Err(EncodingError::Unsupported(format!("en passant without a pawn on {}", square)));
+    }
+
+    Ok(Some(square))
+}
+
+// Legal moves in an order that does not depend on the order of the pieces on the board
+fn ordered_moves(board: &Board) -> Vec<ChessMove> {
+    let mut moves = board.legal_moves();
+    moves.sort_by_key(move_key);
+    moves
+}
+
+fn move_key(chess_move: &ChessMove) -> (u8, u8, u8) {
+    let action = match chess_move.action() {
+        Action::Move | Action::Capture | Action::EnPassant => 0,
+        Action::ShortCastle => 1,
+        Action::LongCastle => 2,
+        Action::MovePromotion(kind) | Action::CapturePromotion(kind) | Action::Drop(kind) => 3 + kind_code(kind),
+    };
+
+    (index(chess_move.origin_square()), index(chess_move.destination_square()), action)
+}
+
+fn index(square: &Square) -> u8 {
+    ((square.rank() - 1) * 8 + square.file() - 1) as u8
+}
+
+fn square(index: u8) -> Square {
+    Square::new((index % 8 + 1) as i8, (index / 8 + 1) as i8)
+}
+
+fn kind_code(kind: &Kind) -> u8 {
+    match kind {
+        Kind::Pawn => 0,
+        Kind::Knight => 1,
+        Kind::Bishop => 2,
+        Kind::Rook => 3,
+        Kind::Queen => 4,
+        Kind::King => 5,
+    }
+}
+
+fn kind(code: u8) -> Option<Kind> {
+    match code {
+        0 => Some(Kind::Pawn),
+        1 => Some(Kind::Knight),
+        2 => Some(Kind::Bishop),
+        3 => Some(Kind::Rook),
+        4 => Some(Kind::Queen),
+        5 => Some(Kind::King),
+        _ => None,
+    }
+}
+
+// Bytes that are read from the start, with an error when there are not enough of them
+struct Reader<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn new(bytes: &'a [u8]) -> Reader<'a> {
+        Reader { bytes }
+    }
+
+    fn next(&mut self) -> Option<u8> {
+        let (first, rest) = self.bytes.split_first()?;
+        self.bytes = rest;
+        Some(*first)
+    }
+
+    fn byte(&mut self) -> Result<u8, EncodingError> {
+        self.next().ok_or(EncodingError::UnexpectedEnd)
+    }
+
+    fn bytes(&mut self, count: usize) -> Result<&'a [u8], EncodingError> {
+        if self.bytes.len() < count {
+            return Err(EncodingError::UnexpectedEnd);
+        }
+
+        let (first, rest) = self.bytes.split_at(count);
+        self.bytes = rest;
+        Ok(first)
+    }
+
+    fn array<const N: usize>(&mut self) -> Result<[u8; N], EncodingError> {
+        let bytes = self.bytes(N)?;
+        Ok(bytes.try_into().expect("slice has the length of the array"))
+    }
+}
@@ -0,0 +1,23 @@
+// External imports
+use thiserror::Error;
+// Absolute imports within crate
+use crate::game::GameError;
+
+// Enum to represent the different error types
+#[derive(Error, Debug, PartialEq)]
+pub enum EncodingError {
+    #[error("unsupported encoding version: {0}")]
+    UnsupportedVersion(u8),
+    #[error("encoded data ended unexpectedly")]
+    UnexpectedEnd,
+    #[error("invalid piece code: {0}")]
+    InvalidPiece(u8),
+    #[error("invalid active color: {0}")]
+    InvalidActiveColor(u8),
+    #[error("invalid move index: {0}")]
+    InvalidMoveIndex(u8),
+    #[error("position cannot be encoded: {0}")]
+    Unsupported(String),
+    #[error(transparent)]
+    Game(#[from] GameError),
+}
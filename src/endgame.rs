@@ -0,0 +1,305 @@
+// Absolute imports within crate
+use crate::board::{Board, Outcome, PiecePlacement, Square};
+use crate::piece::{Color, Kind};
+
+// Relative imports of sub modules
+mod kpk;
+
+// The pieces of one side, excluding the king
+struct Material<'a> {
+    king: &'a Square,
+    pieces: Vec<(&'a Square, &'a Kind)>,
+}
+
+impl<'a> Material<'a> {
+    fn collect(piece_placement: &'a PiecePlacement, color: &Color) -> Option<Material<'a>> {
+        let mut king = None;
+        let mut pieces = Vec::new();
+
+        for (square, piece) in piece_placement.iter() {
+            if piece.color() != color {
+                continue;
+            }
+
+            match piece.kind() {
+                Kind::King => {
+                    king = Some(square);
+                }
+                kind => {
+                    pieces.push((square, kind));
+                }
+            }
+        }
+
+        // Without a king there is no endgame knowledge to apply
+        king.map(|king| Material { king, pieces })
+    }
+
+    fn count(&self, kind: &Kind) -> usize {
+        self.pieces.iter().filter(|(_, k)| *k == kind).count()
+    }
+
+    fn squares(&self, kind: &Kind) -> Vec<&'a Square> {
+        self.pieces
+            .iter()
+            .filter(|(_, k)| *k == kind)
+            .map(|(square, _)| *square)
+            .collect()
+    }
+
+    fn is_lone_king(&self) -> bool {
+        self.pieces.is_empty()
+    }
+
+    fn has_only(&self, kinds: &[Kind]) -> bool {
+        self.pieces.iter().all(|(_, kind)| kinds.contains(kind))
+    }
+}
+
+// Determine whether the position is a known win or draw, assuming best play
+pub fn known_outcome(board: &Board) -> Option<Outcome> {
+    let piece_placement = board.piece_placement();
+    let white = Material::collect(piece_placement, &Color::White)?;
+    let black = Material::collect(piece_placement, &Color::Black)?;
+
+    // Neither side can checkmate with the material left on the board
+    if is_insufficient_material(&white, &black) {
+        return Some(Outcome::Draw);
+    }
+
+    // Two knights cannot force checkmate against a lone king
+    if (white.is_lone_king() && black.has_only(&[Kind::Knight]) && black.count(&Kind::Knight) == 2)
+        || (black.is_lone_king() && white.has_only(&[Kind::Knight]) && white.count(&Kind::Knight) == 2)
+    {
+        return Some(Outcome::Draw);
+    }
+
+    // All further knowledge is about a lone king against some material
+    let (strong_color, strong, weak) = if black.is_lone_king() {
+        (Color::White, &white, &black)
+    } else if white.is_lone_king() {
+        (Color::Black, &black, &white)
+    } else {
+        return None;
+    };
+
+    let strong_to_move = *board.active_color() == strong_color;
+    let mut kinds: Vec<&Kind> = strong.pieces.iter().map(|(_, kind)| *kind).collect();
+    kinds.sort();
+
+    match kinds.as_slice() {
+        [Kind::Pawn] => {
+            let pawn = strong.squares(&Kind::Pawn)[0];
+
+            // The bitbase only has pawns that can be reached in a game, which lenient records do not guarantee
+            if !(2..=7).contains(&pawn.rank()) {
+                return None;
+            }
+
+            if is_king_and_pawn_win(&strong_color, strong_to_move, strong.king, weak.king, pawn) {
+                Some(Outcome::Win(strong_color))
+            } else {
+                Some(Outcome::Draw)
+            }
+        }
+        [Kind::Queen] | [Kind::Rook] | [Kind::Bishop, Kind::Knight] => {
+            // The lone king may still escape by capturing a piece or by being stalemated
+            if !strong_to_move && (can_capture_piece(board, strong, weak) || is_stalemate(board, strong, weak)) {
+                Some(Outcome::Draw)
+            } else {
+                Some(Outcome::Win(strong_color))
+            }
+        }
+        _ => {
+            if is_wrong_rook_pawn(&strong_color, strong, weak) {
+                Some(Outcome::Draw)
+            } else {
+                None
+            }
+        }
+    }
+}
+
+// The given color cannot checkmate, no matter how the opponent plays
+// This is the case for a lone king, a single minor piece, or only bishops on the same color as all other bishops
+pub fn has_insufficient_material(board: &Board, color: &Color) -> bool {
+    let piece_placement = board.piece_placement();
+    let (own, opponent) =
+        match (Material::collect(piece_placement, color), Material::collect(piece_placement, &color.opposite())) {
+            (Some(own), Some(opponent)) => (own, opponent),
+            _ => {
+                return false;
+            }
+        };
+
+    if own.is_lone_king() {
+        return true;
+    }
+    if !own.has_only(&[Kind::Bishop, Kind::Knight]) {
+        return false;
+    }
+
+    // A single knight or bishop can only checkmate if the opponent has pieces to block their own king
+    // which is not possible if the opponent has no pieces or only bishops on the same color
+    if own.pieces.len() == 1 && opponent.is_lone_king() {
+        return true;
+    }
+
+    is_insufficient_material(&own, &opponent)
+}
+
+// Only kings and minor pieces, where there is at most one minor piece or all bishops are on the same color
+fn is_insufficient_material(white: &Material, black: &Material) -> bool {
+    let minor_pieces = [Kind::Bishop, Kind::Knight];
+
+    if !white.has_only(&minor_pieces) || !black.has_only(&minor_pieces) {
+        return false;
+    }
+
+    let knights = white.count(&Kind::Knight) + black.count(&Kind::Knight);
+    let bishops: Vec<&Square> = [white.squares(&Kind::Bishop), black.squares(&Kind::Bishop)].concat();
+
+    if knights + bishops.len() <= 1 {
+        return true;
+    }
+
+    knights == 0
+        && bishops
+            .iter()
+            .all(|square| is_light_square(square) == is_light_square(bishops[0]))
+}
+
+// Probe the bitbase after normalizing the squares, so the pawn moves up the board on the A-D files
+fn is_king_and_pawn_win(
+    strong_color: &Color, strong_to_move: bool, strong_king: &Square, weak_king: &Square, pawn: &Square,
+) -> bool {
+    let mirror_files = pawn.file() > 4;
+    let normalize = |square: &Square| {
+        let file = if mirror_files {
+            8 - square.file()
+        } else {
+            square.file() - 1
+        };
+        let rank = match strong_color {
+            Color::White => square.rank() - 1,
+            Color::Black => 8 - square.rank(),
+        };
+
+        (rank * 8 + file) as u8
+    };
+
+    kpk::probe(strong_to_move, normalize(strong_king), normalize(weak_king), normalize(pawn))
+}
+
+// Pawns only on a rook file, supported by bishops that cannot control the promotion square
+// The defending king in front of the pawns can then never be driven away
+fn is_wrong_rook_pawn(strong_color: &Color, strong: &Material, weak: &Material) -> bool {
+    if !weak.is_lone_king() || !strong.has_only(&[Kind::Pawn, Kind::Bishop]) {
+        return false;
+    }
+
+    let pawns = strong.squares(&Kind::Pawn);
+    let file = match pawns.first() {
+        Some(pawn) if pawn.file() == 1 || pawn.file() == 8 => pawn.file(),
+        _ => {
+            return false;
+        }
+    };
+    if pawns.iter().any(|pawn| pawn.file() != file) {
+        return false;
+    }
+
+    let promotion_square = Square::new(file, strong_color.get_eight_rank());
+    let bishops_are_wrong_color = strong
+        .squares(&Kind::Bishop)
+        .iter()
+        .all(|bishop| is_light_square(bishop) != is_light_square(&promotion_square));
+
+    bishops_are_wrong_color && distance(weak.king, &promotion_square) <= 1
+}
+
+// The lone king can take a piece that is next to it and not defended
+fn can_capture_piece(board: &Board, strong: &Material, weak: &Material) -> bool {
+    strong
+        .pieces
+        .iter()
+        .any(|(square, _)| distance(weak.king, square) == 1 && !is_attacked_by(board, strong, square, Some(weak.king)))
+}
+
+// The lone king is not in check, but every square around it is
+fn is_stalemate(board: &Board, strong: &Material, weak: &Material) -> bool {
+    if is_attacked_by(board, strong, weak.king, None) {
+        return false;
+    }
+
+    weak.king
+        .squares_on_king_move()
+        .iter()
+        .all(|square| is_attacked_by(board, strong, square, Some(weak.king)))
+}
+
+// Whether any piece of the given material attacks the square, optionally looking through the lone king
+fn is_attacked_by(board: &Board, material: &Material, target: &Square, ignore: Option<&Square>) -> bool {
+    let is_blocked = |square: &Square| Some(square) != ignore && board.piece_placement().contains_key(square);
+    let is_on_line = |line: Vec<Square>| {
+        line.iter()
+            .position(|square| square == target)
+            .is_some_and(|position| !line[..position].iter().any(is_blocked))
+    };
+
+    if distance(material.king, target) == 1 {
+        return true;
+    }
+
+    material.pieces.iter().any(|(square, kind)| match kind {
+        Kind::Knight => square.squares_on_knight_moves().contains(target),
+        Kind::Bishop | Kind::Queen | Kind::Rook => {
+            let mut lines = Vec::new();
+
+            if *kind != &Kind::Rook {
+                lines.push(square.squares_on_top_right_diagonal());
+                lines.push(square.squares_on_top_left_diagonal());
+                lines.push(square.squares_on_bottom_right_diagonal());
+                lines.push(square.squares_on_bottom_left_diagonal());
+            }
+            if *kind != &Kind::Bishop {
+                lines.push(square.squares_on_up_vertical());
+                lines.push(square.squares_on_down_vertical());
+                lines.push(square.squares_on_right_horizontal());
+                lines.push(square.squares_on_left_horizontal());
+            }
+
+            lines.into_iter().any(is_on_line)
+        }
+        Kind::Pawn => {
+            let direction = match board
+                .piece_placement()
+                .get(material.king)
+                .map(|king| king.color())
+            {
+                Some(Color::Black) => -1,
+                _ => 1,
+            };
+
+            target.rank() == square.rank() + direction && (target.file() - square.file()).abs() == 1
+        }
+        Kind::King => false,
+    })
+}
+
+// The mating corners for king, bishop and knight against king are the ones with the same color as the bishop
+pub fn mating_corners(bishop: &Square) -> [Square; 2] {
+    if is_light_square(bishop) {
+        [Square::new(1, 8), Square::new(8, 1)]
+    } else {
+        [Square::new(1, 1), Square::new(8, 8)]
+    }
+}
+
+fn is_light_square(square: &Square) -> bool {
+    (square.file() + square.rank()) % 2 == 1
+}
+
+fn distance(a: &Square, b: &Square) -> i8 {
+    (a.file() - b.file()).abs().max((a.rank() - b.rank()).abs())
+}
@@ -0,0 +1,193 @@
+// External imports
+use std::sync::OnceLock;
+
+// Squares in this module are numbered 0 to 63, starting at A1 and going file by file, then rank by rank
+type Index = u8;
+
+// All positions: 2 sides to move × 24 pawn squares (files A-D, ranks 2-7) × 64 × 64 king squares
+const MAX_INDEX: usize = 2 * 24 * 64 * 64;
+
+// Results of the analysis, which are combined as bit flags while classifying a position
+const INVALID: u8 = 0;
+const UNKNOWN: u8 = 1;
+const DRAW: u8 = 2;
+const WIN: u8 = 4;
+
+// Computed on first use, where a set bit means the position is a win for the side with the pawn
+static BITBASE: OnceLock<Vec<u64>> = OnceLock::new();
+
+// Position from the perspective of the stronger side, with the pawn moving up the board
+struct Position {
+    strong_to_move: bool,
+    strong_king: Index,
+    weak_king: Index,
+    pawn: Index,
+}
+
+fn file_of(square: Index) -> i8 {
+    (square % 8) as i8
+}
+
+fn rank_of(square: Index) -> i8 {
+    (square / 8) as i8
+}
+
+fn distance(a: Index, b: Index) -> i8 {
+    (file_of(a) - file_of(b))
+        .abs()
+        .max((rank_of(a) - rank_of(b)).abs())
+}
+
+fn king_moves(square: Index) -> impl Iterator<Item = Index> {
+    (0..64).filter(move |destination| distance(square, *destination) == 1)
+}
+
+fn is_attacked_by_pawn(pawn: Index, square: Index) -> bool {
+    rank_of(square) == rank_of(pawn) + 1 && (file_of(square) - file_of(pawn)).abs() == 1
+}
+
+// Encode a position into an index of the bitbase
+// NOTE: the pawn needs to be normalized to the A-D files and ranks 2-7
+fn index(strong_to_move: bool, strong_king: Index, weak_king: Index, pawn: Index) -> usize {
+    let side = if strong_to_move { 0 } else { 1 };
+    let pawn_file = file_of(pawn) as usize;
+    let pawn_rank = (6 - rank_of(pawn)) as usize;
+
+    strong_king as usize | (weak_king as usize) << 6 | side << 12 | pawn_file << 13 | pawn_rank << 15
+}
+
+// Decode an index of the bitbase back into a position
+fn position(index: usize) -> Position {
+    Position {
+        strong_king: (index & 0x3F) as Index,
+        weak_king: ((index >> 6) & 0x3F) as Index,
+        strong_to_move: (index >> 12) & 0x01 == 0,
+        pawn: (8 * (6 - ((index >> 15) & 0x07)) + ((index >> 13) & 0x03)) as Index,
+    }
+}
+
+// Result that can be determined by looking only at the position itself
+fn initial_result(position: &Position) -> u8 {
+    let Position {
+        strong_to_move,
+        strong_king,
+        weak_king,
+        pawn,
+    } = *position;
+
+    // Kings next to each other, pieces on the same square or the weak king in check while it is not its turn
+    if distance(strong_king, weak_king) <= 1
+        || strong_king == pawn
+        || weak_king == pawn
+        || (strong_to_move && is_attacked_by_pawn(pawn, weak_king))
+    {
+        return INVALID;
+    }
+
+    // Pawn on the 7th rank can promote without the queen being captured
+    if strong_to_move
+        && rank_of(pawn) == 6
+        && strong_king != pawn + 8
+        && (distance(weak_king, pawn + 8) > 1 || distance(strong_king, pawn + 8) == 1)
+    {
+        return WIN;
+    }
+
+    if !strong_to_move {
+        // Weak king has no squares to go to
+        let is_stalemate =
+            king_moves(weak_king).all(|square| distance(strong_king, square) <= 1 || is_attacked_by_pawn(pawn, square));
+
+        // Weak king can capture the undefended pawn
+        let can_capture_pawn = distance(weak_king, pawn) == 1 && distance(strong_king, pawn) > 1;
+
+        if is_stalemate || can_capture_pawn {
+            return DRAW;
+        }
+    }
+
+    UNKNOWN
+}
+
+// Result based on the results of all positions that can be reached in one move
+fn classify(position: &Position, results: &[u8]) -> u8 {
+    let Position {
+        strong_to_move,
+        strong_king,
+        weak_king,
+        pawn,
+    } = *position;
+
+    // Combine results of all king moves of the side to move
+    let mut result = if strong_to_move {
+        king_moves(strong_king).fold(INVALID, |result, square| result | results[index(false, square, weak_king, pawn)])
+    } else {
+        king_moves(weak_king).fold(INVALID, |result, square| result | results[index(true, strong_king, square, pawn)])
+    };
+
+    // Combine results of pawn moves
+    if strong_to_move {
+        // Single step forward, promotions have been handled by the initial result
+        if rank_of(pawn) < 6 {
+            result |= results[index(false, strong_king, weak_king, pawn + 8)];
+        }
+
+        // Double step forward from the second rank
+        if rank_of(pawn) == 1 && pawn + 8 != strong_king && pawn + 8 != weak_king {
+            result |= results[index(false, strong_king, weak_king, pawn + 16)];
+        }
+    }
+
+    // The side to move picks the best result for themselves
+    let (good, bad) = if strong_to_move { (WIN, DRAW) } else { (DRAW, WIN) };
+
+    if result & good != 0 {
+        good
+    } else if result & UNKNOWN != 0 {
+        UNKNOWN
+    } else {
+        bad
+    }
+}
+
+// Retrograde analysis, repeating until no more positions can be resolved
+fn generate() -> Vec<u64> {
+    let mut results: Vec<u8> = (0..MAX_INDEX)
+        .map(|index| initial_result(&position(index)))
+        .collect();
+
+    let mut changed = true;
+    while changed {
+        changed = false;
+
+        for index in 0..MAX_INDEX {
+            if results[index] == UNKNOWN {
+                let result = classify(&position(index), &results);
+
+                if result != UNKNOWN {
+                    results[index] = result;
+                    changed = true;
+                }
+            }
+        }
+    }
+
+    // Any positions that are still unknown cannot be won, so pack only the wins into bits
+    let mut bitbase = vec![0; MAX_INDEX / 64];
+    for (index, result) in results.into_iter().enumerate() {
+        if result == WIN {
+            bitbase[index / 64] |= 1 << (index % 64);
+        }
+    }
+
+    bitbase
+}
+
+// Whether the side with the pawn wins
+// NOTE: squares need to be normalized, so the pawn moves up the board and is on the A-D files
+pub fn probe(strong_to_move: bool, strong_king: Index, weak_king: Index, pawn: Index) -> bool {
+    let bitbase = BITBASE.get_or_init(generate);
+    let index = index(strong_to_move, strong_king, weak_king, pawn);
+
+    bitbase[index / 64] & (1 << (index % 64)) != 0
+}
@@ -0,0 +1,433 @@
+// Absolute imports within crate
+use crate::board::{Board, ChessMove, Outcome};
+use crate::clock::Clock;
+use crate::endgame;
+use crate::piece::Color;
+
+// Relative imports of sub modules
+pub use adjudicator::{Adjudicator, KnownOutcomeAdjudicator, ScoreAdjudicator};
+pub use annotation::{Annotation, AnnotationColor, Arrow, Highlight};
+pub use game_error::GameError;
+pub use game_tree::{GameTree, Node, NodeId};
+pub use played_move::PlayedMove;
+pub use tags::{Tags, SEVEN_TAG_ROSTER};
+pub use termination::Termination;
+mod adjudicator;
+mod annotation;
+mod game_error;
+mod game_tree;
+mod played_move;
+pub(crate) mod portable_game_notation;
+mod tags;
+mod termination;
+
+// A game from its initial position, with all moves played so far and its metadata
+// Moves can be undone and redone, which only changes the current position until a new move is made
+#[derive(Debug, Clone)]
+pub struct Game {
+    tags: Tags,
+    // Position before each move, followed by the position after the last move
+    positions: Vec<Board>,
+    moves: Vec<PlayedMove>,
+    ply: usize,
+    outcome: Option<Outcome>,
+    termination: Option<Termination>,
+    draw_offer: Option<Color>,
+    clock: Option<Clock>,
+}
+
+impl Default for Game {
+    fn default() -> Self {
+        Game::new()
+    }
+}
+
+impl Game {
+    // Game from the regular starting position
+    pub fn new() -> Game {
+        Game::from_position(Board::starting_position())
+    }
+
+    pub fn from_position(board: Board) -> Game {
+        Game {
+            tags: Tags::new(),
+            positions: vec![board],
+            moves: Vec::new(),
+            ply: 0,
+            outcome: None,
+            termination: None,
+            draw_offer: None,
+            clock: None,
+        }
+    }
+
+    pub fn portable_game_notation(record: &str) -> Result<Game, GameError> {
+        portable_game_notation::parse(record)
+    }
+
+    // Read every game of a file with multiple records, where a game that cannot be read does not stop the others
+    pub fn portable_game_notation_collection(records: &str) -> Vec<Result<Game, GameError>> {
+        portable_game_notation::split(records)
+            .iter()
+            .map(|record| portable_game_notation::parse(record))
+            .collect()
+    }
+
+    pub fn to_portable_game_notation(&self) -> String {
+        portable_game_notation::format(self)
+    }
+
+    pub fn tags(&self) -> &Tags {
+        &self.tags
+    }
+
+    pub fn tag(&self, name: &str) -> Option<&str> {
+        self.tags.get(name)
+    }
+
+    pub fn set_tag(&mut self, name: &str, value: &str) {
+        self.tags.set(name, value);
+    }
+
+    pub fn initial_position(&self) -> &Board {
+        &self.positions[0]
+    }
+
+    // Position at the current ply
+    pub fn board(&self) -> &Board {
+        &self.positions[self.ply]
+    }
+
+    // All moves of the game, including the ones after the current ply that have been undone
+    pub fn moves(&self) -> &[PlayedMove] {
+        &self.moves
+    }
+
+    // Number of moves made to get to the current position
+    pub fn ply(&self) -> usize {
+        self.ply
+    }
+
+    // Result of the game, which is only known once the last move of the game has been made
+    pub fn outcome(&self) -> Option<&Outcome> {
+        self.outcome.as_ref()
+    }
+
+    pub(crate) fn set_outcome(&mut self, outcome: Option<Outcome>) {
+        self.outcome = outcome;
+    }
+
+    pub fn clock(&self) -> Option<&Clock> {
+        self.clock.as_ref()
+    }
+
+    // Play with a clock, which is switched with every move and starts running after the first move
+    pub fn set_clock(&mut self, clock: Clock) {
+        self.set_tag("TimeControl", &clock.time_control().to_string());
+        self.clock = Some(clock);
+    }
+
+    // Reason the game has ended, which is only known once the game is over
+    pub fn termination(&self) -> Option<&Termination> {
+        self.termination.as_ref()
+    }
+
+    // Player who has offered a draw that has not been accepted or declined yet
+    pub fn draw_offer(&self) -> Option<&Color> {
+        self.draw_offer.as_ref()
+    }
+
+    // End the game if the player to move has run out of time, returns the outcome of the game
+    pub fn check_time(&mut self) -> Option<&Outcome> {
+        let flag_fallen = self.clock.as_ref().and_then(|clock| clock.flag_fallen());
+
+        if let (false, Some(color)) = (self.is_over(), flag_fallen) {
+            // The opponent only wins if they could still checkmate
+            let opponent = color.opposite();
+            let outcome = if endgame::has_insufficient_material(self.board(), &opponent) {
+                Outcome::Draw
+            } else {
+                Outcome::Win(opponent)
+            };
+
+            self.finish(Some(outcome), Termination::TimeForfeit);
+        }
+
+        self.outcome.as_ref()
+    }
+
+    // Make a legal move in the current position, which removes any moves that have been undone
+    pub fn make_move(&mut self, chess_move: ChessMove) -> Result<(), GameError> {
+        self.check_time();
+        self.ensure_not_over()?;
+
+        // Use the legal move itself, so the move always has the same details as a generated move
+        let board = self.board();
+        let chess_move = board
+            .legal_moves()
+            .into_iter()
+            .find(|legal_move| {
+                legal_move.origin_square() == chess_move.origin_square()
+                    && legal_move.destination_square() == chess_move.destination_square()
+                    && legal_move.action() == chess_move.action()
+            })
+            .ok_or_else(|| GameError::IllegalMove(chess_move.to_string()))?;
+        let notation = board.to_standard_algebraic_notation(&chess_move);
+
+        let color: Color = board.active_color().clone();
+        let mut board = board.clone();
+        board.make_move(chess_move.clone())?;
+
+        self.moves.truncate(self.ply);
+        self.positions.truncate(self.ply + 1);
+        self.moves.push(PlayedMove::new(chess_move, notation));
+        self.positions.push(board);
+        self.ply += 1;
+
+        // Making a move declines a draw offer of the opponent
+        if self
+            .draw_offer
+            .as_ref()
+            .is_some_and(|offer| *offer != color)
+        {
+            self.draw_offer = None;
+        }
+
+        // Any earlier end of the game came from a position that has now been replaced
+        self.outcome = None;
+        self.termination = None;
+        self.tags.remove("Termination");
+
+        if let Some(clock) = &mut self.clock {
+            clock.press(&color);
+        }
+
+        match self.automatic_end() {
+            Some((outcome, termination)) => self.finish(outcome, termination),
+            // The move could have been made after the time had run out
+            None => {
+                self.check_time();
+            }
+        }
+
+        Ok(())
+    }
+
+    // Checkmate, stalemate and the draws that do not need to be claimed
+    fn automatic_end(&self) -> Option<(Option<Outcome>, Termination)> {
+        let board = self.board();
+
+        // Repetitions are not part of the outcome of the board, since it does not know the earlier positions
+        let outcome = match board.outcome() {
+            Some(outcome) => outcome,
+            None => {
+                return self
+                    .is_fivefold_repetition()
+                    .then_some((Some(Outcome::Draw), Termination::FivefoldRepetition))
+            }
+        };
+
+        let termination = if let Outcome::Win(_) = outcome {
+            Termination::Checkmate
+        } else if board.legal_moves().is_empty() {
+            Termination::Stalemate
+        } else if board.is_insufficient_material() {
+            Termination::InsufficientMaterial
+        } else {
+            Termination::SeventyFiveMoveRule
+        };
+
+        Some((Some(outcome), termination))
+    }
+
+    // Make a move written in standard algebraic notation, for example Nf3
+    pub fn make_standard_algebraic_notation_move(&mut self, notation: &str) -> Result<(), GameError> {
+        self.check_time();
+        self.ensure_not_over()?;
+        let chess_move = self.board().parse_standard_algebraic_notation(notation)?;
+        self.make_move(chess_move)
+    }
+
+    // Whether the game has ended, including games that were abandoned without a result
+    pub fn is_over(&self) -> bool {
+        self.outcome.is_some() || self.termination.is_some()
+    }
+
+    // No more moves can be made after the last move of a finished game
+    // A game that was ended by the players or the clock stays over, even in an earlier position
+    fn ensure_not_over(&self) -> Result<(), GameError> {
+        let is_from_position = self
+            .termination
+            .as_ref()
+            .is_some_and(Termination::is_from_position);
+
+        if self.is_over() && (self.ply == self.moves.len() || !is_from_position) {
+            Err(GameError::GameOver)
+        } else {
+            Ok(())
+        }
+    }
+
+    // Actions besides moves can only be done in the last position of a game that is still going on
+    fn ensure_playing(&self) -> Result<(), GameError> {
+        self.ensure_not_over()?;
+
+        if self.ply != self.moves.len() {
+            return Err(GameError::InvalidPly(self.ply));
+        }
+
+        Ok(())
+    }
+
+    // End the game, with the reason as Termination tag
+    fn finish(&mut self, outcome: Option<Outcome>, termination: Termination) {
+        self.set_tag("Termination", termination.portable_game_notation());
+        self.outcome = outcome;
+        self.termination = Some(termination);
+        self.draw_offer = None;
+
+        if let Some(clock) = &mut self.clock {
+            clock.pause();
+        }
+    }
+
+    pub fn resign(&mut self, color: &Color) -> Result<(), GameError> {
+        self.check_time();
+        self.ensure_playing()?;
+
+        self.finish(Some(Outcome::Win(color.opposite())), Termination::Resignation);
+        Ok(())
+    }
+
+    // Offer a draw, which stands until the opponent accepts or declines it, or makes a move
+    pub fn offer_draw(&mut self, color: &Color) -> Result<(), GameError> {
+        self.check_time();
+        self.ensure_playing()?;
+
+        self.draw_offer = Some(color.clone());
+        Ok(())
+    }
+
+    // Accept the draw offered by the opponent
+    pub fn accept_draw(&mut self, color: &Color) -> Result<(), GameError> {
+        self.check_time();
+        self.ensure_playing()?;
+
+        if self.draw_offer != Some(color.opposite()) {
+            return Err(GameError::NoDrawOffer);
+        }
+
+        self.finish(Some(Outcome::Draw), Termination::DrawAgreement);
+        Ok(())
+    }
+
+    pub fn decline_draw(&mut self, color: &Color) -> Result<(), GameError> {
+        if self.draw_offer != Some(color.opposite()) {
+            return Err(GameError::NoDrawOffer);
+        }
+
+        self.draw_offer = None;
+        Ok(())
+    }
+
+    // Claim a draw because the current position has occurred three times
+    pub fn claim_threefold_repetition(&mut self) -> Result<(), GameError> {
+        self.check_time();
+        self.ensure_playing()?;
+
+        if !self.is_threefold_repetition() {
+            return Err(GameError::InvalidClaim(Termination::ThreefoldRepetition));
+        }
+
+        self.finish(Some(Outcome::Draw), Termination::ThreefoldRepetition);
+        Ok(())
+    }
+
+    // Claim a draw because no pawn has moved and nothing has been captured in the last fifty moves
+    pub fn claim_fifty_move_rule(&mut self) -> Result<(), GameError> {
+        self.check_time();
+        self.ensure_playing()?;
+
+        if self.board().halfmove_clock() < 100 {
+            return Err(GameError::InvalidClaim(Termination::FiftyMoveRule));
+        }
+
+        self.finish(Some(Outcome::Draw), Termination::FiftyMoveRule);
+        Ok(())
+    }
+
+    // Claim a draw for any reason that applies to the current position
+    pub fn claim_draw(&mut self) -> Result<(), GameError> {
+        self.claim_threefold_repetition()
+            .or_else(|_| self.claim_fifty_move_rule())
+    }
+
+    // Stop the game without a result
+    pub fn abort(&mut self) -> Result<(), GameError> {
+        self.ensure_playing()?;
+
+        self.finish(None, Termination::Abandoned);
+        Ok(())
+    }
+
+    // Let an arbiter decide the result of the game, for example with a tablebase or an engine evaluation
+    // Returns the outcome if the adjudicator has decided the game
+    pub fn adjudicate(&mut self, adjudicator: &dyn Adjudicator) -> Result<Option<&Outcome>, GameError> {
+        self.check_time();
+        self.ensure_playing()?;
+
+        if let Some(outcome) = adjudicator.adjudicate(self.board()) {
+            self.finish(Some(outcome), Termination::Adjudication);
+        }
+
+        Ok(self.outcome.as_ref())
+    }
+
+    // Go back one move, returns false if already at the initial position
+    pub fn undo(&mut self) -> bool {
+        if self.ply == 0 {
+            return false;
+        }
+
+        self.ply -= 1;
+        true
+    }
+
+    // Go forward one move that has been undone, returns false if already at the last move
+    pub fn redo(&mut self) -> bool {
+        if self.ply == self.moves.len() {
+            return false;
+        }
+
+        self.ply += 1;
+        true
+    }
+
+    // Go to the position after the given number of moves
+    pub fn go_to_ply(&mut self, ply: usize) -> Result<(), GameError> {
+        if ply > self.moves.len() {
+            return Err(GameError::InvalidPly(ply));
+        }
+
+        self.ply = ply;
+        Ok(())
+    }
+
+    // Number of times the current position has occurred so far, including the current one
+    pub fn repetition_count(&self) -> usize {
+        let board = self.board();
+
+        self.positions[..=self.ply]
+            .iter()
+            .filter(|position| position.is_same_position(board))
+            .count()
+    }
+
+    pub fn is_threefold_repetition(&self) -> bool {
+        self.repetition_count() >= 3
+    }
+
+    pub fn is_fivefold_repetition(&self) -> bool {
+        self.repetition_count() >= 5
+    }
+}
@@ -0,0 +1,45 @@
+// Absolute imports within crate
+use crate::board::{Board, Outcome};
+use crate::piece::Color;
+
+// Decides the result of a game that has not ended yet, for example when the result is clear to an engine
+pub trait Adjudicator {
+    fn adjudicate(&self, board: &Board) -> Option<Outcome>;
+}
+
+// Result from endgame knowledge, which is exact for the positions it covers like a tablebase
+#[derive(Debug, Clone, Default)]
+pub struct KnownOutcomeAdjudicator;
+
+impl Adjudicator for KnownOutcomeAdjudicator {
+    fn adjudicate(&self, board: &Board) -> Option<Outcome> {
+        board.known_outcome()
+    }
+}
+
+// Win for a player once the evaluation is at least the threshold in their favour
+// The evaluation is in centipawns from the point of view of white
+pub struct ScoreAdjudicator<F: Fn(&Board) -> i32> {
+    threshold: i32,
+    evaluate: F,
+}
+
+impl<F: Fn(&Board) -> i32> ScoreAdjudicator<F> {
+    pub fn new(threshold: i32, evaluate: F) -> ScoreAdjudicator<F> {
+        ScoreAdjudicator { threshold, evaluate }
+    }
+}
+
+impl<F: Fn(&Board) -> i32> Adjudicator for ScoreAdjudicator<F> {
+    fn adjudicate(&self, board: &Board) -> Option<Outcome> {
+        let score = (self.evaluate)(board);
+
+        if score >= self.threshold {
+            Some(Outcome::Win(Color::White))
+        } else if score <= -self.threshold {
+            Some(Outcome::Win(Color::Black))
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,272 @@
+// Absolute imports within crate
+use crate::board::Square;
+use crate::parser;
+
+// Colors of arrows and highlighted squares, as used by most GUIs
+#[derive(Debug, PartialEq, Clone)]
+pub enum AnnotationColor {
+    Red,
+    Green,
+    Blue,
+    Yellow,
+}
+
+impl AnnotationColor {
+    fn parse(character: char) -> Option<AnnotationColor> {
+        match character {
+            'R' => Some(AnnotationColor::Red),
+            'G' => Some(AnnotationColor::Green),
+            'B' => Some(AnnotationColor::Blue),
+            'Y' => Some(AnnotationColor::Yellow),
+            _ => None,
+        }
+    }
+
+    fn letter(&self) -> char {
+        match self {
+            AnnotationColor::Red => 'R',
+            AnnotationColor::Green => 'G',
+            AnnotationColor::Blue => 'B',
+            AnnotationColor::Yellow => 'Y',
+        }
+    }
+}
+
+// Arrow drawn from one square to another, written as [%cal Ge2e4] in a comment
+#[derive(Debug, PartialEq, Clone)]
+pub struct Arrow {
+    color: AnnotationColor,
+    from: Square,
+    to: Square,
+}
+
+impl Arrow {
+    pub fn new(color: AnnotationColor, from: Square, to: Square) -> Arrow {
+        Arrow { color, from, to }
+    }
+
+    pub fn color(&self) -> &AnnotationColor {
+        &self.color
+    }
+
+    pub fn from(&self) -> &Square {
+        &self.from
+    }
+
+    pub fn to(&self) -> &Square {
+        &self.to
+    }
+}
+
+// Highlighted square, written as [%csl Rd4] in a comment
+#[derive(Debug, PartialEq, Clone)]
+pub struct Highlight {
+    color: AnnotationColor,
+    square: Square,
+}
+
+impl Highlight {
+    pub fn new(color: AnnotationColor, square: Square) -> Highlight {
+        Highlight { color, square }
+    }
+
+    pub fn color(&self) -> &AnnotationColor {
+        &self.color
+    }
+
+    pub fn square(&self) -> &Square {
+        &self.square
+    }
+}
+
+// Everything that can be attached to a move besides the move itself
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Annotation {
+    // Comment before the move, only written for the first move of a variation
+    starting_comment: Option<String>,
+    comment: Option<String>,
+    // Numeric annotation glyphs, for example 1 for a good move (!) and 2 for a mistake (?)
+    nags: Vec<u8>,
+    arrows: Vec<Arrow>,
+    highlights: Vec<Highlight>,
+}
+
+impl Annotation {
+    pub fn starting_comment(&self) -> Option<&str> {
+        self.starting_comment.as_deref()
+    }
+
+    pub fn set_starting_comment(&mut self, comment: Option<String>) {
+        self.starting_comment = comment;
+    }
+
+    pub fn comment(&self) -> Option<&str> {
+        self.comment.as_deref()
+    }
+
+    pub fn set_comment(&mut self, comment: Option<String>) {
+        self.comment = comment;
+    }
+
+    pub fn nags(&self) -> &[u8] {
+        &self.nags
+    }
+
+    pub fn add_nag(&mut self, nag: u8) {
+        if !self.nags.contains(&nag) {
+            self.nags.push(nag);
+        }
+    }
+
+    pub fn remove_nag(&mut self, nag: u8) {
+        self.nags.retain(|existing| *existing != nag);
+    }
+
+    pub fn arrows(&self) -> &[Arrow] {
+        &self.arrows
+    }
+
+    pub fn add_arrow(&mut self, arrow: Arrow) {
+        self.arrows.push(arrow);
+    }
+
+    pub fn highlights(&self) -> &[Highlight] {
+        &self.highlights
+    }
+
+    pub fn add_highlight(&mut self, highlight: Highlight) {
+        self.highlights.push(highlight);
+    }
+
+    pub fn clear_drawings(&mut self) {
+        self.arrows.clear();
+        self.highlights.clear();
+    }
+
+    // Add the text of a PGN comment, taking out the arrow and highlight commands
+    pub(crate) fn add_comment(&mut self, text: &str) {
+        let text = self.take_drawings(text);
+        if !text.is_empty() {
+            self.comment = Some(match self.comment.take() {
+                Some(comment) => format!("{} {}", comment, text),
+                None => text,
+            });
+        }
+    }
+
+    // Text of the PGN comment after the move, with the arrows and highlights as commands in front
+    pub(crate) fn format_comment(&self) -> Option<String> {
+        let mut parts = Vec::new();
+
+        if !self.highlights.is_empty() {
+            let highlights: Vec<String> = self
+                .highlights
+                .iter()
+                .map(|highlight| format!("{}{}", highlight.color.letter(), highlight.square))
+                .collect();
+            parts.push(format!("[%csl {}]", highlights.join(",")));
+        }
+        if !self.arrows.is_empty() {
+            let arrows: Vec<String> = self
+                .arrows
+                .iter()
+                .map(|arrow| format!("{}{}{}", arrow.color.letter(), arrow.from, arrow.to))
+                .collect();
+            parts.push(format!("[%cal {}]", arrows.join(",")));
+        }
+        if let Some(comment) = &self.comment {
+            parts.push(comment.clone());
+        }
+
+        (!parts.is_empty()).then(|| parts.join(" "))
+    }
+
+    // Collect the drawings from [%cal ...] and [%csl ...] commands, returns the remaining text
+    fn take_drawings(&mut self, text: &str) -> String {
+        let mut remaining = String::new();
+        let mut rest = text;
+
+        while let Some(start) = rest.find("[%") {
+            let end = match rest[start..].find(']') {
+                Some(end) => start + end,
+                None => {
+                    break;
+                }
+            };
+            let command = &rest[start + 2..end];
+
+            let is_drawing = match command.split_once(' ') {
+                Some(("cal", arguments)) => self.parse_arrows(arguments),
+                Some(("csl", arguments)) => self.parse_highlights(arguments),
+                _ => false,
+            };
+
+            // Other commands, like clock times, are kept as part of the comment
+            remaining.push_str(&rest[..start]);
+            if !is_drawing {
+                remaining.push_str(&rest[start..=end]);
+            }
+            rest = &rest[end + 1..];
+        }
+        remaining.push_str(rest);
+
+        remaining
+            .split_whitespace()
+            .collect::<Vec<&str>>()
+            .join(" ")
+    }
+
+    fn parse_arrows(&mut self, arguments: &str) -> bool {
+        let arrows: Option<Vec<Arrow>> = arguments
+            .split(',')
+            .map(|argument| {
+                let mut characters = argument.trim().chars();
+                let color = AnnotationColor::parse(characters.next()?)?;
+                let from = parse_square(&mut characters)?;
+                let to = parse_square(&mut characters)?;
+                characters
+                    .next()
+                    .is_none()
+                    .then_some(Arrow::new(color, from, to))
+            })
+            .collect();
+
+        match arrows {
+            Some(mut arrows) => {
+                self.arrows.append(&mut arrows);
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn parse_highlights(&mut self, arguments: &str) -> bool {
+        let highlights: Option<Vec<Highlight>> = arguments
+            .split(',')
+            .map(|argument| {
+                let mut characters = argument.trim().chars();
+                let color = AnnotationColor::parse(characters.next()?)?;
+                let square = parse_square(&mut characters)?;
+                characters
+                    .next()
+                    .is_none()
+                    .then_some(Highlight::new(color, square))
+            })
+            .collect();
+
+        match highlights {
+            Some(mut highlights) => {
+                self.highlights.append(&mut highlights);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+fn parse_square(characters: &mut impl Iterator<Item = char>) -> Option<Square> {
+    let file = parser::parse_file(characters.next()?).ok()?;
+    let rank = parser::parse_rank(characters.next()?).ok()?;
+
+    Some(Square::new(file, rank))
+}
@@ -0,0 +1,30 @@
+// External imports
+use thiserror::Error;
+// Absolute imports within crate
+use crate::board::BoardError;
+use crate::parser::ParseError;
+// Imports from parent
+use super::Termination;
+
+// Enum to represent the different error types
+#[derive(Error, Debug, PartialEq)]
+pub enum GameError {
+    #[error("illegal move: {0}")]
+    IllegalMove(String),
+    #[error("no position at ply: {0}")]
+    InvalidPly(usize),
+    #[error("no node with id: {0}")]
+    InvalidNode(usize),
+    #[error("game is already over")]
+    GameOver,
+    #[error("no draw has been offered by the opponent")]
+    NoDrawOffer,
+    #[error("draw cannot be claimed: {0:?}")]
+    InvalidClaim(Termination),
+    #[error("invalid portable game notation: {0}")]
+    InvalidPortableGameNotation(String),
+    #[error(transparent)]
+    Board(#[from] BoardError),
+    #[error(transparent)]
+    Parse(#[from] ParseError),
+}
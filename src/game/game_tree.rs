@@ -0,0 +1,336 @@
+// External imports
+use std::collections::HashMap;
+// Absolute imports within crate
+use crate::board::{Board, ChessMove, Outcome};
+// Imports from parent
+use super::{portable_game_notation, Annotation, Game, GameError, PlayedMove, Tags};
+
+// Identifier of a node in a game tree, which stays the same while other nodes are added or deleted
+pub type NodeId = usize;
+
+// Position in a game tree, reached by a move from the position of its parent
+#[derive(Debug, Clone)]
+pub struct Node {
+    board: Board,
+    // Not set for the root node with the initial position
+    played_move: Option<PlayedMove>,
+    parent: Option<NodeId>,
+    // The first child continues the main line, the others are variations
+    children: Vec<NodeId>,
+    annotation: Annotation,
+}
+
+impl Node {
+    pub fn board(&self) -> &Board {
+        &self.board
+    }
+
+    pub fn played_move(&self) -> Option<&PlayedMove> {
+        self.played_move.as_ref()
+    }
+
+    pub fn parent(&self) -> Option<NodeId> {
+        self.parent
+    }
+
+    pub fn children(&self) -> &[NodeId] {
+        &self.children
+    }
+
+    pub fn annotation(&self) -> &Annotation {
+        &self.annotation
+    }
+}
+
+// Game with variations at any ply, for analysis and studies
+// The comment of the root node is the comment before the first move of the game
+#[derive(Debug, Clone)]
+pub struct GameTree {
+    tags: Tags,
+    nodes: HashMap<NodeId, Node>,
+    next_id: NodeId,
+    outcome: Option<Outcome>,
+}
+
+impl Default for GameTree {
+    fn default() -> Self {
+        GameTree::new()
+    }
+}
+
+impl GameTree {
+    const ROOT: NodeId = 0;
+
+    pub fn new() -> GameTree {
+        GameTree::from_position(Board::starting_position())
+    }
+
+    pub fn from_position(board: Board) -> GameTree {
+        let root = Node {
+            board,
+            played_move: None,
+            parent: None,
+            children: Vec::new(),
+            annotation: Annotation::default(),
+        };
+
+        GameTree {
+            tags: Tags::new(),
+            nodes: HashMap::from([(GameTree::ROOT, root)]),
+            next_id: GameTree::ROOT + 1,
+            outcome: None,
+        }
+    }
+
+    pub fn portable_game_notation(record: &str) -> Result<GameTree, GameError> {
+        portable_game_notation::parse_tree(record)
+    }
+
+    pub fn to_portable_game_notation(&self) -> String {
+        portable_game_notation::format_tree(self)
+    }
+
+    pub fn tags(&self) -> &Tags {
+        &self.tags
+    }
+
+    pub fn tag(&self, name: &str) -> Option<&str> {
+        self.tags.get(name)
+    }
+
+    pub fn set_tag(&mut self, name: &str, value: &str) {
+        self.tags.set(name, value);
+    }
+
+    pub fn outcome(&self) -> Option<&Outcome> {
+        self.outcome.as_ref()
+    }
+
+    pub fn set_outcome(&mut self, outcome: Option<Outcome>) {
+        self.outcome = outcome;
+    }
+
+    pub fn root(&self) -> NodeId {
+        GameTree::ROOT
+    }
+
+    pub fn initial_position(&self) -> &Board {
+        self.nodes[&GameTree::ROOT].board()
+    }
+
+    pub fn node(&self, id: NodeId) -> Option<&Node> {
+        self.nodes.get(&id)
+    }
+
+    fn node_mut(&mut self, id: NodeId) -> Result<&mut Node, GameError> {
+        self.nodes.get_mut(&id).ok_or(GameError::InvalidNode(id))
+    }
+
+    pub fn annotation_mut(&mut self, id: NodeId) -> Result<&mut Annotation, GameError> {
+        Ok(&mut self.node_mut(id)?.annotation)
+    }
+
+    // Nodes of the main line, starting with the root
+    pub fn mainline(&self) -> Vec<NodeId> {
+        let mut line = vec![GameTree::ROOT];
+
+        while let Some(child) = self.nodes[line.last().expect("line is not empty")]
+            .children
+            .first()
+        {
+            line.push(*child);
+        }
+
+        line
+    }
+
+    // Nodes from the root to the given node
+    pub fn path(&self, id: NodeId) -> Vec<NodeId> {
+        let mut path = Vec::new();
+        let mut current = Some(id);
+
+        while let Some(id) = current {
+            match self.nodes.get(&id) {
+                Some(node) => {
+                    path.push(id);
+                    current = node.parent;
+                }
+                None => {
+                    break;
+                }
+            }
+        }
+
+        path.reverse();
+        path
+    }
+
+    // Add a legal move after the given node, which becomes a variation if the node already has a continuation
+    // Returns the existing node if the move has already been added
+    pub fn add_move(&mut self, parent: NodeId, chess_move: ChessMove) -> Result<NodeId, GameError> {
+        let parent_node = self
+            .nodes
+            .get(&parent)
+            .ok_or(GameError::InvalidNode(parent))?;
+
+        let existing = parent_node.children.iter().find(|child| {
+            self.nodes[child]
+                .played_move
+                .as_ref()
+                .is_some_and(|played_move| {
+                    let played_move = played_move.chess_move();
+                    played_move.origin_square() == chess_move.origin_square()
+                        && played_move.destination_square() == chess_move.destination_square()
+                        && played_move.action() == chess_move.action()
+                })
+        });
+        if let Some(existing) = existing {
+            return Ok(*existing);
+        }
+
+        // Use the legal move itself, so the move always has the same details as a generated move
+        let board = &parent_node.board;
+        let chess_move = board
+            .legal_moves()
+            .into_iter()
+            .find(|legal_move| {
+                legal_move.origin_square() == chess_move.origin_square()
+                    && legal_move.destination_square() == chess_move.destination_square()
+                    && legal_move.action() == chess_move.action()
+            })
+            .ok_or_else(|| GameError::IllegalMove(chess_move.to_string()))?;
+        let notation = board.to_standard_algebraic_notation(&chess_move);
+
+        let mut board = board.clone();
+        board.make_move(chess_move.clone())?;
+
+        let id = self.next_id;
+        self.next_id += 1;
+        self.nodes.insert(
+            id,
+            Node {
+                board,
+                played_move: Some(PlayedMove::new(chess_move, notation)),
+                parent: Some(parent),
+                children: Vec::new(),
+                annotation: Annotation::default(),
+            },
+        );
+        self.node_mut(parent)?.children.push(id);
+
+        Ok(id)
+    }
+
+    // Add a move written in standard algebraic notation, for example Nf3
+    pub fn add_standard_algebraic_notation_move(
+        &mut self, parent: NodeId, notation: &str,
+    ) -> Result<NodeId, GameError> {
+        let board = &self
+            .nodes
+            .get(&parent)
+            .ok_or(GameError::InvalidNode(parent))?
+            .board;
+        let chess_move = board.parse_standard_algebraic_notation(notation)?;
+
+        self.add_move(parent, chess_move)
+    }
+
+    // Make the variation with the given node the main line, including all variations it is part of
+    pub fn promote_to_mainline(&mut self, id: NodeId) -> Result<(), GameError> {
+        let path = self.path(id);
+        if path.last() != Some(&id) {
+            return Err(GameError::InvalidNode(id));
+        }
+
+        for pair in path.windows(2) {
+            let (parent, child) = (pair[0], pair[1]);
+            let children = &mut self.node_mut(parent)?.children;
+            let index = children
+                .iter()
+                .position(|sibling| *sibling == child)
+                .expect("child is part of its parent");
+            let child = children.remove(index);
+            children.insert(0, child);
+        }
+
+        Ok(())
+    }
+
+    // Move a variation one place up between its siblings, so it becomes the main line if it was the first variation
+    pub fn promote_variation(&mut self, id: NodeId) -> Result<(), GameError> {
+        let parent = self
+            .nodes
+            .get(&id)
+            .and_then(|node| node.parent)
+            .ok_or(GameError::InvalidNode(id))?;
+        let children = &mut self.node_mut(parent)?.children;
+        let index = children
+            .iter()
+            .position(|sibling| *sibling == id)
+            .expect("child is part of its parent");
+
+        if index > 0 {
+            children.swap(index - 1, index);
+        }
+
+        Ok(())
+    }
+
+    // Delete the node with all moves after it, which cannot be done for the root
+    pub fn delete_subtree(&mut self, id: NodeId) -> Result<(), GameError> {
+        let parent = self
+            .nodes
+            .get(&id)
+            .and_then(|node| node.parent)
+            .ok_or(GameError::InvalidNode(id))?;
+        self.node_mut(parent)?.children.retain(|child| *child != id);
+
+        let mut stack = vec![id];
+        while let Some(id) = stack.pop() {
+            if let Some(node) = self.nodes.remove(&id) {
+                stack.extend(node.children);
+            }
+        }
+
+        Ok(())
+    }
+
+    // Linear game with the main line of the tree
+    // Fails when the main line continues after the game has ended, for example by insufficient material
+    pub fn to_game(&self) -> Result<Game, GameError> {
+        let mut game = Game::from_position(self.initial_position().clone());
+        for (name, value) in self.tags.iter() {
+            game.set_tag(name, value);
+        }
+
+        for id in self.mainline().into_iter().skip(1) {
+            let played_move = self.nodes[&id]
+                .played_move
+                .as_ref()
+                .expect("only the root has no move");
+            game.make_move(played_move.chess_move().clone())?;
+        }
+        if self.outcome.is_some() {
+            game.set_outcome(self.outcome.clone());
+        }
+
+        Ok(game)
+    }
+}
+
+impl From<&Game> for GameTree {
+    fn from(game: &Game) -> Self {
+        let mut tree = GameTree::from_position(game.initial_position().clone());
+        tree.tags = game.tags().clone();
+        tree.outcome = game.outcome().cloned();
+
+        let mut current = tree.root();
+        for played_move in game.moves() {
+            current = tree
+                .add_move(current, played_move.chess_move().clone())
+                .expect("moves in the game are legal");
+        }
+
+        tree
+    }
+}
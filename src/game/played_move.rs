@@ -0,0 +1,26 @@
+// Absolute imports within crate
+use crate::board::ChessMove;
+
+// Move of a game, together with its notation as regenerated by to_standard_algebraic_notation when the move was made
+#[derive(Debug, Clone)]
+pub struct PlayedMove {
+    chess_move: ChessMove,
+    standard_algebraic_notation: String,
+}
+
+impl PlayedMove {
+    pub fn new(chess_move: ChessMove, standard_algebraic_notation: String) -> PlayedMove {
+        PlayedMove {
+            chess_move,
+            standard_algebraic_notation,
+        }
+    }
+
+    pub fn chess_move(&self) -> &ChessMove {
+        &self.chess_move
+    }
+
+    pub fn standard_algebraic_notation(&self) -> &str {
+        &self.standard_algebraic_notation
+    }
+}
@@ -0,0 +1,519 @@
+// Absolute imports within crate
+use crate::board::{Board, Outcome};
+use crate::piece::Color;
+// Imports from parent
+use super::{Annotation, Game, GameError, GameTree, NodeId, Tags};
+
+// Maximum length of a line of movetext when writing a record
+const LINE_LENGTH: usize = 80;
+
+// Smallest units of a PGN record
+#[derive(Debug, PartialEq, Clone)]
+pub(crate) enum Token {
+    Tag(String, String),
+    Move(String),
+    Nag(u8),
+    Comment(String),
+    StartVariation,
+    EndVariation,
+    Result(Option<Outcome>),
+}
+
+// Split a record into tokens, skipping move numbers
+pub(crate) fn tokenize(record: &str) -> Result<Vec<Token>, GameError> {
+    let mut tokens = Vec::new();
+    let mut characters = record.chars().peekable();
+    let mut is_line_start = true;
+
+    while let Some(character) = characters.next() {
+        match character {
+            '\n' => {
+                is_line_start = true;
+                continue;
+            }
+            _ if character.is_whitespace() => {}
+            // Escaped lines are ignored
+            '%' if is_line_start => {
+                characters
+                    .by_ref()
+                    .take_while(|c| *c != '\n')
+                    .for_each(drop);
+            }
+            '[' => {
+                let tag: String = read_until(&mut characters, ']')?;
+                tokens.push(parse_tag(&tag)?);
+            }
+            '{' => {
+                let comment = read_until(&mut characters, '}')?;
+                tokens.push(Token::Comment(comment.trim().to_string()));
+            }
+            ';' => {
+                let comment: String = characters.by_ref().take_while(|c| *c != '\n').collect();
+                tokens.push(Token::Comment(comment.trim().to_string()));
+                is_line_start = true;
+                continue;
+            }
+            '(' => {
+                tokens.push(Token::StartVariation);
+            }
+            ')' => {
+                tokens.push(Token::EndVariation);
+            }
+            '$' => {
+                let mut digits = String::new();
+                while let Some(digit) = characters.next_if(|c| c.is_ascii_digit()) {
+                    digits.push(digit);
+                }
+                let nag = digits
+                    .parse()
+                    .map_err(|_| GameError::InvalidPortableGameNotation(format!("${}", digits)))?;
+                tokens.push(Token::Nag(nag));
+            }
+            _ => {
+                let mut symbol = String::from(character);
+                while let Some(c) = characters.next_if(|c| !c.is_whitespace() && !"[]{}();$".contains(*c)) {
+                    symbol.push(c);
+                }
+                tokens.append(&mut parse_symbol(&symbol));
+            }
+        }
+
+        is_line_start = false;
+    }
+
+    Ok(tokens)
+}
+
+// Read all characters up to the closing character, which is consumed as well
+fn read_until(characters: &mut impl Iterator<Item = char>, closing: char) -> Result<String, GameError> {
+    let mut text = String::new();
+
+    for character in characters {
+        if character == closing {
+            return Ok(text);
+        }
+        text.push(character);
+    }
+
+    Err(GameError::InvalidPortableGameNotation(format!("missing {:?}", closing)))
+}
+
+// Tag pair in the form Name "Value", where quotes and backslashes in the value are escaped
+fn parse_tag(tag: &str) -> Result<Token, GameError> {
+    let invalid = || GameError::InvalidPortableGameNotation(format!("[{}]", tag));
+
+    let (name, value) = tag
+        .trim()
+        .split_once(char::is_whitespace)
+        .ok_or_else(invalid)?;
+    let value = value
+        .trim()
+        .strip_prefix('"')
+        .and_then(|value| value.strip_suffix('"'))
+        .ok_or_else(invalid)?;
+
+    Ok(Token::Tag(name.to_string(), value.replace("\\\"", "\"").replace("\\\\", "\\")))
+}
+
+// Move number, move with optional annotation suffix or game termination marker
+fn parse_symbol(symbol: &str) -> Vec<Token> {
+    if let Some(outcome) = parse_result(symbol) {
+        return vec![Token::Result(outcome)];
+    }
+
+    // Move numbers like 12. or 12... can be directly followed by the move
+    let without_number = symbol.trim_start_matches(|c: char| c.is_ascii_digit());
+    let notation = match without_number.strip_prefix('.') {
+        Some(rest) => rest.trim_start_matches('.'),
+        None => symbol,
+    };
+    if notation.is_empty() {
+        return Vec::new();
+    }
+
+    // Traditional suffix annotations are the same as the first six NAGs
+    let suffix_start = notation.find(['!', '?']).unwrap_or(notation.len());
+    let mut tokens = vec![Token::Move(notation[..suffix_start].to_string())];
+    let nag = match &notation[suffix_start..] {
+        "!" => Some(1),
+        "?" => Some(2),
+        "!!" => Some(3),
+        "??" => Some(4),
+        "!?" => Some(5),
+        "?!" => Some(6),
+        _ => None,
+    };
+    if let Some(nag) = nag {
+        tokens.push(Token::Nag(nag));
+    }
+
+    tokens
+}
+
+// Returns None for a valid marker of an unfinished game
+fn parse_result(symbol: &str) -> Option<Option<Outcome>> {
+    match symbol {
+        "1-0" => Some(Some(Outcome::Win(Color::White))),
+        "0-1" => Some(Some(Outcome::Win(Color::Black))),
+        "1/2-1/2" => Some(Some(Outcome::Draw)),
+        "*" => Some(None),
+        _ => None,
+    }
+}
+
+pub(crate) fn format_result(outcome: Option<&Outcome>) -> &'static str {
+    match outcome {
+        Some(Outcome::Win(Color::White)) => "1-0",
+        Some(Outcome::Win(Color::Black)) => "0-1",
+        Some(Outcome::Draw) => "1/2-1/2",
+        None => "*",
+    }
+}
+
+// Initial position from the FEN tag, or the regular starting position
+pub(crate) fn initial_position(tokens: &[Token]) -> Result<Board, GameError> {
+    let record = tokens.iter().find_map(|token| match token {
+        Token::Tag(name, value) if name == "FEN" => Some(value),
+        _ => None,
+    });
+
+    match record {
+        Some(record) => Ok(Board::forsyth_edwards_notation(record)?),
+        None => Ok(Board::starting_position()),
+    }
+}
+
+// Split a file with multiple records into the records of each game, which start at the first tag after the moves
+// of the previous game
+pub(super) fn split(records: &str) -> Vec<String> {
+    let mut split = Vec::new();
+    let mut record = String::new();
+    let mut has_moves = false;
+    let mut is_in_comment = false;
+
+    for line in records.lines() {
+        let trimmed = line.trim_start();
+        let is_movetext = !is_in_comment && !trimmed.starts_with('[') && !trimmed.starts_with('%');
+
+        if !is_in_comment && !trimmed.is_empty() {
+            if trimmed.starts_with('[') {
+                if has_moves {
+                    split.push(std::mem::take(&mut record));
+                    has_moves = false;
+                }
+            } else if !trimmed.starts_with('%') {
+                has_moves = true;
+            }
+        }
+
+        // Tags inside a comment that continues on the next lines do not start a new game
+        // Braces in tag values, escaped lines and comments to the end of the line do not start a comment
+        if is_in_comment || is_movetext {
+            for character in line.chars() {
+                match character {
+                    '{' => is_in_comment = true,
+                    '}' => is_in_comment = false,
+                    ';' if !is_in_comment => break,
+                    _ => {}
+                }
+            }
+        }
+
+        record.push_str(line);
+        record.push('\n');
+    }
+
+    if !record.trim().is_empty() {
+        split.push(record);
+    }
+
+    split
+}
+
+// Read a single game, where only the main line is kept
+pub(super) fn parse(record: &str) -> Result<Game, GameError> {
+    let tokens = tokenize(record)?;
+    let mut game = Game::from_position(initial_position(&tokens)?);
+    let mut tags = Tags::new();
+    let mut depth: usize = 0;
+
+    for token in tokens {
+        match token {
+            Token::Tag(name, value) => {
+                tags.set(&name, &value);
+            }
+            Token::StartVariation => {
+                depth += 1;
+            }
+            Token::EndVariation => {
+                depth = depth
+                    .checked_sub(1)
+                    .ok_or_else(|| GameError::InvalidPortableGameNotation(String::from("unexpected ')'")))?;
+            }
+            Token::Move(notation) if depth == 0 => {
+                game.make_standard_algebraic_notation_move(&notation)?;
+            }
+            // An unfinished game keeps the outcome that follows from the position
+            Token::Result(Some(outcome)) if depth == 0 => {
+                game.set_outcome(Some(outcome));
+            }
+            _ => {}
+        }
+    }
+
+    if depth != 0 {
+        return Err(GameError::InvalidPortableGameNotation(String::from("missing ')'")));
+    }
+
+    // Tags of the record replace the tags that were added while making the moves, like Termination
+    game.tags = tags;
+
+    Ok(game)
+}
+
+pub(super) fn format(game: &Game) -> String {
+    let mut record = format_tags(game.tags(), game.initial_position(), game.outcome());
+    record.push('\n');
+
+    let initial_position = game.initial_position();
+    let mut words = Vec::new();
+    for (index, played_move) in game.moves().iter().enumerate() {
+        // Keep the move number on the same line as the move
+        let notation = played_move.standard_algebraic_notation();
+        match format_move_number(initial_position, index) {
+            Some(number) => words.push(format!("{} {}", number, notation)),
+            None => words.push(notation.to_string()),
+        }
+    }
+    words.push(format_result(game.outcome()).to_string());
+
+    record.push_str(&wrap(&words));
+    record.push('\n');
+    record
+}
+
+pub(crate) fn format_tags(tags: &Tags, initial_position: &Board, outcome: Option<&Outcome>) -> String {
+    let mut tags = tags.clone();
+    tags.set("Result", format_result(outcome));
+
+    // Games that do not start from the regular starting position need the position as well
+    if !initial_position.is_same_position(&Board::starting_position()) || initial_position.fullmove_number() != 1 {
+        tags.set("SetUp", "1");
+        tags.set("FEN", &initial_position.to_forsyth_edwards_notation());
+    }
+
+    tags.roster()
+        .into_iter()
+        .map(|(name, value)| format!("[{} \"{}\"]\n", name, value.replace('\\', "\\\\").replace('"', "\\\"")))
+        .collect()
+}
+
+// Read a single game with all its variations and annotations
+pub(super) fn parse_tree(record: &str) -> Result<GameTree, GameError> {
+    let tokens = tokenize(record)?;
+    let mut tree = GameTree::from_position(initial_position(&tokens)?);
+
+    // Node of the last move, and the nodes to return to at the end of each variation
+    let mut current = tree.root();
+    let mut variations: Vec<NodeId> = Vec::new();
+    // Comments at the start of a variation belong to its first move
+    let mut starting_comment: Option<String> = None;
+    let mut is_variation_start = false;
+
+    for token in tokens {
+        match token {
+            Token::Tag(name, value) => {
+                tree.set_tag(&name, &value);
+            }
+            Token::Move(notation) => {
+                current = tree.add_standard_algebraic_notation_move(current, &notation)?;
+                if is_variation_start {
+                    tree.annotation_mut(current)?
+                        .set_starting_comment(starting_comment.take());
+                    is_variation_start = false;
+                }
+            }
+            Token::Nag(nag) => {
+                tree.annotation_mut(current)?.add_nag(nag);
+            }
+            Token::Comment(comment) if is_variation_start => {
+                starting_comment = Some(match starting_comment.take() {
+                    Some(existing) => format!("{} {}", existing, comment),
+                    None => comment,
+                });
+            }
+            Token::Comment(comment) => {
+                tree.annotation_mut(current)?.add_comment(&comment);
+            }
+            Token::StartVariation => {
+                // A variation is an alternative for the last move
+                let parent = tree
+                    .node(current)
+                    .and_then(|node| node.parent())
+                    .ok_or_else(|| {
+                        GameError::InvalidPortableGameNotation(String::from("variation before first move"))
+                    })?;
+                variations.push(current);
+                current = parent;
+                is_variation_start = true;
+            }
+            Token::EndVariation => {
+                current = variations
+                    .pop()
+                    .ok_or_else(|| GameError::InvalidPortableGameNotation(String::from("unexpected ')'")))?;
+                is_variation_start = false;
+            }
+            Token::Result(outcome) => {
+                tree.set_outcome(outcome);
+            }
+        }
+    }
+
+    if !variations.is_empty() {
+        return Err(GameError::InvalidPortableGameNotation(String::from("missing ')'")));
+    }
+
+    Ok(tree)
+}
+
+pub(super) fn format_tree(tree: &GameTree) -> String {
+    let initial_position = tree.initial_position();
+    let mut record = format_tags(tree.tags(), initial_position, tree.outcome());
+    record.push('\n');
+
+    let root = tree.node(tree.root()).expect("tree has a root");
+    let mut words = Vec::new();
+    if let Some(comment) = root.annotation().format_comment() {
+        words.push(format!("{{{}}}", comment));
+    }
+    format_line(tree, tree.root(), 0, true, &mut words);
+    words.push(format_result(tree.outcome()).to_string());
+
+    record.push_str(&wrap(&words));
+    record.push('\n');
+    record
+}
+
+// Write the moves after the given node, with the variations of each move directly after it
+fn format_line(tree: &GameTree, parent: NodeId, ply: usize, force_number: bool, words: &mut Vec<String>) {
+    let node = tree
+        .node(parent)
+        .expect("children of a node are part of the tree");
+    let (main, variations) = match node.children().split_first() {
+        Some(children) => children,
+        None => {
+            return;
+        }
+    };
+
+    format_move(tree, *main, ply, force_number, words);
+    for variation in variations {
+        words.push(String::from("("));
+        format_move(tree, *variation, ply, true, words);
+        format_line(tree, *variation, ply + 1, false, words);
+        words.push(String::from(")"));
+    }
+
+    // Black moves need a number again after variations or a comment
+    let annotation = tree
+        .node(*main)
+        .expect("main line is part of the tree")
+        .annotation();
+    let force_number = !variations.is_empty() || annotation.format_comment().is_some();
+    format_line(tree, *main, ply + 1, force_number, words);
+}
+
+fn format_move(tree: &GameTree, id: NodeId, ply: usize, force_number: bool, words: &mut Vec<String>) {
+    let node = tree
+        .node(id)
+        .expect("children of a node are part of the tree");
+    let played_move = node.played_move().expect("only the root has no move");
+    let annotation: &Annotation = node.annotation();
+
+    if let Some(comment) = annotation.starting_comment() {
+        words.push(format!("{{{}}}", comment));
+    }
+
+    let notation = played_move.standard_algebraic_notation();
+    match format_move_number(tree.initial_position(), ply) {
+        Some(number) => words.push(format!("{} {}", number, notation)),
+        None if force_number => {
+            words.push(format!("{} {}", format_black_move_number(tree.initial_position(), ply), notation))
+        }
+        None => words.push(notation.to_string()),
+    }
+
+    words.extend(annotation.nags().iter().map(|nag| format!("${}", nag)));
+    if let Some(comment) = annotation.format_comment() {
+        words.push(format!("{{{}}}", comment));
+    }
+}
+
+// Number before the move at the given index, for example 12. for white and 12... for the first move by black
+pub(crate) fn format_move_number(initial_position: &Board, index: usize) -> Option<String> {
+    let is_black_first = *initial_position.active_color() == Color::Black;
+    let ply = index + usize::from(is_black_first);
+    let number = initial_position.fullmove_number() as usize + ply / 2;
+
+    if ply.is_multiple_of(2) {
+        Some(format!("{}.", number))
+    } else if index == 0 {
+        Some(format!("{}...", number))
+    } else {
+        None
+    }
+}
+
+// Number before a move by black that does not directly follow the move by white, for example 12...
+fn format_black_move_number(initial_position: &Board, index: usize) -> String {
+    let is_black_first = *initial_position.active_color() == Color::Black;
+    let ply = index + usize::from(is_black_first);
+
+    format!("{}...", initial_position.fullmove_number() as usize + ply / 2)
+}
+
+// Join words with spaces, starting a new line before the line gets too long
+// Parentheses of variations are joined to the word next to them
+pub(crate) fn wrap(words: &[String]) -> String {
+    let mut joined: Vec<String> = Vec::new();
+    let mut is_after_opening = false;
+    for word in words {
+        match word.as_str() {
+            "(" => {
+                joined.push(word.clone());
+                is_after_opening = true;
+                continue;
+            }
+            ")" => match joined.last_mut() {
+                Some(last) => last.push(')'),
+                None => joined.push(word.clone()),
+            },
+            _ if is_after_opening => {
+                joined
+                    .last_mut()
+                    .expect("opening parenthesis was added")
+                    .push_str(word);
+            }
+            _ => joined.push(word.clone()),
+        }
+        is_after_opening = false;
+    }
+
+    let mut text = String::new();
+    let mut line_length = 0;
+
+    for word in &joined {
+        if line_length > 0 && line_length + 1 + word.len() > LINE_LENGTH {
+            text.push('\n');
+            line_length = 0;
+        } else if line_length > 0 {
+            text.push(' ');
+            line_length += 1;
+        }
+
+        text.push_str(word);
+        line_length += word.len();
+    }
+
+    text
+}
@@ -0,0 +1,69 @@
+// Tags that are always part of a PGN record, in this order, with their value when unknown
+pub const SEVEN_TAG_ROSTER: [(&str, &str); 7] = [
+    ("Event", "?"),
+    ("Site", "?"),
+    ("Date", "????.??.??"),
+    ("Round", "?"),
+    ("White", "?"),
+    ("Black", "?"),
+    ("Result", "*"),
+];
+
+// Metadata of a game as name and value pairs, for example the players and the event
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Tags {
+    pairs: Vec<(String, String)>,
+}
+
+impl Tags {
+    pub fn new() -> Tags {
+        Tags::default()
+    }
+
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.pairs
+            .iter()
+            .find(|(tag, _)| tag == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    // Replace the value of an existing tag, or add the tag at the end
+    pub fn set(&mut self, name: &str, value: &str) {
+        match self.pairs.iter_mut().find(|(tag, _)| tag == name) {
+            Some((_, existing)) => {
+                *existing = value.to_string();
+            }
+            None => {
+                self.pairs.push((name.to_string(), value.to_string()));
+            }
+        }
+    }
+
+    pub fn remove(&mut self, name: &str) -> Option<String> {
+        let index = self.pairs.iter().position(|(tag, _)| tag == name)?;
+        Some(self.pairs.remove(index).1)
+    }
+
+    // Tags in the order they were added
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.pairs
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+    }
+
+    // Tags in the order they are written in PGN, with the seven tag roster first
+    pub fn roster(&self) -> Vec<(&str, &str)> {
+        let mut tags: Vec<(&str, &str)> = SEVEN_TAG_ROSTER
+            .iter()
+            .map(|(name, unknown)| (*name, self.get(name).unwrap_or(unknown)))
+            .collect();
+
+        tags.extend(self.iter().filter(|(name, _)| {
+            !SEVEN_TAG_ROSTER
+                .iter()
+                .any(|(roster_name, _)| roster_name == name)
+        }));
+
+        tags
+    }
+}
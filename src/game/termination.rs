@@ -0,0 +1,50 @@
+// Reason a game has ended
+#[derive(Debug, PartialEq, Clone)]
+pub enum Termination {
+    Checkmate,
+    Stalemate,
+    InsufficientMaterial,
+    SeventyFiveMoveRule,
+    FivefoldRepetition,
+    ThreefoldRepetition,
+    FiftyMoveRule,
+    Resignation,
+    DrawAgreement,
+    TimeForfeit,
+    Abandoned,
+    Adjudication,
+}
+
+impl Termination {
+    // Value of the Termination tag of PGN, where the end by the rules of chess or by the players themselves is normal
+    pub fn portable_game_notation(&self) -> &'static str {
+        match self {
+            Termination::Checkmate
+            | Termination::Stalemate
+            | Termination::InsufficientMaterial
+            | Termination::SeventyFiveMoveRule
+            | Termination::FivefoldRepetition
+            | Termination::ThreefoldRepetition
+            | Termination::FiftyMoveRule
+            | Termination::Resignation
+            | Termination::DrawAgreement => "normal",
+            Termination::TimeForfeit => "time forfeit",
+            Termination::Abandoned => "abandoned",
+            Termination::Adjudication => "adjudication",
+        }
+    }
+
+    // Whether the game ended because of the position, so other moves in an earlier position could continue the game
+    pub fn is_from_position(&self) -> bool {
+        matches!(
+            self,
+            Termination::Checkmate
+                | Termination::Stalemate
+                | Termination::InsufficientMaterial
+                | Termination::SeventyFiveMoveRule
+                | Termination::FivefoldRepetition
+                | Termination::ThreefoldRepetition
+                | Termination::FiftyMoveRule
+        )
+    }
+}
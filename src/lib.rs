@@ -1,6 +1,20 @@
 #[macro_use]
 mod macros;
 
+pub mod analysis;
 pub mod board;
+pub mod clock;
+pub mod encoding;
+pub mod endgame;
+pub mod game;
 pub mod parser;
+pub mod pawns;
 pub mod piece;
+pub mod problem;
+pub mod puzzles;
+pub mod render;
+pub mod search;
+pub mod tactics;
+#[cfg(feature = "tui")]
+pub mod tui;
+pub mod variant;
@@ -4,12 +4,12 @@ macro_rules! piece_placement {
     { $( ($f: expr, $r: expr) => ($c: expr, $k: expr)),* $(,)?} => {
         {
             // A chess board contains a maximum of 32 pieces
-            let mut map = crate::board::PiecePlacement::with_capacity(32);
+            let mut map = $crate::board::PiecePlacement::with_capacity(32);
 
             // Add each expression to the map
             $(
-                let square = crate::board::Square::new($f, $r);
-                let piece = crate::piece::Piece::new($c, $k);
+                let square = $crate::board::Square::new($f, $r);
+                let piece = $crate::piece::Piece::new($c, $k);
                 map.insert(square, piece);
             )*
 
@@ -1,54 +1,105 @@
 // Absolute imports within crate
-use crate::board::{Board, CastlingAvailability, PiecePlacement, Square};
+use crate::board::{
+    Board, CastlingAvailability, File, PiecePlacement, Pocket, PromotedSquares, RemainingChecks, Square,
+};
 use crate::piece::{Color, Kind, Piece};
 
 // Relative imports of sub modules
 pub use parse_error::ParseError;
 mod parse_error;
 
+// Notation of the castling availability field when writing a record
+#[derive(Debug, PartialEq, Clone)]
+pub enum CastlingNotation {
+    // Letters KQkq, with the file of the rook instead when it is not the outermost rook (X-FEN)
+    Standard,
+    // Files of the castling rooks, for example HAha (Shredder-FEN)
+    Shredder,
+}
+
+// Whether a record is only checked for its syntax, or also for the legality of the position
+#[derive(Debug, PartialEq, Clone)]
+pub enum ParseMode {
+    // Any position that can be written down is accepted, which is needed for variants and puzzles
+    Lenient,
+    // Only positions that can arise in a game of regular chess are accepted
+    Strict,
+}
+
 // TODO: implement a function on Iterator which returns a parse error
 
+pub fn parse_forsyth_edwards_notation_with_mode(record: &str, mode: &ParseMode) -> Result<Board, ParseError> {
+    let board = parse_forsyth_edwards_notation(record)?;
+
+    if *mode == ParseMode::Strict {
+        board.validate()?;
+    }
+
+    Ok(board)
+}
+
 pub fn parse_forsyth_edwards_notation(record: &str) -> Result<Board, ParseError> {
     // Deconstruct specification into the different fields
-    let mut fields = record.split_whitespace();
+    let mut fields = record.split_whitespace().peekable();
 
-    // Collect all the pieces
-    let field = fields.next().ok_or_else(|| ParseError::UnexpectedEnd)?;
-    let piece_placement = parse_piece_placement(field)?;
+    // Collect all the pieces, followed by the pocket in Crazyhouse, for example [Nq]
+    let field = fields.next().ok_or(ParseError::UnexpectedEnd)?;
+    let (field, pocket) = match field.split_once('[') {
+        Some((field, pocket_field)) => (field, Some(parse_pocket(pocket_field)?)),
+        None => (field, None),
+    };
+    let (piece_placement, promoted_squares) = parse_piece_placement(field)?;
 
     // Detect active color
-    let field = fields.next().ok_or_else(|| ParseError::UnexpectedEnd)?;
+    let field = fields.next().ok_or(ParseError::UnexpectedEnd)?;
     let active_color = parse_active_color(field)?;
 
     // Collect all castling options
-    let field = fields.next().ok_or_else(|| ParseError::UnexpectedEnd)?;
-    let castling_availability = parse_castling_availability(field)?;
+    let field = fields.next().ok_or(ParseError::UnexpectedEnd)?;
+    let castling_availability = parse_castling_availability(field, &piece_placement)?;
 
     // Detect en passant target square
-    let field = fields.next().ok_or_else(|| ParseError::UnexpectedEnd)?;
+    let field = fields.next().ok_or(ParseError::UnexpectedEnd)?;
     let en_passant_target = parse_en_passant_target_square(field)?;
 
+    // Detect remaining checks of Three-check, for example 3+3
+    let mut remaining_checks = None;
+    if let Some(field) = fields.next_if(|field| field.contains('+')) {
+        remaining_checks = Some(parse_remaining_checks(field)?);
+    }
+
     // Detect halfmove clock
-    let field = fields.next().ok_or_else(|| ParseError::UnexpectedEnd)?;
+    let field = fields.next().ok_or(ParseError::UnexpectedEnd)?;
     let halfmove_clock = parse_number(field)?;
 
     // Detect fullmove number
-    let field = fields.next().ok_or_else(|| ParseError::UnexpectedEnd)?;
+    let field = fields.next().ok_or(ParseError::UnexpectedEnd)?;
     let fullmove_number = parse_number(field)?;
 
-    Ok(Board::new(
+    // Detect checks given in Three-check as a suffix, for example +0+1
+    if let Some(field) = fields.next_if(|field| field.starts_with('+')) {
+        remaining_checks = Some(parse_checks_given(field)?);
+    }
+
+    let mut board = Board::new(
         piece_placement,
         active_color,
         castling_availability,
         en_passant_target,
         halfmove_clock,
         fullmove_number,
-    ))
+    );
+    board.set_remaining_checks(remaining_checks);
+    board.set_pocket(pocket);
+    board.set_promoted_squares(promoted_squares);
+
+    Ok(board)
 }
 
-fn parse_piece_placement(piece_placement_field: &str) -> Result<PiecePlacement, ParseError> {
+fn parse_piece_placement(piece_placement_field: &str) -> Result<(PiecePlacement, PromotedSquares), ParseError> {
     // Start with empty squares
     let mut piece_placement = PiecePlacement::new();
+    let mut promoted_squares = PromotedSquares::new();
 
     // Go from highest rank to lowest, and from lowest file to highest
     let mut rank: i8 = 8;
@@ -92,6 +143,18 @@ fn parse_piece_placement(piece_placement_field: &str) -> Result<PiecePlacement,
             '8' => {
                 file += 8;
             }
+            // A tilde marks the previous piece as promoted in Crazyhouse
+            '~' => {
+                // There is no previous piece at the start of a rank
+                if file <= 1 {
+                    return Err(ParseError::InvalidPiece(character));
+                }
+                let square = Square::new(file - 1, rank);
+                if !piece_placement.contains_key(&square) {
+                    return Err(ParseError::InvalidPiece(character));
+                }
+                promoted_squares.insert(square);
+            }
             // Any character implies a piece on the current square, so  and increase file
             _ => {
                 // Create a new square and piece
@@ -109,12 +172,26 @@ fn parse_piece_placement(piece_placement_field: &str) -> Result<PiecePlacement,
 
     // Verify whether we have gone through all the squares
     if rank == 1 && file == 9 {
-        Ok(piece_placement)
+        Ok((piece_placement, promoted_squares))
     } else {
         Err(ParseError::IncompletePiecePlacement)
     }
 }
 
+// Pieces in the pocket, closed by a bracket, for example Nq]
+fn parse_pocket(pocket_field: &str) -> Result<Pocket, ParseError> {
+    let pieces_field = pocket_field
+        .strip_suffix(']')
+        .ok_or(ParseError::UnexpectedEnd)?;
+
+    let pieces = pieces_field
+        .chars()
+        .map(parse_piece)
+        .collect::<Result<Vec<Piece>, ParseError>>()?;
+
+    Ok(Pocket::new(pieces)?)
+}
+
 fn parse_piece(character: char) -> Result<Piece, ParseError> {
     // Return new piece
     let piece = match character {
@@ -142,8 +219,8 @@ fn parse_active_color(active_color_field: &str) -> Result<Color, ParseError> {
     // Detect whether it is the turn of black or white
     let character = active_color_field
         .chars()
-        .nth(0)
-        .ok_or_else(|| ParseError::UnexpectedEnd)?;
+        .next()
+        .ok_or(ParseError::UnexpectedEnd)?;
 
     let color = match character {
         // Blacks turn to move
@@ -159,12 +236,15 @@ fn parse_active_color(active_color_field: &str) -> Result<Color, ParseError> {
     Ok(color)
 }
 
-fn parse_castling_availability(castling_availability_field: &str) -> Result<CastlingAvailability, ParseError> {
+// Supports both X-FEN (KQkq, with file letters for inner rooks) and Shredder-FEN (file letters only)
+fn parse_castling_availability(
+    castling_availability_field: &str, piece_placement: &PiecePlacement,
+) -> Result<CastlingAvailability, ParseError> {
     // By default, no castling is allowed
-    let mut white_kingside = false;
-    let mut white_queenside = false;
-    let mut black_kingside = false;
-    let mut black_queenside = false;
+    let mut white_kingside = None;
+    let mut white_queenside = None;
+    let mut black_kingside = None;
+    let mut black_queenside = None;
 
     for character in castling_availability_field.chars() {
         match character {
@@ -172,21 +252,39 @@ fn parse_castling_availability(castling_availability_field: &str) -> Result<Cast
             '-' => {
                 break;
             }
-            // Letter K indicates white can castle kingside
+            // Letter K indicates white can castle kingside with the outermost rook
             'K' => {
-                white_kingside = true;
+                white_kingside = Some(outermost_rook_file(piece_placement, &Color::White, true));
             }
-            // Letter Q indicates white can castle queenside
+            // Letter Q indicates white can castle queenside with the outermost rook
             'Q' => {
-                white_queenside = true;
+                white_queenside = Some(outermost_rook_file(piece_placement, &Color::White, false));
             }
-            // Letter k indicates black can castle kingside
+            // Letter k indicates black can castle kingside with the outermost rook
             'k' => {
-                black_kingside = true;
+                black_kingside = Some(outermost_rook_file(piece_placement, &Color::Black, true));
             }
-            // Letter q indicates black can castle queenside
+            // Letter q indicates black can castle queenside with the outermost rook
             'q' => {
-                black_queenside = true;
+                black_queenside = Some(outermost_rook_file(piece_placement, &Color::Black, false));
+            }
+            // Uppercase file indicates white can castle with the rook on that file
+            'A'..='H' => {
+                let file = parse_file(character.to_ascii_lowercase())?;
+                if file > king_file(piece_placement, &Color::White) {
+                    white_kingside = Some(file);
+                } else {
+                    white_queenside = Some(file);
+                }
+            }
+            // Lowercase file indicates black can castle with the rook on that file
+            'a'..='h' => {
+                let file = parse_file(character)?;
+                if file > king_file(piece_placement, &Color::Black) {
+                    black_kingside = Some(file);
+                } else {
+                    black_queenside = Some(file);
+                }
             }
             // Invalid character
             _ => {
@@ -195,21 +293,62 @@ fn parse_castling_availability(castling_availability_field: &str) -> Result<Cast
         }
     }
 
-    Ok(CastlingAvailability::new(white_kingside, white_queenside, black_kingside, black_queenside))
+    Ok(CastlingAvailability::from_rook_files(
+        white_kingside,
+        white_queenside,
+        black_kingside,
+        black_queenside,
+    ))
+}
+
+// File of the king on its first rank, or the E-file if it cannot be found
+fn king_file(piece_placement: &PiecePlacement, color: &Color) -> File {
+    piece_placement
+        .iter()
+        .find(|(square, piece)| {
+            square.rank() == color.get_first_rank() && piece.color() == color && piece.kind() == &Kind::King
+        })
+        .map(|(square, _)| square.file())
+        .unwrap_or(5)
+}
+
+// Files of all rooks of the given color on its first rank on one side of the king
+fn rook_files(piece_placement: &PiecePlacement, color: &Color, kingside: bool) -> Vec<File> {
+    let king_file = king_file(piece_placement, color);
+
+    piece_placement
+        .iter()
+        .filter(|(square, piece)| {
+            square.rank() == color.get_first_rank() && piece.color() == color && piece.kind() == &Kind::Rook
+        })
+        .map(|(square, _)| square.file())
+        .filter(|file| if kingside { *file > king_file } else { *file < king_file })
+        .collect()
+}
+
+// File of the rook closest to the corner, or the A- or H-file if there is no rook
+fn outermost_rook_file(piece_placement: &PiecePlacement, color: &Color, kingside: bool) -> File {
+    let files = rook_files(piece_placement, color, kingside);
+
+    if kingside {
+        files.into_iter().max().unwrap_or(8)
+    } else {
+        files.into_iter().min().unwrap_or(1)
+    }
 }
 
 fn parse_en_passant_target_square(en_passant_target_square_field: &str) -> Result<Option<Square>, ParseError> {
     // Detect the target square for en passant
     let mut characters = en_passant_target_square_field.chars();
 
-    let first_character = characters.next().ok_or_else(|| ParseError::UnexpectedEnd)?;
+    let first_character = characters.next().ok_or(ParseError::UnexpectedEnd)?;
 
     // Handle special case, no en passant
     if first_character == '-' {
         return Ok(None);
     }
 
-    let second_character = characters.next().ok_or_else(|| ParseError::UnexpectedEnd)?;
+    let second_character = characters.next().ok_or(ParseError::UnexpectedEnd)?;
 
     // Expecting valid file and rank now
     let file = parse_file(first_character)?;
@@ -218,7 +357,7 @@ fn parse_en_passant_target_square(en_passant_target_square_field: &str) -> Resul
     Ok(Some(Square::new(file, rank)))
 }
 
-fn parse_file(character: char) -> Result<i8, ParseError> {
+pub(crate) fn parse_file(character: char) -> Result<i8, ParseError> {
     let file = match character {
         'a' => 1,
         'b' => 2,
@@ -237,7 +376,7 @@ fn parse_file(character: char) -> Result<i8, ParseError> {
     Ok(file)
 }
 
-fn parse_rank(character: char) -> Result<i8, ParseError> {
+pub(crate) fn parse_rank(character: char) -> Result<i8, ParseError> {
     let rank = match character {
         '1' => 1,
         '2' => 2,
@@ -259,3 +398,189 @@ fn parse_rank(character: char) -> Result<i8, ParseError> {
 fn parse_number(field: &str) -> Result<u16, ParseError> {
     field.parse().map_err(|_| ParseError::InvalidNumber)
 }
+
+// Remaining checks for white and black, separated by a plus sign
+fn parse_remaining_checks(field: &str) -> Result<RemainingChecks, ParseError> {
+    let (white, black) = field.split_once('+').ok_or(ParseError::InvalidNumber)?;
+    let white = white.parse().map_err(|_| ParseError::InvalidNumber)?;
+    let black = black.parse().map_err(|_| ParseError::InvalidNumber)?;
+
+    Ok(RemainingChecks::new(white, black))
+}
+
+// Checks already given by white and black, each preceded by a plus sign
+fn parse_checks_given(field: &str) -> Result<RemainingChecks, ParseError> {
+    let (white, black) = field
+        .trim_start_matches('+')
+        .split_once('+')
+        .ok_or(ParseError::InvalidNumber)?;
+    let white: u8 = white.parse().map_err(|_| ParseError::InvalidNumber)?;
+    let black: u8 = black.parse().map_err(|_| ParseError::InvalidNumber)?;
+
+    if white > 3 || black > 3 {
+        return Err(ParseError::InvalidNumber);
+    }
+
+    Ok(RemainingChecks::new(3 - white, 3 - black))
+}
+
+pub fn format_forsyth_edwards_notation(board: &Board, castling_notation: &CastlingNotation) -> String {
+    let mut fields = vec![
+        format_piece_placement(board),
+        format_active_color(board.active_color()),
+        format_castling_availability(board, castling_notation),
+        format_en_passant_target_square(board.en_passant_target()),
+    ];
+
+    // Remaining checks are only part of the record in Three-check
+    if let Some(remaining_checks) = board.remaining_checks() {
+        fields.push(format!("{}+{}", remaining_checks.get(&Color::White), remaining_checks.get(&Color::Black)));
+    }
+
+    fields.push(board.halfmove_clock().to_string());
+    fields.push(board.fullmove_number().to_string());
+
+    fields.join(" ")
+}
+
+fn format_piece_placement(board: &Board) -> String {
+    let piece_placement = board.piece_placement();
+    let mut field = String::new();
+
+    // Go from highest rank to lowest, and from lowest file to highest
+    for rank in (1..=8).rev() {
+        let mut empty_squares = 0;
+
+        for file in 1..=8 {
+            match piece_placement.get(&Square::new(file, rank)) {
+                Some(piece) => {
+                    // Write the number of empty squares before the piece
+                    if empty_squares > 0 {
+                        field.push_str(&empty_squares.to_string());
+                        empty_squares = 0;
+                    }
+                    field.push(format_piece(piece));
+
+                    // Promoted pieces are only marked when they matter, which is in Crazyhouse
+                    if board.pocket().is_some() && board.is_promoted(&Square::new(file, rank)) {
+                        field.push('~');
+                    }
+                }
+                None => {
+                    empty_squares += 1;
+                }
+            }
+        }
+
+        // Write the number of empty squares at the end of the rank
+        if empty_squares > 0 {
+            field.push_str(&empty_squares.to_string());
+        }
+
+        // Slash indicates end of current rank
+        if rank > 1 {
+            field.push('/');
+        }
+    }
+
+    // Pocket with white pieces first, both from most to least valuable
+    if let Some(pocket) = board.pocket() {
+        field.push('[');
+        for color in [Color::White, Color::Black] {
+            field.extend(pocket.pieces(&color).into_iter().map(format_piece));
+        }
+        field.push(']');
+    }
+
+    field
+}
+
+pub(crate) fn format_piece(piece: &Piece) -> char {
+    let character = match piece.kind() {
+        Kind::Bishop => 'b',
+        Kind::King => 'k',
+        Kind::Knight => 'n',
+        Kind::Pawn => 'p',
+        Kind::Queen => 'q',
+        Kind::Rook => 'r',
+    };
+
+    // White pieces are written in uppercase
+    match piece.color() {
+        Color::White => character.to_ascii_uppercase(),
+        Color::Black => character,
+    }
+}
+
+fn format_active_color(active_color: &Color) -> String {
+    match active_color {
+        Color::White => String::from("w"),
+        Color::Black => String::from("b"),
+    }
+}
+
+fn format_castling_availability(board: &Board, castling_notation: &CastlingNotation) -> String {
+    let castling_availability = board.castling_availability();
+    let piece_placement = board.piece_placement();
+    let mut field = String::new();
+
+    for color in [Color::White, Color::Black] {
+        let options = [
+            (castling_availability.kingside_rook_file(&color), true, 'k'),
+            (castling_availability.queenside_rook_file(&color), false, 'q'),
+        ];
+
+        for (rook_file, kingside, letter) in options {
+            let rook_file = match rook_file {
+                Some(rook_file) => rook_file,
+                None => {
+                    continue;
+                }
+            };
+
+            // Only use the letter K or Q if it unambiguously refers to the outermost rook
+            let character = match castling_notation {
+                CastlingNotation::Standard if outermost_rook_file(piece_placement, &color, kingside) == rook_file => {
+                    letter
+                }
+                _ => format_file(rook_file),
+            };
+
+            // White castling availability is written in uppercase
+            match color {
+                Color::White => field.push(character.to_ascii_uppercase()),
+                Color::Black => field.push(character),
+            }
+        }
+    }
+
+    // A dash indicates that no player can castle anymore
+    if field.is_empty() {
+        field.push('-');
+    }
+
+    field
+}
+
+fn format_en_passant_target_square(en_passant_target_square: Option<&Square>) -> String {
+    match en_passant_target_square {
+        Some(square) => square.to_string(),
+        None => String::from("-"),
+    }
+}
+
+pub(crate) fn format_file(file: File) -> char {
+    match file {
+        1 => 'a',
+        2 => 'b',
+        3 => 'c',
+        4 => 'd',
+        5 => 'e',
+        6 => 'f',
+        7 => 'g',
+        8 => 'h',
+        _ => {
+            panic!("file needs to be between 1 and 8");
+        }
+    }
+}
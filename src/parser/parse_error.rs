@@ -1,5 +1,7 @@
 // External imports
 use thiserror::Error;
+// Absolute imports within crate
+use crate::board::PositionError;
 
 // Enum to represent the different error types
 #[derive(Error, Debug, PartialEq)]
@@ -20,4 +22,6 @@ pub enum ParseError {
     UnexpectedEnd,
     #[error("not all squares were provided in piece placement field")]
     IncompletePiecePlacement,
+    #[error(transparent)]
+    InvalidPosition(#[from] PositionError),
 }
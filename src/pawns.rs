@@ -0,0 +1,320 @@
+// External imports
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashSet;
+use std::hash::{Hash, Hasher};
+// Absolute imports within crate
+use crate::board::{Board, File, Rank, Square, SquareList};
+use crate::piece::{Color, Kind};
+
+// Relative imports of sub modules
+pub use pawn::Pawn;
+pub use pawn_cache::PawnCache;
+mod pawn;
+mod pawn_cache;
+
+// Classification of all pawns on a board, which only depends on the placement of the pawns
+#[derive(Debug, PartialEq, Clone)]
+pub struct PawnStructure {
+    // Sorted by color, file and rank
+    pawns: Vec<Pawn>,
+}
+
+impl PawnStructure {
+    pub fn new(board: &Board) -> PawnStructure {
+        let placement = PawnPlacement::new(board);
+        let mut pawns = Vec::new();
+
+        for color in [Color::White, Color::Black] {
+            let islands = placement.islands(&color);
+
+            for square in placement.squares(&color) {
+                let (file, rank) = (square.file(), square.rank());
+                let opponent = color.opposite();
+                let adjacent_files = [file - 1, file + 1];
+                let forward = forward(&color);
+
+                let passed = placement.is_passed(&color, &square);
+                let protected = adjacent_files
+                    .iter()
+                    .any(|file| placement.has(&color, *file, rank - forward));
+                let isolated = adjacent_files
+                    .iter()
+                    .all(|file| placement.count_on_file(&color, *file) == 0);
+                let doubled = placement.count_on_file(&color, file) > 1;
+                let connected = adjacent_files.iter().any(|file| {
+                    [rank - 1, rank, rank + 1]
+                        .iter()
+                        .any(|rank| placement.has(&color, *file, *rank))
+                });
+
+                // Pawns on the adjacent files that can still defend it are beside or behind it
+                let helpers: usize = adjacent_files
+                    .iter()
+                    .map(|file| placement.count_behind(&color, *file, &color, rank + forward))
+                    .sum();
+                let stop_is_attacked = adjacent_files
+                    .iter()
+                    .any(|file| placement.has(&opponent, *file, rank + 2 * forward));
+                let backward = !isolated && helpers == 0 && stop_is_attacked;
+
+                // Pawns of the opponent on the adjacent files in front of it have to be exchanged to make it passed
+                let sentries: usize = adjacent_files
+                    .iter()
+                    .map(|file| placement.count_ahead(&opponent, *file, &color, rank))
+                    .sum();
+                let candidate =
+                    !passed && placement.count_ahead(&opponent, file, &color, rank) == 0 && helpers >= sentries;
+
+                let island = islands
+                    .iter()
+                    .position(|island| island.contains(&file))
+                    .expect("every pawn is on an island");
+
+                pawns.push(Pawn {
+                    square,
+                    color: color.clone(),
+                    passed,
+                    protected,
+                    isolated,
+                    doubled,
+                    backward,
+                    connected,
+                    candidate,
+                    island,
+                });
+            }
+        }
+
+        PawnStructure { pawns }
+    }
+
+    pub fn pawns(&self) -> &[Pawn] {
+        &self.pawns
+    }
+
+    pub fn pawn(&self, square: &Square) -> Option<&Pawn> {
+        self.pawns.iter().find(|pawn| pawn.square() == square)
+    }
+
+    pub fn pawns_of(&self, color: &Color) -> Vec<&Pawn> {
+        self.pawns
+            .iter()
+            .filter(|pawn| pawn.color() == color)
+            .collect()
+    }
+
+    // Groups of pawns on adjacent files, counted from the A-file
+    pub fn islands(&self, color: &Color) -> Vec<SquareList> {
+        let mut islands: Vec<SquareList> = Vec::new();
+
+        for pawn in self.pawns_of(color) {
+            match islands.get_mut(pawn.island()) {
+                Some(island) => island.push(pawn.square().copy()),
+                None => islands.push(vec![pawn.square().copy()]),
+            }
+        }
+
+        islands
+    }
+
+    // Diagonal lines of pawns defending each other, each starting at the base of the chain
+    pub fn chains(&self, color: &Color) -> Vec<SquareList> {
+        let squares: Vec<&Square> = self.pawns_of(color).into_iter().map(Pawn::square).collect();
+        let forward = forward(color);
+        let has = |file: File, rank: Rank| {
+            squares
+                .iter()
+                .any(|square| square.file() == file && square.rank() == rank)
+        };
+        let mut chains = Vec::new();
+
+        for square in squares.iter() {
+            for direction in [-1, 1] {
+                // Start only at the base, so every chain is found once
+                if has(square.file() - direction, square.rank() - forward) {
+                    continue;
+                }
+
+                let mut chain = vec![square.copy()];
+                let (mut file, mut rank) = (square.file() + direction, square.rank() + forward);
+                while has(file, rank) {
+                    chain.push(Square::new(file, rank));
+                    file += direction;
+                    rank += forward;
+                }
+
+                if chain.len() > 1 {
+                    chains.push(chain);
+                }
+            }
+        }
+
+        chains
+    }
+}
+
+// Hash of the pawns on the board, which is the key of the pawn cache
+pub fn pawn_hash(board: &Board) -> u64 {
+    let mut pawns: Vec<(File, Rank, bool)> = board
+        .piece_placement()
+        .iter()
+        .filter(|(_, piece)| piece.kind() == &Kind::Pawn)
+        .map(|(square, piece)| (square.file(), square.rank(), piece.color() == &Color::White))
+        .collect();
+    pawns.sort();
+
+    let mut hasher = DefaultHasher::new();
+    pawns.hash(&mut hasher);
+    hasher.finish()
+}
+
+// Rule of the square: whether the king of the opponent is too far away to catch the passed pawn on the square
+// NOTE: only applies when the opponent has no pieces besides the king and pawns, and pieces in the way are ignored
+pub fn is_unstoppable(board: &Board, square: &Square) -> bool {
+    let color = match board.piece_placement().get(square) {
+        Some(piece) if piece.kind() == &Kind::Pawn => piece.color(),
+        _ => {
+            return false;
+        }
+    };
+    let opponent = color.opposite();
+
+    let is_passed = PawnPlacement::new(board).is_passed(color, square);
+    let only_pawns = board
+        .piece_placement()
+        .values()
+        .filter(|piece| piece.color() == &opponent)
+        .all(|piece| matches!(piece.kind(), Kind::King | Kind::Pawn));
+    let king = board
+        .piece_placement()
+        .iter()
+        .find(|(_, piece)| piece.color() == &opponent && piece.kind() == &Kind::King)
+        .map(|(square, _)| square);
+
+    match king {
+        Some(king) if is_passed && only_pawns => {
+            // The first move of a pawn can be two squares
+            let promotion_rank = color.get_eight_rank();
+            let mut moves = (promotion_rank - square.rank()).abs();
+            if square.rank() == color.get_second_rank() {
+                moves -= 1;
+            }
+
+            let king_distance = (king.file() - square.file())
+                .abs()
+                .max((king.rank() - promotion_rank).abs());
+            // The king can still capture the new queen when it is next to the promotion square
+            let tempo = if board.active_color() == &opponent { 1 } else { 0 };
+
+            king_distance > moves + tempo
+        }
+        _ => false,
+    }
+}
+
+// Direction in which the pawns of the color move
+fn forward(color: &Color) -> Rank {
+    match color {
+        Color::White => 1,
+        Color::Black => -1,
+    }
+}
+
+// Squares of the pawns of both colors for quick lookups
+struct PawnPlacement {
+    white: HashSet<(File, Rank)>,
+    black: HashSet<(File, Rank)>,
+}
+
+impl PawnPlacement {
+    fn new(board: &Board) -> PawnPlacement {
+        let mut placement = PawnPlacement {
+            white: HashSet::new(),
+            black: HashSet::new(),
+        };
+
+        for (square, piece) in board.piece_placement().iter() {
+            if piece.kind() == &Kind::Pawn {
+                let pawns = match piece.color() {
+                    Color::White => &mut placement.white,
+                    Color::Black => &mut placement.black,
+                };
+                pawns.insert((square.file(), square.rank()));
+            }
+        }
+
+        placement
+    }
+
+    fn pawns(&self, color: &Color) -> &HashSet<(File, Rank)> {
+        match color {
+            Color::White => &self.white,
+            Color::Black => &self.black,
+        }
+    }
+
+    // Squares of the pawns of the color, sorted by file and rank
+    fn squares(&self, color: &Color) -> SquareList {
+        let mut squares: Vec<&(File, Rank)> = self.pawns(color).iter().collect();
+        squares.sort();
+
+        squares
+            .into_iter()
+            .map(|(file, rank)| Square::new(*file, *rank))
+            .collect()
+    }
+
+    fn has(&self, color: &Color, file: File, rank: Rank) -> bool {
+        self.pawns(color).contains(&(file, rank))
+    }
+
+    fn count_on_file(&self, color: &Color, file: File) -> usize {
+        self.pawns(color)
+            .iter()
+            .filter(|(pawn_file, _)| *pawn_file == file)
+            .count()
+    }
+
+    // Pawns of the color on the file in front of the rank, from the point of view of the given side
+    fn count_ahead(&self, color: &Color, file: File, side: &Color, rank: Rank) -> usize {
+        self.pawns(color)
+            .iter()
+            .filter(|(pawn_file, pawn_rank)| *pawn_file == file && (pawn_rank - rank) * forward(side) > 0)
+            .count()
+    }
+
+    // Pawns of the color on the file behind the rank, from the point of view of the given side
+    fn count_behind(&self, color: &Color, file: File, side: &Color, rank: Rank) -> usize {
+        self.pawns(color)
+            .iter()
+            .filter(|(pawn_file, pawn_rank)| *pawn_file == file && (pawn_rank - rank) * forward(side) < 0)
+            .count()
+    }
+
+    fn is_passed(&self, color: &Color, square: &Square) -> bool {
+        let opponent = color.opposite();
+
+        (square.file() - 1..=square.file() + 1).all(|file| self.count_ahead(&opponent, file, color, square.rank()) == 0)
+    }
+
+    // Groups of adjacent files with pawns of the color
+    fn islands(&self, color: &Color) -> Vec<Vec<File>> {
+        let mut islands: Vec<Vec<File>> = Vec::new();
+        let mut previous_has_pawn = false;
+
+        for file in 1..=8 {
+            let has_pawn = self.count_on_file(color, file) > 0;
+            match (has_pawn, previous_has_pawn) {
+                (true, true) => islands
+                    .last_mut()
+                    .expect("previous file started an island")
+                    .push(file),
+                (true, false) => islands.push(vec![file]),
+                _ => {}
+            }
+            previous_has_pawn = has_pawn;
+        }
+
+        islands
+    }
+}
@@ -0,0 +1,70 @@
+// Absolute imports within crate
+use crate::board::Square;
+use crate::piece::Color;
+
+// Classification of a single pawn in the pawn structure
+#[derive(Debug, PartialEq, Clone)]
+pub struct Pawn {
+    pub(super) square: Square,
+    pub(super) color: Color,
+    pub(super) passed: bool,
+    pub(super) protected: bool,
+    pub(super) isolated: bool,
+    pub(super) doubled: bool,
+    pub(super) backward: bool,
+    pub(super) connected: bool,
+    pub(super) candidate: bool,
+    pub(super) island: usize,
+}
+
+impl Pawn {
+    pub fn square(&self) -> &Square {
+        &self.square
+    }
+
+    pub fn color(&self) -> &Color {
+        &self.color
+    }
+
+    // No pawns of the opponent in front of it on the same or adjacent files
+    pub fn is_passed(&self) -> bool {
+        self.passed
+    }
+
+    // Passed pawn defended by a pawn of its own color
+    pub fn is_protected_passed(&self) -> bool {
+        self.passed && self.protected
+    }
+
+    // No pawns of its own color on the adjacent files
+    pub fn is_isolated(&self) -> bool {
+        self.isolated
+    }
+
+    // Another pawn of its own color on the same file
+    pub fn is_doubled(&self) -> bool {
+        self.doubled
+    }
+
+    // Cannot be defended by other pawns and cannot advance safely, since a pawn of the opponent controls the square
+    // in front of it
+    pub fn is_backward(&self) -> bool {
+        self.backward
+    }
+
+    // Pawn of its own color next to it or diagonally in front or behind it
+    pub fn is_connected(&self) -> bool {
+        self.connected
+    }
+
+    // Pawn on a file without pawns of the opponent in front of it, which can become passed by exchanging the pawns
+    // of the opponent on the adjacent files
+    pub fn is_candidate(&self) -> bool {
+        self.candidate
+    }
+
+    // Index of the pawn island of its color, counted from the A-file
+    pub fn island(&self) -> usize {
+        self.island
+    }
+}
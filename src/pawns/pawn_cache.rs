@@ -0,0 +1,48 @@
+// External imports
+use std::collections::HashMap;
+// Absolute imports within crate
+use crate::board::Board;
+// Imports from parent
+use super::{pawn_hash, PawnStructure};
+
+// Pawn structures by the hash of the pawns, since the pawns change far less often than the other pieces
+#[derive(Debug, Clone)]
+pub struct PawnCache {
+    entries: HashMap<u64, PawnStructure>,
+    capacity: usize,
+}
+
+impl PawnCache {
+    // Cache that is cleared when it holds the given number of pawn structures
+    pub fn new(capacity: usize) -> PawnCache {
+        PawnCache {
+            entries: HashMap::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    // Pawn structure of the board, which is only analyzed if it is not in the cache yet
+    pub fn get(&mut self, board: &Board) -> &PawnStructure {
+        let hash = pawn_hash(board);
+
+        if !self.entries.contains_key(&hash) && self.entries.len() >= self.capacity {
+            self.entries.clear();
+        }
+
+        self.entries
+            .entry(hash)
+            .or_insert_with(|| PawnStructure::new(board))
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
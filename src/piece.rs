@@ -7,7 +7,8 @@ pub use kind::Kind;
 mod color;
 mod kind;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Piece {
     color: Color,
     kind: Kind,
@@ -3,7 +3,9 @@ use std::fmt;
 
 const PROMOTABLE_KINDS: [Kind; 4] = [Kind::Bishop, Kind::Knight, Kind::Rook, Kind::Queen];
 
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 pub enum Kind {
     Bishop,
     King,
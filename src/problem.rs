@@ -0,0 +1,202 @@
+// Absolute imports within crate
+use crate::board::{Board, ChessMove};
+use crate::game::PlayedMove;
+
+// Relative imports of sub modules
+pub use problem_type::ProblemType;
+pub use solution::Solution;
+pub use try_move::TryMove;
+pub use variation::Variation;
+mod problem_type;
+mod solution;
+mod try_move;
+mod variation;
+
+// Find every key that solves the problem in the given number of moves of the player to move
+// NOTE: the search is exhaustive, so it quickly becomes slow for more than a few moves
+pub fn solve(board: &Board, problem_type: &ProblemType, moves: usize) -> Solution {
+    if moves == 0 {
+        return Solution::new(problem_type.clone(), moves, Vec::new(), Vec::new());
+    }
+
+    let solver: &dyn Stipulation = match problem_type {
+        ProblemType::Directmate => &Directmate,
+        ProblemType::Selfmate => &Selfmate,
+        ProblemType::Helpmate => {
+            return Solution::new(problem_type.clone(), moves, helpmates(board, moves), Vec::new());
+        }
+    };
+
+    let mut keys = Vec::new();
+    let mut tries = Vec::new();
+
+    for chess_move in board.legal_moves() {
+        let after = play(board, &chess_move);
+
+        if solver.defence_fails(&after, moves) {
+            keys.push(solver.variation(board, &chess_move, moves));
+            continue;
+        }
+
+        // Moves that fail to a single defence are the tries of the problem
+        let refutations: Vec<ChessMove> = after
+            .legal_moves()
+            .into_iter()
+            .filter(|reply| !solver.defence_fails_after(&after, reply, moves))
+            .collect();
+        if let [refutation] = refutations.as_slice() {
+            tries.push(TryMove::new(played_move(board, &chess_move), played_move(&after, refutation)));
+        }
+    }
+
+    Solution::new(problem_type.clone(), moves, keys, tries)
+}
+
+// Problem types where the player to move has to succeed against every defence
+trait Stipulation {
+    // Whether every defence of the player to move fails within the given number of moves
+    fn defence_fails(&self, board: &Board, moves: usize) -> bool;
+
+    // Whether the defence is answered within the given number of moves, counting the move that was defended
+    fn defence_fails_after(&self, board: &Board, reply: &ChessMove, moves: usize) -> bool;
+
+    // Whether the player to move can make a move after which every defence fails
+    fn succeeds(&self, board: &Board, moves: usize) -> bool {
+        moves > 0
+            && board
+                .legal_moves()
+                .iter()
+                .any(|chess_move| self.defence_fails(&play(board, chess_move), moves))
+    }
+
+    // Move of the solving player that succeeds, with all defences and the moves that answer them
+    fn variation(&self, board: &Board, chess_move: &ChessMove, moves: usize) -> Variation {
+        let after = play(board, chess_move);
+
+        let replies = after
+            .legal_moves()
+            .iter()
+            .map(|reply| {
+                let after_reply = play(&after, reply);
+                Variation::new(played_move(&after, reply), Vec::new(), self.continuations(&after_reply, moves - 1))
+            })
+            .collect();
+
+        // The threat is what would happen if the opponent could pass
+        let threats = if moves > 1 && !after.is_in_check(after.active_color()) {
+            let mut passed = after.clone();
+            passed.make_null_move();
+            self.continuations(&passed, moves - 1)
+        } else {
+            Vec::new()
+        };
+
+        Variation::new(played_move(board, chess_move), threats, replies)
+    }
+
+    // All moves of the solving player that still succeed in the remaining number of moves
+    fn continuations(&self, board: &Board, moves: usize) -> Vec<Variation> {
+        if moves == 0 {
+            return Vec::new();
+        }
+
+        board
+            .legal_moves()
+            .iter()
+            .filter(|chess_move| self.defence_fails(&play(board, chess_move), moves))
+            .map(|chess_move| self.variation(board, chess_move, moves))
+            .collect()
+    }
+}
+
+// The player to move checkmates the opponent
+struct Directmate;
+
+impl Stipulation for Directmate {
+    fn defence_fails(&self, board: &Board, moves: usize) -> bool {
+        let replies = board.legal_moves();
+        if replies.is_empty() {
+            // Stalemate is a successful defence
+            return board.is_in_check(board.active_color());
+        }
+
+        replies
+            .iter()
+            .all(|reply| self.defence_fails_after(board, reply, moves))
+    }
+
+    fn defence_fails_after(&self, board: &Board, reply: &ChessMove, moves: usize) -> bool {
+        self.succeeds(&play(board, reply), moves - 1)
+    }
+}
+
+// The opponent of the player to move has to checkmate them
+struct Selfmate;
+
+impl Stipulation for Selfmate {
+    fn defence_fails(&self, board: &Board, moves: usize) -> bool {
+        let replies = board.legal_moves();
+
+        // Without any moves the opponent cannot give checkmate
+        !replies.is_empty()
+            && replies
+                .iter()
+                .all(|reply| self.defence_fails_after(board, reply, moves))
+    }
+
+    fn defence_fails_after(&self, board: &Board, reply: &ChessMove, moves: usize) -> bool {
+        let after = play(board, reply);
+        is_checkmate(&after) || self.succeeds(&after, moves - 1)
+    }
+}
+
+// Lines where the player to move helps the opponent to checkmate them, with the opponent mating on their last move
+fn helpmates(board: &Board, moves: usize) -> Vec<Variation> {
+    if moves == 0 {
+        return Vec::new();
+    }
+
+    board
+        .legal_moves()
+        .iter()
+        .filter_map(|chess_move| {
+            let after = play(board, chess_move);
+
+            let mates: Vec<Variation> = after
+                .legal_moves()
+                .iter()
+                .filter_map(|reply| {
+                    let after_reply = play(&after, reply);
+
+                    if moves == 1 {
+                        is_checkmate(&after_reply)
+                            .then(|| Variation::new(played_move(&after, reply), Vec::new(), Vec::new()))
+                    } else {
+                        let continuations = helpmates(&after_reply, moves - 1);
+                        (!continuations.is_empty())
+                            .then(|| Variation::new(played_move(&after, reply), Vec::new(), continuations))
+                    }
+                })
+                .collect();
+
+            (!mates.is_empty()).then(|| Variation::new(played_move(board, chess_move), Vec::new(), mates))
+        })
+        .collect()
+}
+
+fn play(board: &Board, chess_move: &ChessMove) -> Board {
+    let mut board = board.clone();
+    board
+        .make_move(chess_move.clone())
+        .expect("legal moves can be made");
+
+    board
+}
+
+fn played_move(board: &Board, chess_move: &ChessMove) -> PlayedMove {
+    PlayedMove::new(chess_move.clone(), board.to_standard_algebraic_notation(chess_move))
+}
+
+fn is_checkmate(board: &Board) -> bool {
+    board.is_in_check(board.active_color()) && board.legal_moves().is_empty()
+}
@@ -0,0 +1,10 @@
+// Stipulation of a chess problem, for the player to move
+#[derive(Debug, PartialEq, Clone)]
+pub enum ProblemType {
+    // Force checkmate of the opponent against any defence
+    Directmate,
+    // Force the opponent to checkmate you against their will
+    Selfmate,
+    // Both players cooperate, so the opponent checkmates the player to move
+    Helpmate,
+}
@@ -0,0 +1,45 @@
+// Imports from parent
+use super::{ProblemType, TryMove, Variation};
+
+// Complete solution of a problem
+#[derive(Debug, Clone)]
+pub struct Solution {
+    problem_type: ProblemType,
+    moves: usize,
+    keys: Vec<Variation>,
+    tries: Vec<TryMove>,
+}
+
+impl Solution {
+    pub fn new(problem_type: ProblemType, moves: usize, keys: Vec<Variation>, tries: Vec<TryMove>) -> Solution {
+        Solution {
+            problem_type,
+            moves,
+            keys,
+            tries,
+        }
+    }
+
+    pub fn problem_type(&self) -> &ProblemType {
+        &self.problem_type
+    }
+
+    pub fn moves(&self) -> usize {
+        self.moves
+    }
+
+    // First moves that solve the problem, each with the full tree of defences and continuations
+    // For helpmates every key is the start of a separate solution
+    pub fn keys(&self) -> &[Variation] {
+        &self.keys
+    }
+
+    pub fn tries(&self) -> &[TryMove] {
+        &self.tries
+    }
+
+    // A sound problem has exactly one key
+    pub fn is_sound(&self) -> bool {
+        self.keys.len() == 1
+    }
+}
@@ -0,0 +1,26 @@
+// Absolute imports within crate
+use crate::game::PlayedMove;
+
+// First move that looks like a solution, but is refuted by a single defence
+#[derive(Debug, Clone)]
+pub struct TryMove {
+    played_move: PlayedMove,
+    refutation: PlayedMove,
+}
+
+impl TryMove {
+    pub fn new(played_move: PlayedMove, refutation: PlayedMove) -> TryMove {
+        TryMove {
+            played_move,
+            refutation,
+        }
+    }
+
+    pub fn played_move(&self) -> &PlayedMove {
+        &self.played_move
+    }
+
+    pub fn refutation(&self) -> &PlayedMove {
+        &self.refutation
+    }
+}
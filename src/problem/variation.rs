@@ -0,0 +1,35 @@
+// Absolute imports within crate
+use crate::game::PlayedMove;
+
+// Move in the solution of a problem, with the moves that can follow it
+#[derive(Debug, Clone)]
+pub struct Variation {
+    played_move: PlayedMove,
+    threats: Vec<Variation>,
+    replies: Vec<Variation>,
+}
+
+impl Variation {
+    pub fn new(played_move: PlayedMove, threats: Vec<Variation>, replies: Vec<Variation>) -> Variation {
+        Variation {
+            played_move,
+            threats,
+            replies,
+        }
+    }
+
+    pub fn played_move(&self) -> &PlayedMove {
+        &self.played_move
+    }
+
+    // What the move threatens if the opponent could pass, only set for moves without check
+    pub fn threats(&self) -> &[Variation] {
+        &self.threats
+    }
+
+    // Every defence after a move of the solving player, or the moves that continue the solution after a defence
+    // More than one continuation is a dual
+    pub fn replies(&self) -> &[Variation] {
+        &self.replies
+    }
+}
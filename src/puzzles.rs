@@ -0,0 +1,124 @@
+// External imports
+use std::io::{self, BufRead, Write};
+// Absolute imports within crate
+use crate::piece::Color;
+
+// Relative imports of sub modules
+pub use attempt::Attempt;
+pub use attempt_status::AttemptStatus;
+pub use history_entry::HistoryEntry;
+pub use phase::Phase;
+pub use puzzle::Puzzle;
+pub use puzzle_error::PuzzleError;
+pub use puzzle_generator::PuzzleGenerator;
+pub use training_profile::TrainingProfile;
+mod attempt;
+mod attempt_status;
+mod history_entry;
+mod phase;
+mod puzzle;
+mod puzzle_error;
+mod puzzle_generator;
+mod training_profile;
+
+// Columns of a line written by `Puzzle::to_csv_record`
+pub const CSV_HEADER: &str = "FEN,Moves,Difficulty,Themes,Source,Ply";
+
+// Puzzles as CSV with a header line
+pub fn to_csv(puzzles: &[Puzzle]) -> String {
+    let mut csv = format!("{}\n", CSV_HEADER);
+
+    for puzzle in puzzles {
+        csv.push_str(&puzzle.to_csv_record());
+        csv.push('\n');
+    }
+
+    csv
+}
+
+// Read puzzles written by `to_csv`, where a line that cannot be read does not stop the others
+pub fn read_csv(csv: &str) -> Vec<Result<Puzzle, PuzzleError>> {
+    csv.lines()
+        .filter(|line| !line.trim().is_empty() && *line != CSV_HEADER)
+        .map(Puzzle::from_csv_record)
+        .collect()
+}
+
+// Puzzles as JSON Lines, with one object per line
+pub fn to_json_lines(puzzles: &[Puzzle]) -> String {
+    puzzles
+        .iter()
+        .map(|puzzle| format!("{}\n", puzzle.to_json()))
+        .collect()
+}
+
+// Solve the puzzles one by one in the terminal, updating the profile after each attempt
+// Moves are read in standard algebraic or UCI notation, besides the commands hint, skip and quit
+pub fn train(
+    puzzles: &[Puzzle], profile: &mut TrainingProfile, input: &mut impl BufRead, output: &mut impl Write,
+) -> io::Result<()> {
+    for (index, puzzle) in puzzles.iter().enumerate() {
+        let color = match puzzle.board().active_color() {
+            Color::White => "White",
+            Color::Black => "Black",
+        };
+        writeln!(
+            output,
+            "Puzzle {} of {} ({}), {} to move. Your rating: {:.0}",
+            index + 1,
+            puzzles.len(),
+            puzzle.difficulty(),
+            color,
+            profile.rating()
+        )?;
+
+        let mut attempt = Attempt::new(puzzle);
+        while !attempt.is_finished() {
+            write!(output, "{}> ", attempt.board())?;
+            output.flush()?;
+
+            let mut line = String::new();
+            if input.read_line(&mut line)? == 0 {
+                return Ok(());
+            }
+
+            match line.trim() {
+                "" => {}
+                "quit" => {
+                    return Ok(());
+                }
+                "skip" => {
+                    break;
+                }
+                "hint" => {
+                    if let Some(expected) = attempt.expected_move() {
+                        writeln!(output, "Move the piece on {}.", expected.origin_square())?;
+                    }
+                }
+                notation => match attempt.play(notation) {
+                    Ok(AttemptStatus::Correct(reply)) => {
+                        writeln!(output, "Correct! The opponent plays {}.", reply.standard_algebraic_notation())?;
+                    }
+                    Ok(AttemptStatus::Solved) => {
+                        profile.record(puzzle, true);
+                        writeln!(output, "Solved! Your rating is now {:.0}.", profile.rating())?;
+                    }
+                    Ok(AttemptStatus::Incorrect(expected)) => {
+                        profile.record(puzzle, false);
+                        writeln!(
+                            output,
+                            "Incorrect, {} was the solution. Your rating is now {:.0}.",
+                            expected.standard_algebraic_notation(),
+                            profile.rating()
+                        )?;
+                    }
+                    Err(error) => {
+                        writeln!(output, "{}", error)?;
+                    }
+                },
+            }
+        }
+    }
+
+    writeln!(output, "No puzzles left.")
+}
@@ -0,0 +1,94 @@
+// Absolute imports within crate
+use crate::board::{Board, BoardError, ChessMove};
+use crate::game::PlayedMove;
+use crate::search::is_same_move;
+// Imports from parent
+use super::{AttemptStatus, Puzzle};
+
+// Solving a puzzle move by move, where the replies of the opponent are played automatically
+// The attempt fails on the first move that is not part of the solution
+#[derive(Debug, Clone)]
+pub struct Attempt {
+    board: Board,
+    solution: Vec<ChessMove>,
+    // Number of moves of the solution played so far
+    ply: usize,
+    is_finished: bool,
+    is_solved: bool,
+}
+
+impl Attempt {
+    pub fn new(puzzle: &Puzzle) -> Attempt {
+        Attempt {
+            board: puzzle.board().clone(),
+            solution: puzzle.solution().to_vec(),
+            ply: 0,
+            is_finished: false,
+            is_solved: false,
+        }
+    }
+
+    // Current position, with the player solving the puzzle to move
+    pub fn board(&self) -> &Board {
+        &self.board
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.is_finished
+    }
+
+    pub fn is_solved(&self) -> bool {
+        self.is_solved
+    }
+
+    // Next move of the solution, to give a hint or show the solution
+    pub fn expected_move(&self) -> Option<&ChessMove> {
+        self.solution.get(self.ply).filter(|_| !self.is_finished)
+    }
+
+    // Make a move in standard algebraic or UCI notation
+    pub fn play(&mut self, notation: &str) -> Result<AttemptStatus, BoardError> {
+        let expected = match self.expected_move() {
+            Some(expected) => expected.clone(),
+            None => {
+                return Err(BoardError::IllegalMove(notation.to_string()));
+            }
+        };
+        let chess_move = self.board.parse_standard_algebraic_notation(notation)?;
+
+        let mut after = self.board.clone();
+        after.make_move(chess_move.clone())?;
+        let is_checkmate = after.is_in_check(after.active_color()) && after.legal_moves().is_empty();
+
+        // Any checkmate solves the puzzle, even when the solution has another move or a longer line
+        if is_checkmate || (is_same_move(&chess_move, &expected) && self.ply + 1 == self.solution.len()) {
+            self.board = after;
+            self.finish(true);
+            return Ok(AttemptStatus::Solved);
+        }
+
+        if !is_same_move(&chess_move, &expected) {
+            let expected = PlayedMove::new(expected.clone(), self.board.to_standard_algebraic_notation(&expected));
+            self.finish(false);
+            return Ok(AttemptStatus::Incorrect(expected));
+        }
+
+        let reply = self.solution[self.ply + 1].clone();
+        let reply = PlayedMove::new(reply.clone(), after.to_standard_algebraic_notation(&reply));
+        after.make_move(reply.chess_move().clone())?;
+        self.board = after;
+        self.ply += 2;
+
+        Ok(AttemptStatus::Correct(reply))
+    }
+
+    // Give up, which counts as a failed attempt
+    pub fn resign(&mut self) {
+        self.finish(false);
+    }
+
+    fn finish(&mut self, is_solved: bool) {
+        self.is_finished = true;
+        self.is_solved = is_solved;
+    }
+}
@@ -0,0 +1,13 @@
+// Absolute imports within crate
+use crate::game::PlayedMove;
+
+// Result of a move made while solving a puzzle
+#[derive(Debug, Clone)]
+pub enum AttemptStatus {
+    // The move is part of the solution, followed by the reply of the opponent
+    Correct(PlayedMove),
+    // The last move of the solution, or another move that gives checkmate
+    Solved,
+    // The move is not part of the solution, together with the move that was expected
+    Incorrect(PlayedMove),
+}
@@ -0,0 +1,31 @@
+// Attempt at a puzzle in the history of a training profile
+#[derive(Debug, PartialEq, Clone)]
+pub struct HistoryEntry {
+    // Position of the puzzle in Forsyth-Edwards notation
+    position: String,
+    is_solved: bool,
+    // Rating of the player after the attempt
+    rating: f32,
+}
+
+impl HistoryEntry {
+    pub fn new(position: String, is_solved: bool, rating: f32) -> HistoryEntry {
+        HistoryEntry {
+            position,
+            is_solved,
+            rating,
+        }
+    }
+
+    pub fn position(&self) -> &str {
+        &self.position
+    }
+
+    pub fn is_solved(&self) -> bool {
+        self.is_solved
+    }
+
+    pub fn rating(&self) -> f32 {
+        self.rating
+    }
+}
@@ -0,0 +1,50 @@
+// External imports
+use std::fmt;
+// Absolute imports within crate
+use crate::board::Board;
+use crate::piece::Kind;
+
+// Material besides kings and pawns of both players together, below which the game is in the endgame
+const ENDGAME_MATERIAL: f32 = 26.0;
+
+// Last move number of the opening when most pieces are still on the board
+const OPENING_MOVES: u16 = 10;
+
+// Stage of the game a position is in
+#[derive(Debug, PartialEq, Clone)]
+pub enum Phase {
+    Opening,
+    Middlegame,
+    Endgame,
+}
+
+impl Phase {
+    pub fn of(board: &Board) -> Phase {
+        let material: f32 = board
+            .pieces()
+            .iter()
+            .filter(|piece| !matches!(piece.kind(), Kind::King | Kind::Pawn))
+            .map(|piece| piece.kind().relative_value())
+            .sum();
+
+        if material <= ENDGAME_MATERIAL {
+            Phase::Endgame
+        } else if board.fullmove_number() <= OPENING_MOVES {
+            Phase::Opening
+        } else {
+            Phase::Middlegame
+        }
+    }
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Phase::Opening => "opening",
+            Phase::Middlegame => "middlegame",
+            Phase::Endgame => "endgame",
+        };
+
+        write!(f, "{}", name)
+    }
+}
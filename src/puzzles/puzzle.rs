@@ -0,0 +1,323 @@
+// Absolute imports within crate
+use crate::board::{Action, Board, ChessMove};
+use crate::tactics::{self, Motif};
+// Imports from parent
+use super::{Phase, PuzzleError};
+
+// Rating of the easiest puzzles, with a single obvious move
+const BASE_DIFFICULTY: u16 = 800;
+
+// Motifs that can be written as a theme
+const MOTIFS: [Motif; 11] = [
+    Motif::Fork,
+    Motif::RoyalFork,
+    Motif::Skewer,
+    Motif::Pin,
+    Motif::DiscoveredAttack,
+    Motif::DiscoveredCheck,
+    Motif::DoubleCheck,
+    Motif::HangingPiece,
+    Motif::UnderDefendedPiece,
+    Motif::BackRankWeakness,
+    Motif::OverloadedDefender,
+];
+
+// Position where the player to move has a single winning line, which is the solution of the puzzle
+#[derive(Debug, Clone)]
+pub struct Puzzle {
+    pub(super) board: Board,
+    // Moves of the player to move, alternated with the best replies of the opponent
+    pub(super) solution: Vec<ChessMove>,
+    pub(super) motifs: Vec<Motif>,
+    pub(super) phase: Phase,
+    pub(super) is_mate: bool,
+    pub(super) difficulty: u16,
+    // Site or event of the game the puzzle comes from
+    pub(super) source: Option<String>,
+    // Number of moves played in the game before the position
+    pub(super) ply: usize,
+}
+
+impl Puzzle {
+    // Puzzle from a position and its solution in standard algebraic or UCI notation, starting with a move of the
+    // player to move
+    pub fn new(board: Board, solution: &[&str]) -> Result<Puzzle, PuzzleError> {
+        let mut position = board.clone();
+        let mut moves = Vec::new();
+
+        for notation in solution {
+            let chess_move = position
+                .parse_standard_algebraic_notation(notation)
+                .map_err(|_| PuzzleError::IllegalMove(notation.to_string()))?;
+            position.make_move(chess_move.clone())?;
+            moves.push(chess_move);
+        }
+
+        if moves.is_empty() {
+            return Err(PuzzleError::InvalidRecord(String::from("the solution has no moves")));
+        }
+        // The solution ends with a move of the solver, after every reply of the opponent
+        if moves.len().is_multiple_of(2) {
+            return Err(PuzzleError::InvalidRecord(String::from("the solution ends with a reply")));
+        }
+
+        Ok(Puzzle::from_solution(board, moves))
+    }
+
+    // Read a line written by `to_csv_record`, keeping the difficulty and source of the record
+    pub fn from_csv_record(record: &str) -> Result<Puzzle, PuzzleError> {
+        let fields = split_csv_record(record);
+        let field = |index: usize| {
+            fields
+                .get(index)
+                .map(String::as_str)
+                .ok_or_else(|| PuzzleError::InvalidRecord(record.to_string()))
+        };
+
+        let board = Board::forsyth_edwards_notation(field(0)?)?;
+        let solution: Vec<&str> = field(1)?.split_whitespace().collect();
+        let mut puzzle = Puzzle::new(board, &solution)?;
+
+        if let Ok(difficulty) = field(2) {
+            puzzle.difficulty = difficulty
+                .parse()
+                .map_err(|_| PuzzleError::InvalidRecord(record.to_string()))?;
+        }
+        // Themes like mateIn2 and the phase follow from the position and are not read back
+        if let Ok(themes) = field(3) {
+            let themes: Vec<&str> = themes.split_whitespace().collect();
+            puzzle.motifs = MOTIFS
+                .iter()
+                .filter(|motif| themes.contains(&theme(motif)))
+                .cloned()
+                .collect();
+        }
+        puzzle.source = field(4)
+            .ok()
+            .filter(|source| !source.is_empty())
+            .map(String::from);
+        if let Ok(ply) = field(5) {
+            puzzle.ply = ply
+                .parse()
+                .map_err(|_| PuzzleError::InvalidRecord(record.to_string()))?;
+        }
+
+        Ok(puzzle)
+    }
+
+    // Puzzle from legal moves, with the motifs of the moves of the solving player and an estimated difficulty
+    pub(super) fn from_solution(board: Board, solution: Vec<ChessMove>) -> Puzzle {
+        let mut motifs: Vec<Motif> = Vec::new();
+        let mut position = board.clone();
+
+        for (index, chess_move) in solution.iter().enumerate() {
+            if index.is_multiple_of(2) {
+                for tactic in tactics::find_after_move(&position, chess_move).expect("moves of the solution are legal")
+                {
+                    if !motifs.contains(tactic.motif()) {
+                        motifs.push(tactic.motif().clone());
+                    }
+                }
+            }
+            position
+                .make_move(chess_move.clone())
+                .expect("moves of the solution are legal");
+        }
+
+        let is_mate = position.is_in_check(position.active_color()) && position.legal_moves().is_empty();
+        Puzzle {
+            difficulty: difficulty(&board, &solution, &motifs),
+            phase: Phase::of(&board),
+            board,
+            solution,
+            motifs,
+            is_mate,
+            source: None,
+            ply: 0,
+        }
+    }
+
+    pub fn board(&self) -> &Board {
+        &self.board
+    }
+
+    pub fn solution(&self) -> &[ChessMove] {
+        &self.solution
+    }
+
+    pub fn motifs(&self) -> &[Motif] {
+        &self.motifs
+    }
+
+    pub fn phase(&self) -> &Phase {
+        &self.phase
+    }
+
+    // Whether the solution ends with checkmate
+    pub fn is_mate(&self) -> bool {
+        self.is_mate
+    }
+
+    // Estimated rating of a player who solves the puzzle half of the time
+    pub fn difficulty(&self) -> u16 {
+        self.difficulty
+    }
+
+    pub fn source(&self) -> Option<&str> {
+        self.source.as_deref()
+    }
+
+    pub fn ply(&self) -> usize {
+        self.ply
+    }
+
+    // Tags of the puzzle in the style of the Lichess puzzle database, like middlegame, fork or mateIn2
+    pub fn themes(&self) -> Vec<String> {
+        let mut themes = vec![self.phase.to_string()];
+
+        if self.is_mate {
+            themes.push(format!("mateIn{}", self.solution.len().div_ceil(2)));
+        }
+        themes.extend(self.motifs.iter().map(|motif| theme(motif).to_string()));
+
+        themes
+    }
+
+    // Solution written in the notation of the Universal Chess Interface
+    pub fn uci_solution(&self) -> Vec<String> {
+        self.solution.iter().map(ChessMove::uci).collect()
+    }
+
+    // Line with the columns of the CSV header, where the moves and themes are separated by spaces
+    pub fn to_csv_record(&self) -> String {
+        let fields = [
+            self.board.to_forsyth_edwards_notation(),
+            self.uci_solution().join(" "),
+            self.difficulty.to_string(),
+            self.themes().join(" "),
+            self.source.clone().unwrap_or_default(),
+            self.ply.to_string(),
+        ];
+
+        fields
+            .iter()
+            .map(|field| csv_field(field))
+            .collect::<Vec<String>>()
+            .join(",")
+    }
+
+    // Object on a single line, for a file with one puzzle per line
+    pub fn to_json(&self) -> String {
+        let list = |items: Vec<String>| {
+            items
+                .iter()
+                .map(|item| json_string(item))
+                .collect::<Vec<String>>()
+                .join(",")
+        };
+        let source = match &self.source {
+            Some(source) => json_string(source),
+            None => String::from("null"),
+        };
+
+        format!(
+            "{{\"fen\":{},\"moves\":[{}],\"difficulty\":{},\"themes\":[{}],\"source\":{},\"ply\":{}}}",
+            json_string(&self.board.to_forsyth_edwards_notation()),
+            list(self.uci_solution()),
+            self.difficulty,
+            list(self.themes()),
+            source,
+            self.ply
+        )
+    }
+}
+
+// Longer solutions, quiet first moves and sacrifices are harder to find
+fn difficulty(board: &Board, solution: &[ChessMove], motifs: &[Motif]) -> u16 {
+    let first = &solution[0];
+    let mut after = board.clone();
+    after
+        .make_move(first.clone())
+        .expect("moves of the solution are legal");
+
+    let is_forcing = first.is_capture()
+        || matches!(first.action(), Action::MovePromotion(_))
+        || after.is_in_check(after.active_color());
+    let is_sacrifice = board.see(first) < 0.0;
+
+    let mut difficulty = BASE_DIFFICULTY + 300 * (solution.len() / 2) as u16;
+    if !is_forcing {
+        difficulty += 300;
+    }
+    if is_sacrifice {
+        difficulty += 200;
+    }
+    // Combinations of motifs are harder to see than a single one
+    difficulty + 50 * motifs.len().saturating_sub(1) as u16
+}
+
+fn theme(motif: &Motif) -> &'static str {
+    match motif {
+        Motif::Fork => "fork",
+        Motif::RoyalFork => "royalFork",
+        Motif::Skewer => "skewer",
+        Motif::Pin => "pin",
+        Motif::DiscoveredAttack => "discoveredAttack",
+        Motif::DiscoveredCheck => "discoveredCheck",
+        Motif::DoubleCheck => "doubleCheck",
+        Motif::HangingPiece => "hangingPiece",
+        Motif::UnderDefendedPiece => "underDefendedPiece",
+        Motif::BackRankWeakness => "backRank",
+        Motif::OverloadedDefender => "overloading",
+    }
+}
+
+// Field quoted when it contains a separator, a quote or a line break
+fn csv_field(text: &str) -> String {
+    if text.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", text.replace('"', "\"\""))
+    } else {
+        text.to_string()
+    }
+}
+
+// Fields of a CSV line, where quoted fields can contain separators and doubled quotes
+fn split_csv_record(record: &str) -> Vec<String> {
+    let mut fields = vec![String::new()];
+    let mut is_quoted = false;
+    let mut characters = record.trim_end_matches(['\n', '\r']).chars().peekable();
+
+    while let Some(character) = characters.next() {
+        let field = fields.last_mut().expect("there is always a field");
+        match character {
+            '"' if is_quoted && characters.peek() == Some(&'"') => {
+                field.push('"');
+                characters.next();
+            }
+            '"' => is_quoted = !is_quoted,
+            ',' if !is_quoted => fields.push(String::new()),
+            character => field.push(character),
+        }
+    }
+
+    fields
+}
+
+fn json_string(text: &str) -> String {
+    let mut escaped = String::from("\"");
+
+    for character in text.chars() {
+        match character {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            character if character.is_control() => escaped.push_str(&format!("\\u{:04x}", character as u32)),
+            character => escaped.push(character),
+        }
+    }
+
+    escaped.push('"');
+    escaped
+}
@@ -0,0 +1,20 @@
+// External imports
+use thiserror::Error;
+// Absolute imports within crate
+use crate::board::BoardError;
+use crate::parser::ParseError;
+
+// Enum to represent the different error types
+#[derive(Error, Debug, PartialEq)]
+pub enum PuzzleError {
+    #[error("invalid puzzle record: {0}")]
+    InvalidRecord(String),
+    #[error("illegal move in the solution: {0}")]
+    IllegalMove(String),
+    #[error("cannot read or write the training profile: {0}")]
+    Profile(String),
+    #[error(transparent)]
+    Board(#[from] BoardError),
+    #[error(transparent)]
+    Parse(#[from] ParseError),
+}
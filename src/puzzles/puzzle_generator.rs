@@ -0,0 +1,163 @@
+// Absolute imports within crate
+use crate::board::{Board, ChessMove};
+use crate::game::Game;
+use crate::search::{SearchResult, Searcher};
+// Imports from parent
+use super::Puzzle;
+
+// Finds puzzles in games, where the player to move has exactly one move that keeps a winning advantage
+#[derive(Debug, Clone)]
+pub struct PuzzleGenerator {
+    depth: u8,
+    minimum_score: i32,
+    score_gap: i32,
+    max_moves: usize,
+}
+
+impl Default for PuzzleGenerator {
+    fn default() -> Self {
+        PuzzleGenerator::new()
+    }
+}
+
+impl PuzzleGenerator {
+    pub fn new() -> PuzzleGenerator {
+        PuzzleGenerator {
+            depth: 2,
+            minimum_score: 200,
+            score_gap: 200,
+            max_moves: 3,
+        }
+    }
+
+    // Depth of the search in plies for every move of the solution
+    pub fn depth(mut self, depth: u8) -> PuzzleGenerator {
+        self.depth = depth;
+        self
+    }
+
+    // Centipawns the best move has to win by for the position to be a puzzle
+    pub fn minimum_score(mut self, minimum_score: i32) -> PuzzleGenerator {
+        self.minimum_score = minimum_score;
+        self
+    }
+
+    // Centipawns the best move has to be better than the second best move, so the solution is unique
+    pub fn score_gap(mut self, score_gap: i32) -> PuzzleGenerator {
+        self.score_gap = score_gap;
+        self
+    }
+
+    // Maximum number of moves of the player solving the puzzle, which is at least one
+    pub fn max_moves(mut self, max_moves: usize) -> PuzzleGenerator {
+        self.max_moves = max_moves.max(1);
+        self
+    }
+
+    // Puzzles in the positions of the game, where positions on the solution of an earlier puzzle are skipped
+    pub fn find(&self, game: &Game) -> Vec<Puzzle> {
+        let mut searcher = Searcher::new();
+        let source = ["Site", "Event"]
+            .iter()
+            .filter_map(|name| game.tag(name))
+            .find(|value| !value.is_empty() && *value != "?")
+            .map(String::from);
+
+        let mut puzzles: Vec<Puzzle> = Vec::new();
+        let mut board = game.initial_position().clone();
+
+        for (ply, played_move) in game.moves().iter().enumerate() {
+            let is_on_solution = puzzles
+                .last()
+                .is_some_and(|puzzle| ply < puzzle.ply() + puzzle.solution().len());
+
+            if !is_on_solution {
+                if let Some(mut puzzle) = self.puzzle_with(&mut searcher, &board) {
+                    puzzle.source = source.clone();
+                    puzzle.ply = ply;
+                    puzzles.push(puzzle);
+                }
+            }
+
+            board
+                .make_move(played_move.chess_move().clone())
+                .expect("moves in the game are legal");
+        }
+
+        puzzles
+    }
+
+    // Puzzles in every game of a file with multiple records, skipping the games that cannot be read
+    pub fn find_in_collection(&self, records: &str) -> Vec<Puzzle> {
+        Game::portable_game_notation_collection(records)
+            .into_iter()
+            .flatten()
+            .flat_map(|game| self.find(&game))
+            .collect()
+    }
+
+    // Puzzle for the player to move in the position, if they have a unique winning move
+    pub fn puzzle(&self, board: &Board) -> Option<Puzzle> {
+        self.puzzle_with(&mut Searcher::new(), board)
+    }
+
+    fn puzzle_with(&self, searcher: &mut Searcher, board: &Board) -> Option<Puzzle> {
+        // Most positions are not winning, which is cheaper to find out than the scores of all moves
+        if searcher.search(board, self.depth).score().centipawns() < self.minimum_score {
+            return None;
+        }
+
+        // Every move of the solving player has to be unique, while the opponent always plays the best reply
+        let mut solution = Vec::new();
+        let mut position = board.clone();
+        let mut next = self.unique_move(searcher, &position);
+
+        while let Some(chess_move) = next.take() {
+            play(&mut position, &chess_move);
+            solution.push(chess_move);
+
+            if solution.len() >= 2 * self.max_moves - 1 {
+                break;
+            }
+
+            // The reply is only part of the solution when the solving player has a unique move after it
+            let reply = match searcher.search(&position, self.depth).best_move() {
+                Some(reply) => reply.clone(),
+                None => {
+                    break;
+                }
+            };
+            let mut after_reply = position.clone();
+            play(&mut after_reply, &reply);
+
+            next = self.unique_move(searcher, &after_reply);
+            if next.is_some() {
+                solution.push(reply);
+                position = after_reply;
+            }
+        }
+
+        (!solution.is_empty()).then(|| Puzzle::from_solution(board.clone(), solution))
+    }
+
+    // Best move when it wins and no other move comes close
+    fn unique_move(&self, searcher: &mut Searcher, board: &Board) -> Option<ChessMove> {
+        let results = searcher.search_moves(board, self.depth);
+        let centipawns = |result: &SearchResult| result.score().centipawns();
+
+        let best = results.first()?;
+        let is_unique = results
+            .get(1)
+            .is_none_or(|second| centipawns(best) - centipawns(second) >= self.score_gap);
+
+        (centipawns(best) >= self.minimum_score && is_unique)
+            .then(|| best.best_move().cloned())
+            .flatten()
+    }
+}
+
+fn play(board: &mut Board, chess_move: &ChessMove) {
+    board
+        .make_move(chess_move.clone())
+        .expect("moves of the solution are legal");
+}
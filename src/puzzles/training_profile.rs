@@ -0,0 +1,112 @@
+// External imports
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+// Imports from parent
+use super::{HistoryEntry, Puzzle, PuzzleError};
+
+// Rating of a player without any attempts
+const INITIAL_RATING: f32 = 1500.0;
+
+// Maximum change of the rating after a single attempt
+const K_FACTOR: f32 = 32.0;
+
+// Rating of a player solving puzzles, with the attempts so far
+// Stored as a CSV file with one line per attempt, so the rating is the rating after the last attempt
+#[derive(Debug, PartialEq, Clone)]
+pub struct TrainingProfile {
+    rating: f32,
+    history: Vec<HistoryEntry>,
+}
+
+impl Default for TrainingProfile {
+    fn default() -> Self {
+        TrainingProfile::new()
+    }
+}
+
+impl TrainingProfile {
+    pub fn new() -> TrainingProfile {
+        TrainingProfile {
+            rating: INITIAL_RATING,
+            history: Vec::new(),
+        }
+    }
+
+    // Read the profile from the file, or start a new profile when the file does not exist yet
+    pub fn load(path: &Path) -> Result<TrainingProfile, PuzzleError> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == ErrorKind::NotFound => {
+                return Ok(TrainingProfile::new());
+            }
+            Err(error) => {
+                return Err(PuzzleError::Profile(error.to_string()));
+            }
+        };
+
+        TrainingProfile::from_csv(&contents)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), PuzzleError> {
+        fs::write(path, self.to_csv()).map_err(|error| PuzzleError::Profile(error.to_string()))
+    }
+
+    pub fn from_csv(contents: &str) -> Result<TrainingProfile, PuzzleError> {
+        let mut profile = TrainingProfile::new();
+
+        for line in contents
+            .lines()
+            .skip(1)
+            .filter(|line| !line.trim().is_empty())
+        {
+            let invalid = || PuzzleError::Profile(format!("invalid line: {}", line));
+            let fields: Vec<&str> = line.split(',').collect();
+
+            let (position, is_solved, rating) = match fields.as_slice() {
+                [position, is_solved, rating] => (position, is_solved, rating),
+                _ => {
+                    return Err(invalid());
+                }
+            };
+            let is_solved = is_solved.parse().map_err(|_| invalid())?;
+            let rating = rating.parse().map_err(|_| invalid())?;
+
+            profile
+                .history
+                .push(HistoryEntry::new(position.to_string(), is_solved, rating));
+            profile.rating = rating;
+        }
+
+        Ok(profile)
+    }
+
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("FEN,Solved,Rating\n");
+
+        for entry in &self.history {
+            csv.push_str(&format!("{},{},{:.1}\n", entry.position(), entry.is_solved(), entry.rating()));
+        }
+
+        csv
+    }
+
+    pub fn rating(&self) -> f32 {
+        self.rating
+    }
+
+    pub fn history(&self) -> &[HistoryEntry] {
+        &self.history
+    }
+
+    // Update the rating like a game between the player and the puzzle, where the difficulty is the rating of the
+    // puzzle
+    pub fn record(&mut self, puzzle: &Puzzle, is_solved: bool) {
+        let expected = 1.0 / (1.0 + 10f32.powf((puzzle.difficulty() as f32 - self.rating) / 400.0));
+        let score = if is_solved { 1.0 } else { 0.0 };
+        self.rating += K_FACTOR * (score - expected);
+
+        self.history
+            .push(HistoryEntry::new(puzzle.board().to_forsyth_edwards_notation(), is_solved, self.rating));
+    }
+}
@@ -0,0 +1,13 @@
+// Relative imports of sub modules
+pub use charset::Charset;
+pub use layout::Layout;
+pub use piece_set::PieceSet;
+pub use svg_renderer::SvgRenderer;
+pub use text_renderer::TextRenderer;
+pub use theme::Theme;
+mod charset;
+mod layout;
+mod piece_set;
+mod svg_renderer;
+mod text_renderer;
+mod theme;
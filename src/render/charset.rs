@@ -0,0 +1,8 @@
+// Characters used for the pieces and the borders of a board rendered as text
+#[derive(Debug, PartialEq, Clone)]
+pub enum Charset {
+    // Figurines and box-drawing characters
+    Unicode,
+    // Letters of FEN for the pieces and plain borders, which work in any terminal or log file
+    Ascii,
+}
@@ -0,0 +1,8 @@
+// Arrangement of the squares of a board rendered as text
+#[derive(Debug, PartialEq, Clone)]
+pub enum Layout {
+    // Squares surrounded by borders, three characters wide
+    Boxed,
+    // One character per square, separated by spaces
+    Compact,
+}
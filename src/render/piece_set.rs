@@ -0,0 +1,8 @@
+// Pieces of a board rendered as SVG, which only use text and shapes so no images have to be embedded
+#[derive(Debug, PartialEq, Clone)]
+pub enum PieceSet {
+    // Chess symbols of Unicode, with the black symbols filled white for the white pieces
+    Figurine,
+    // Letters of the pieces on discs of the color of the piece
+    Letter,
+}
@@ -0,0 +1,354 @@
+// Absolute imports within crate
+use crate::board::{Board, Square};
+use crate::game::{AnnotationColor, Arrow, Game, GameTree, Highlight};
+use crate::piece::{Color, Kind, Piece};
+// Imports from parent
+use super::{PieceSet, Theme};
+
+// Size of a square in the coordinates of the SVG, independent of the size of the image
+const SQUARE: f32 = 45.0;
+const LAST_MOVE: &str = "#9bc700";
+
+// Renders a board as an SVG image, for reports and web pages
+#[derive(Debug, Clone)]
+pub struct SvgRenderer {
+    size: u32,
+    piece_set: PieceSet,
+    theme: Theme,
+    coordinates: bool,
+    orientation: Color,
+    arrows: Vec<Arrow>,
+    highlights: Vec<Highlight>,
+    last_move: Option<(Square, Square)>,
+    check: bool,
+}
+
+impl Default for SvgRenderer {
+    fn default() -> Self {
+        SvgRenderer::new()
+    }
+}
+
+impl SvgRenderer {
+    pub fn new() -> SvgRenderer {
+        SvgRenderer {
+            size: 360,
+            piece_set: PieceSet::Figurine,
+            theme: Theme::Brown,
+            coordinates: true,
+            orientation: Color::White,
+            arrows: Vec::new(),
+            highlights: Vec::new(),
+            last_move: None,
+            check: true,
+        }
+    }
+
+    // Width and height of the image in pixels
+    pub fn size(mut self, size: u32) -> SvgRenderer {
+        self.size = size;
+        self
+    }
+
+    pub fn piece_set(mut self, piece_set: PieceSet) -> SvgRenderer {
+        self.piece_set = piece_set;
+        self
+    }
+
+    pub fn theme(mut self, theme: Theme) -> SvgRenderer {
+        self.theme = theme;
+        self
+    }
+
+    // Files and ranks in the corners of the squares at the edge of the board
+    pub fn coordinates(mut self, coordinates: bool) -> SvgRenderer {
+        self.coordinates = coordinates;
+        self
+    }
+
+    // Color of the player whose side of the board is at the bottom
+    pub fn orientation(mut self, orientation: Color) -> SvgRenderer {
+        self.orientation = orientation;
+        self
+    }
+
+    pub fn arrow(mut self, arrow: Arrow) -> SvgRenderer {
+        self.arrows.push(arrow);
+        self
+    }
+
+    pub fn highlight(mut self, highlight: Highlight) -> SvgRenderer {
+        self.highlights.push(highlight);
+        self
+    }
+
+    // Origin and destination square of the move that led to the position
+    pub fn last_move(mut self, origin: Square, destination: Square) -> SvgRenderer {
+        self.last_move = Some((origin, destination));
+        self
+    }
+
+    // Red glow around a king in check
+    pub fn check(mut self, check: bool) -> SvgRenderer {
+        self.check = check;
+        self
+    }
+
+    pub fn render(&self, board: &Board) -> String {
+        let board_size = SQUARE * 8.0;
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 {0} {0}\" width=\"{1}\" height=\"{1}\">\n",
+            board_size, self.size
+        );
+        svg.push_str(
+            "<defs><radialGradient id=\"check\">\
+             <stop offset=\"0%\" stop-color=\"#ff0000\"/>\
+             <stop offset=\"50%\" stop-color=\"#e70000\"/>\
+             <stop offset=\"100%\" stop-color=\"#9e0000\" stop-opacity=\"0\"/>\
+             </radialGradient></defs>\n",
+        );
+
+        for file in 1..=8 {
+            for rank in 1..=8 {
+                let square = Square::new(file, rank);
+                let color = if (file + rank) % 2 == 1 {
+                    self.theme.light_square()
+                } else {
+                    self.theme.dark_square()
+                };
+                svg.push_str(&self.rectangle(&square, color, None));
+            }
+        }
+
+        if let Some((origin, destination)) = &self.last_move {
+            svg.push_str(&self.rectangle(origin, LAST_MOVE, Some(0.4)));
+            svg.push_str(&self.rectangle(destination, LAST_MOVE, Some(0.4)));
+        }
+        for highlight in &self.highlights {
+            svg.push_str(&self.rectangle(highlight.square(), annotation_color(highlight.color()), Some(0.5)));
+        }
+        if self.check {
+            svg.push_str(&self.check_marker(board));
+        }
+        if self.coordinates {
+            svg.push_str(&self.coordinate_labels());
+        }
+
+        let mut pieces: Vec<(&Square, &Piece)> = board.piece_placement().iter().collect();
+        // Same order for the same position, so the images can be compared
+        pieces.sort_by_key(|(square, _)| (square.rank(), square.file()));
+        for (square, piece) in pieces {
+            svg.push_str(&self.piece(square, piece));
+        }
+
+        for arrow in &self.arrows {
+            svg.push_str(&self.arrow_shape(arrow));
+        }
+
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    // Image of the position before the first move and after every move of the game
+    pub fn render_game(&self, game: &Game) -> Vec<String> {
+        self.render_tree(&GameTree::from(game))
+    }
+
+    // Image of every position of the main line, with the last move and the arrows and highlights of its comment
+    pub fn render_tree(&self, tree: &GameTree) -> Vec<String> {
+        tree.mainline()
+            .into_iter()
+            .filter_map(|id| tree.node(id))
+            .map(|node| {
+                let mut renderer = self.clone();
+                if let Some(played_move) = node.played_move() {
+                    let chess_move = played_move.chess_move();
+                    renderer =
+                        renderer.last_move(chess_move.origin_square().copy(), chess_move.destination_square().copy());
+                }
+                renderer
+                    .arrows
+                    .extend(node.annotation().arrows().iter().cloned());
+                renderer
+                    .highlights
+                    .extend(node.annotation().highlights().iter().cloned());
+
+                renderer.render(node.board())
+            })
+            .collect()
+    }
+
+    // Top left corner of the square in the image
+    fn position(&self, square: &Square) -> (f32, f32) {
+        let (column, row) = match self.orientation {
+            Color::White => (square.file() - 1, 8 - square.rank()),
+            Color::Black => (8 - square.file(), square.rank() - 1),
+        };
+
+        (column as f32 * SQUARE, row as f32 * SQUARE)
+    }
+
+    fn center(&self, square: &Square) -> (f32, f32) {
+        let (x, y) = self.position(square);
+        (x + SQUARE / 2.0, y + SQUARE / 2.0)
+    }
+
+    fn rectangle(&self, square: &Square, color: &str, opacity: Option<f32>) -> String {
+        let (x, y) = self.position(square);
+        let opacity = opacity.map_or(String::new(), |opacity| format!(" fill-opacity=\"{}\"", opacity));
+
+        format!(
+            "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"{}\"{}/>\n",
+            x, y, SQUARE, SQUARE, color, opacity
+        )
+    }
+
+    fn check_marker(&self, board: &Board) -> String {
+        let color = board.active_color();
+        if !board.is_in_check(color) {
+            return String::new();
+        }
+
+        let king = board
+            .piece_placement()
+            .iter()
+            .find(|(_, piece)| piece.color() == color && piece.kind() == &Kind::King);
+        match king {
+            Some((square, _)) => {
+                let (x, y) = self.center(square);
+                format!("<circle cx=\"{}\" cy=\"{}\" r=\"{}\" fill=\"url(#check)\"/>\n", x, y, SQUARE / 2.0)
+            }
+            None => String::new(),
+        }
+    }
+
+    // Ranks in the squares on the left and files in the squares at the bottom, in the color of the other squares
+    fn coordinate_labels(&self) -> String {
+        let (left_file, bottom_rank) = match self.orientation {
+            Color::White => (1, 1),
+            Color::Black => (8, 8),
+        };
+        let label_color = |square: &Square| {
+            if (square.file() + square.rank()) % 2 == 1 {
+                self.theme.dark_square()
+            } else {
+                self.theme.light_square()
+            }
+        };
+        let font_size = SQUARE * 0.25;
+
+        let mut labels = String::new();
+        for rank in 1..=8 {
+            let square = Square::new(left_file, rank);
+            let (x, y) = self.position(&square);
+            labels.push_str(&format!(
+                "<text x=\"{}\" y=\"{}\" font-size=\"{}\" font-family=\"sans-serif\" fill=\"{}\">{}</text>\n",
+                x + 2.0,
+                y + font_size,
+                font_size,
+                label_color(&square),
+                rank
+            ));
+        }
+        for file in 1..=8 {
+            let square = Square::new(file, bottom_rank);
+            let (x, y) = self.position(&square);
+            labels.push_str(&format!(
+                "<text x=\"{}\" y=\"{}\" font-size=\"{}\" font-family=\"sans-serif\" text-anchor=\"end\" fill=\"{}\">{}</text>\n",
+                x + SQUARE - 2.0,
+                y + SQUARE - 3.0,
+                font_size,
+                label_color(&square),
+                (b'a' + file as u8 - 1) as char
+            ));
+        }
+
+        labels
+    }
+
+    fn piece(&self, square: &Square, piece: &Piece) -> String {
+        let (x, y) = self.center(square);
+        let (fill, stroke) = match piece.color() {
+            Color::White => ("#ffffff", "#000000"),
+            Color::Black => ("#000000", "#ffffff"),
+        };
+
+        match self.piece_set {
+            PieceSet::Figurine => {
+                // The symbols of the black pieces are filled, so they can be used for both colors
+                let symbol = Piece::new(Color::Black, piece.kind().clone()).to_string();
+                format!(
+                    "<text x=\"{}\" y=\"{}\" font-size=\"{}\" text-anchor=\"middle\" dominant-baseline=\"central\" \
+                     fill=\"{}\" stroke=\"#000000\" stroke-width=\"1\">{}</text>\n",
+                    x,
+                    y,
+                    SQUARE * 0.85,
+                    fill,
+                    symbol
+                )
+            }
+            PieceSet::Letter => {
+                let letter = match piece.kind() {
+                    Kind::Pawn => String::from("P"),
+                    kind => kind.to_string(),
+                };
+                format!(
+                    "<circle cx=\"{0}\" cy=\"{1}\" r=\"{2}\" fill=\"{3}\" stroke=\"#000000\" stroke-width=\"1.5\"/>\n\
+                     <text x=\"{0}\" y=\"{1}\" font-size=\"{4}\" font-family=\"sans-serif\" font-weight=\"bold\" \
+                     text-anchor=\"middle\" dominant-baseline=\"central\" fill=\"{5}\">{6}</text>\n",
+                    x,
+                    y,
+                    SQUARE * 0.4,
+                    fill,
+                    SQUARE * 0.45,
+                    stroke,
+                    letter
+                )
+            }
+        }
+    }
+
+    // Line from the center of the origin to the head at the edge of the destination square
+    fn arrow_shape(&self, arrow: &Arrow) -> String {
+        let (x1, y1) = self.center(arrow.from());
+        let (x2, y2) = self.center(arrow.to());
+        let color = annotation_color(arrow.color());
+
+        let length = ((x2 - x1).powi(2) + (y2 - y1).powi(2)).sqrt();
+        if length == 0.0 {
+            return String::new();
+        }
+        let (dx, dy) = ((x2 - x1) / length, (y2 - y1) / length);
+        let head_length = SQUARE * 0.45;
+        let head_width = SQUARE * 0.3;
+        let (base_x, base_y) = (x2 - dx * head_length, y2 - dy * head_length);
+
+        format!(
+            "<g opacity=\"0.8\"><line x1=\"{:.1}\" y1=\"{:.1}\" x2=\"{:.1}\" y2=\"{:.1}\" stroke=\"{}\" stroke-width=\"{:.1}\"/>\
+             <polygon points=\"{:.1},{:.1} {:.1},{:.1} {:.1},{:.1}\" fill=\"{}\"/></g>\n",
+            x1,
+            y1,
+            base_x,
+            base_y,
+            color,
+            SQUARE * 0.2,
+            x2,
+            y2,
+            base_x - dy * head_width,
+            base_y + dx * head_width,
+            base_x + dy * head_width,
+            base_y - dx * head_width,
+            color
+        )
+    }
+}
+
+// Colors of arrows and highlights as used by lichess
+fn annotation_color(color: &AnnotationColor) -> &'static str {
+    match color {
+        AnnotationColor::Red => "#882020",
+        AnnotationColor::Green => "#15781b",
+        AnnotationColor::Blue => "#003088",
+        AnnotationColor::Yellow => "#e68f00",
+    }
+}
@@ -0,0 +1,330 @@
+// External imports
+use std::time::Duration;
+// Absolute imports within crate
+use crate::board::{Board, Square};
+use crate::clock::Clock;
+use crate::piece::{Color, Kind, Piece};
+// Imports from parent
+use super::{Charset, Layout};
+
+// Background colors out of the 256 colors of ANSI terminals
+const LIGHT_SQUARE: u8 = 180;
+const DARK_SQUARE: u8 = 137;
+const HIGHLIGHT: u8 = 143;
+const ANSI_RESET: &str = "\x1b[0m";
+
+// Renders a board as text, with options for terminals and log files that cannot show everything
+#[derive(Debug, Clone)]
+pub struct TextRenderer {
+    orientation: Color,
+    charset: Charset,
+    layout: Layout,
+    colors: bool,
+    coordinates: bool,
+    highlights: Vec<Square>,
+    // Squares with their own background color, where later squares take precedence
+    backgrounds: Vec<(Square, u8)>,
+    panel: bool,
+    // Remaining time of white and black
+    clock: Option<(Duration, Duration)>,
+}
+
+impl Default for TextRenderer {
+    fn default() -> Self {
+        TextRenderer::new()
+    }
+}
+
+impl TextRenderer {
+    // Renderer for the same text as the display of a board
+    pub fn new() -> TextRenderer {
+        TextRenderer {
+            orientation: Color::White,
+            charset: Charset::Unicode,
+            layout: Layout::Boxed,
+            colors: false,
+            coordinates: true,
+            highlights: Vec::new(),
+            backgrounds: Vec::new(),
+            panel: false,
+            clock: None,
+        }
+    }
+
+    // Color of the player whose side of the board is at the bottom
+    pub fn orientation(mut self, orientation: Color) -> TextRenderer {
+        self.orientation = orientation;
+        self
+    }
+
+    pub fn charset(mut self, charset: Charset) -> TextRenderer {
+        self.charset = charset;
+        self
+    }
+
+    pub fn layout(mut self, layout: Layout) -> TextRenderer {
+        self.layout = layout;
+        self
+    }
+
+    // Light and dark squares with ANSI background colors
+    pub fn colors(mut self, colors: bool) -> TextRenderer {
+        self.colors = colors;
+        self
+    }
+
+    // Ranks on the left and files at the bottom
+    pub fn coordinates(mut self, coordinates: bool) -> TextRenderer {
+        self.coordinates = coordinates;
+        self
+    }
+
+    // Square shown with a highlight color, or between brackets without colors
+    pub fn highlight(mut self, square: Square) -> TextRenderer {
+        self.highlights.push(square);
+        self
+    }
+
+    // Square shown with one of the 256 ANSI background colors, for example the selected square of an interface
+    // Only used with colors, and a later color for the same square replaces the earlier one
+    pub fn background(mut self, square: Square, color: u8) -> TextRenderer {
+        self.backgrounds.push((square, color));
+        self
+    }
+
+    // Side to move, castling rights and clocks next to the board
+    pub fn panel(mut self, panel: bool) -> TextRenderer {
+        self.panel = panel;
+        self
+    }
+
+    // Remaining time of both players in the panel
+    pub fn clock(mut self, clock: &Clock) -> TextRenderer {
+        self.clock = Some((clock.remaining(&Color::White), clock.remaining(&Color::Black)));
+        self
+    }
+
+    pub fn render(&self, board: &Board) -> String {
+        let mut lines = match self.layout {
+            Layout::Boxed => self.boxed(board),
+            Layout::Compact => self.compact(board),
+        };
+
+        if self.panel {
+            for (index, info) in self.panel_lines(board).into_iter().enumerate() {
+                match lines.get_mut(index) {
+                    Some(line) => {
+                        line.push_str("   ");
+                        line.push_str(&info);
+                    }
+                    None => lines.push(info),
+                }
+            }
+        }
+
+        lines.into_iter().map(|line| line + "\n").collect()
+    }
+
+    fn boxed(&self, board: &Board) -> Vec<String> {
+        let (top, separator, bottom, vertical, thin_vertical) = match self.charset {
+            Charset::Unicode => (["┏", "━━━", "┯", "┓"], ["┠", "───", "┼", "┨"], ["┗", "━━━", "┷", "┛"], "┃", "│"),
+            Charset::Ascii => (["+", "---", "+", "+"], ["+", "---", "+", "+"], ["+", "---", "+", "+"], "|", "|"),
+        };
+        let border = |[left, line, middle, right]: [&str; 4]| {
+            format!("{}{}{}{}", self.margin(None), left, [line; 8].join(middle), right)
+        };
+
+        let mut lines = vec![border(top)];
+        for (index, rank) in self.ranks().into_iter().enumerate() {
+            let cells: Vec<String> = self
+                .files()
+                .into_iter()
+                .map(|file| {
+                    let square = Square::new(file, rank);
+                    let symbol = self.symbol(board.piece_placement().get(&square), " ");
+                    if self.colors {
+                        format!("{} {} {}", self.ansi_background(&square), symbol, ANSI_RESET)
+                    } else if self.highlights.contains(&square) {
+                        format!("[{}]", symbol)
+                    } else {
+                        format!(" {} ", symbol)
+                    }
+                })
+                .collect();
+            lines.push(format!("{}{}{}{}", self.margin(Some(rank)), vertical, cells.join(thin_vertical), vertical));
+
+            if index < 7 {
+                lines.push(border(separator));
+            }
+        }
+        lines.push(border(bottom));
+
+        if self.coordinates {
+            let files: Vec<String> = self
+                .files()
+                .into_iter()
+                .map(|file| file_letter(file).to_string())
+                .collect();
+            lines.push(format!("    {}", files.join("   ").to_uppercase()));
+        }
+
+        lines
+    }
+
+    fn compact(&self, board: &Board) -> Vec<String> {
+        let empty = match self.charset {
+            Charset::Unicode => "·",
+            Charset::Ascii => ".",
+        };
+
+        let mut lines = Vec::new();
+        for rank in self.ranks() {
+            let mut line = if self.colors {
+                self.margin(Some(rank))
+            } else {
+                // Every square is preceded by a separator, which is the space after the rank for the first square
+                self.margin(Some(rank)).trim_end().to_string()
+            };
+            let mut is_previous_highlighted = false;
+
+            for file in self.files() {
+                let square = Square::new(file, rank);
+                let symbol = self.symbol(board.piece_placement().get(&square), empty);
+
+                if self.colors {
+                    // Squares are three characters wide, so they are not too narrow next to each other
+                    line.push_str(&format!("{} {} {}", self.ansi_background(&square), symbol, ANSI_RESET));
+                } else {
+                    // Brackets around highlighted squares take the place of the spaces between squares
+                    let is_highlighted = self.highlights.contains(&square);
+                    let separator = match (is_highlighted, is_previous_highlighted) {
+                        (true, _) => '[',
+                        (false, true) => ']',
+                        (false, false) => ' ',
+                    };
+                    line.push(separator);
+                    line.push_str(&symbol);
+                    is_previous_highlighted = is_highlighted;
+                }
+            }
+            if is_previous_highlighted {
+                line.push(']');
+            }
+            lines.push(line);
+        }
+
+        if self.coordinates {
+            let files: Vec<String> = self
+                .files()
+                .into_iter()
+                .map(|file| file_letter(file).to_string())
+                .collect();
+            let files = if self.colors {
+                format!(" {} ", files.join("  "))
+            } else {
+                files.join(" ")
+            };
+            lines.push(format!("{}{}", self.margin(None), files));
+        }
+
+        lines
+    }
+
+    fn panel_lines(&self, board: &Board) -> Vec<String> {
+        let side_to_move = match board.active_color() {
+            Color::White => "White to move",
+            Color::Black => "Black to move",
+        };
+        // Castling rights are the third field of FEN
+        let record = board.to_forsyth_edwards_notation();
+        let castling = record.split(' ').nth(2).unwrap_or("-");
+
+        let mut lines = vec![side_to_move.to_string(), format!("Castling: {}", castling)];
+        if let Some((white, black)) = &self.clock {
+            lines.push(format!("White: {}", clock_time(white)));
+            lines.push(format!("Black: {}", clock_time(black)));
+        }
+
+        lines
+    }
+
+    // Ranks from the top to the bottom of the rendered board
+    fn ranks(&self) -> Vec<i8> {
+        match self.orientation {
+            Color::White => (1..=8).rev().collect(),
+            Color::Black => (1..=8).collect(),
+        }
+    }
+
+    // Files from the left to the right of the rendered board
+    fn files(&self) -> Vec<i8> {
+        match self.orientation {
+            Color::White => (1..=8).collect(),
+            Color::Black => (1..=8).rev().collect(),
+        }
+    }
+
+    // Rank at the start of a line, or the space it would take
+    fn margin(&self, rank: Option<i8>) -> String {
+        match (self.coordinates, rank) {
+            (false, _) => String::new(),
+            (true, Some(rank)) => format!("{} ", rank),
+            (true, None) => String::from("  "),
+        }
+    }
+
+    fn symbol(&self, piece: Option<&Piece>, empty: &str) -> String {
+        match (piece, &self.charset) {
+            (Some(piece), Charset::Unicode) => piece.to_string(),
+            (Some(piece), Charset::Ascii) => piece_letter(piece).to_string(),
+            (None, _) => empty.to_string(),
+        }
+    }
+
+    fn ansi_background(&self, square: &Square) -> String {
+        let background = self
+            .backgrounds
+            .iter()
+            .rev()
+            .find(|(background_square, _)| background_square == square)
+            .map(|(_, color)| *color);
+
+        let color = if let Some(color) = background {
+            color
+        } else if self.highlights.contains(square) {
+            HIGHLIGHT
+        } else if (square.file() + square.rank()) % 2 == 1 {
+            LIGHT_SQUARE
+        } else {
+            DARK_SQUARE
+        };
+
+        format!("\x1b[30;48;5;{}m", color)
+    }
+}
+
+// Letter of the piece in FEN, which is uppercase for white
+fn piece_letter(piece: &Piece) -> char {
+    let letter = match piece.kind() {
+        Kind::Bishop => 'b',
+        Kind::King => 'k',
+        Kind::Knight => 'n',
+        Kind::Pawn => 'p',
+        Kind::Queen => 'q',
+        Kind::Rook => 'r',
+    };
+
+    match piece.color() {
+        Color::White => letter.to_ascii_uppercase(),
+        Color::Black => letter,
+    }
+}
+
+fn file_letter(file: i8) -> char {
+    (b'a' + file as u8 - 1) as char
+}
+
+fn clock_time(duration: &Duration) -> String {
+    let seconds = duration.as_secs();
+    format!("{}:{:02}", seconds / 60, seconds % 60)
+}
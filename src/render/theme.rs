@@ -0,0 +1,28 @@
+// Colors of the squares of a board rendered as SVG
+#[derive(Debug, PartialEq, Clone)]
+pub enum Theme {
+    Brown,
+    Blue,
+    Green,
+    Grey,
+}
+
+impl Theme {
+    pub fn light_square(&self) -> &'static str {
+        match self {
+            Theme::Brown => "#f0d9b5",
+            Theme::Blue => "#dee3e6",
+            Theme::Green => "#ffffdd",
+            Theme::Grey => "#e0e0e0",
+        }
+    }
+
+    pub fn dark_square(&self) -> &'static str {
+        match self {
+            Theme::Brown => "#b58863",
+            Theme::Blue => "#8ca2ad",
+            Theme::Green => "#86a666",
+            Theme::Grey => "#a0a0a0",
+        }
+    }
+}
@@ -0,0 +1,20 @@
+// Absolute imports within crate
+use crate::board::ChessMove;
+
+// Relative imports of sub modules
+pub use evaluation::evaluate;
+pub use score::Score;
+pub use search_result::SearchResult;
+pub use searcher::Searcher;
+mod evaluation;
+mod score;
+mod search_result;
+mod searcher;
+
+// Whether both moves move the same piece between the same squares in the same way
+// NOTE: the status of a move is ignored, since generated moves do not know whether they give check
+pub fn is_same_move(a: &ChessMove, b: &ChessMove) -> bool {
+    a.origin_square() == b.origin_square()
+        && a.destination_square() == b.destination_square()
+        && a.action() == b.action()
+}
@@ -0,0 +1,76 @@
+// Absolute imports within crate
+use crate::board::{Board, Square};
+use crate::pawns::PawnCache;
+use crate::piece::{Color, Kind};
+
+// Bonuses and penalties in centipawns
+const CENTRALIZATION: i32 = 4;
+const PAWN_ADVANCE: i32 = 4;
+const PASSED_PAWN_ADVANCE: i32 = 10;
+const ISOLATED_PAWN: i32 = -12;
+const DOUBLED_PAWN: i32 = -10;
+const BACKWARD_PAWN: i32 = -8;
+
+// Value of a piece in centipawns
+pub(super) fn piece_value(kind: &Kind) -> i32 {
+    match kind {
+        Kind::King => 0,
+        kind => (kind.relative_value() * 100.0).round() as i32,
+    }
+}
+
+// Static evaluation in centipawns from the point of view of the player to move
+// Counts material, centralization of the minor pieces and the pawn structure
+pub fn evaluate(board: &Board, pawn_cache: &mut PawnCache) -> i32 {
+    let mut score = 0;
+
+    for (square, piece) in board.piece_placement().iter() {
+        let value = piece_value(piece.kind())
+            + match piece.kind() {
+                Kind::Knight | Kind::Bishop => CENTRALIZATION * centralization(square),
+                Kind::Pawn => PAWN_ADVANCE * ranks_advanced(square, piece.color()),
+                _ => 0,
+            };
+
+        score += sign(piece.color()) * value;
+    }
+
+    for pawn in pawn_cache.get(board).pawns() {
+        let mut value = 0;
+        if pawn.is_passed() {
+            value += PASSED_PAWN_ADVANCE * ranks_advanced(pawn.square(), pawn.color());
+        }
+        if pawn.is_isolated() {
+            value += ISOLATED_PAWN;
+        }
+        if pawn.is_doubled() {
+            value += DOUBLED_PAWN;
+        }
+        if pawn.is_backward() {
+            value += BACKWARD_PAWN;
+        }
+
+        score += sign(pawn.color()) * value;
+    }
+
+    sign(board.active_color()) * score
+}
+
+fn sign(color: &Color) -> i32 {
+    match color {
+        Color::White => 1,
+        Color::Black => -1,
+    }
+}
+
+// Number of squares away from the edge in both directions, from 0 in the corner to 6 in the center
+fn centralization(square: &Square) -> i32 {
+    let file = square.file().min(9 - square.file()) - 1;
+    let rank = square.rank().min(9 - square.rank()) - 1;
+
+    (file + rank) as i32
+}
+
+fn ranks_advanced(square: &Square, color: &Color) -> i32 {
+    (square.rank() - color.get_second_rank()).abs() as i32
+}
@@ -0,0 +1,78 @@
+// External imports
+use std::fmt;
+use std::ops::Neg;
+
+// Value of a checkmate, minus the number of plies until the mate
+pub(super) const MATE: i32 = 100_000;
+
+// Centipawns used for a forced mate when a score has to be a number
+const MATE_CENTIPAWNS: i32 = 10_000;
+
+// Evaluation of a position from the point of view of the player to move
+#[derive(Debug, PartialEq, Clone)]
+pub enum Score {
+    Centipawns(i32),
+    // Number of moves until checkmate, negative when the player to move gets checkmated
+    Mate(i32),
+}
+
+impl Score {
+    // Score from the value used in the search, where a mate is close to the mate value
+    pub(super) fn from_value(value: i32) -> Score {
+        let plies = MATE - value.abs();
+
+        if plies <= 1_000 {
+            let moves = (plies + 1) / 2;
+            Score::Mate(if value > 0 { moves } else { -moves })
+        } else {
+            Score::Centipawns(value)
+        }
+    }
+
+    // Score of the position before the last move from the point of view of the player who made it, given the score
+    // of the position after the move
+    pub fn from_previous_ply(&self) -> Score {
+        match self {
+            // The move itself counts towards the mate
+            Score::Mate(moves) if *moves <= 0 => Score::Mate(1 - moves),
+            score => -score.clone(),
+        }
+    }
+
+    // Centipawns, with a large value for a forced mate
+    pub fn centipawns(&self) -> i32 {
+        match self {
+            Score::Centipawns(centipawns) => *centipawns,
+            Score::Mate(moves) if *moves > 0 => MATE_CENTIPAWNS,
+            Score::Mate(_) => -MATE_CENTIPAWNS,
+        }
+    }
+
+    // Chance to win the game from 0 to 100, based on the centipawns
+    // Source: https://lichess.org/page/accuracy
+    pub fn win_percent(&self) -> f32 {
+        let centipawns = self.centipawns() as f32;
+        50.0 + 50.0 * (2.0 / (1.0 + (-0.00368208 * centipawns).exp()) - 1.0)
+    }
+}
+
+impl Neg for Score {
+    type Output = Score;
+
+    fn neg(self) -> Score {
+        match self {
+            Score::Centipawns(centipawns) => Score::Centipawns(-centipawns),
+            Score::Mate(moves) => Score::Mate(-moves),
+        }
+    }
+}
+
+// Written in pawns like 0.35 or as a mate like #3 and #-2
+impl fmt::Display for Score {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Score::Centipawns(centipawns) => write!(f, "{:.2}", *centipawns as f32 / 100.0),
+            Score::Mate(moves) => write!(f, "#{}", moves),
+        }
+    }
+}
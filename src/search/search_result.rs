@@ -0,0 +1,47 @@
+// Absolute imports within crate
+use crate::board::ChessMove;
+// Imports from parent
+use super::Score;
+
+// Best line found by the search with its score from the point of view of the player to move
+#[derive(Debug, Clone)]
+pub struct SearchResult {
+    score: Score,
+    principal_variation: Vec<ChessMove>,
+    depth: u8,
+    nodes: u64,
+}
+
+impl SearchResult {
+    pub fn new(score: Score, principal_variation: Vec<ChessMove>, depth: u8, nodes: u64) -> SearchResult {
+        SearchResult {
+            score,
+            principal_variation,
+            depth,
+            nodes,
+        }
+    }
+
+    pub fn score(&self) -> &Score {
+        &self.score
+    }
+
+    // Moves the search expects both players to make, starting with the best move
+    pub fn principal_variation(&self) -> &[ChessMove] {
+        &self.principal_variation
+    }
+
+    // Not set when the game is already over
+    pub fn best_move(&self) -> Option<&ChessMove> {
+        self.principal_variation.first()
+    }
+
+    pub fn depth(&self) -> u8 {
+        self.depth
+    }
+
+    // Number of positions visited, including the quiescence search
+    pub fn nodes(&self) -> u64 {
+        self.nodes
+    }
+}
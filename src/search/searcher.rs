@@ -0,0 +1,209 @@
+// Absolute imports within crate
+use crate::board::{Action, Board, ChessMove};
+use crate::pawns::PawnCache;
+use crate::piece::Kind;
+// Imports from parent
+use super::evaluation::{evaluate, piece_value};
+use super::score::MATE;
+use super::{is_same_move, Score, SearchResult};
+
+// Number of pawn structures kept between searches
+const PAWN_CACHE_CAPACITY: usize = 4096;
+
+// Bound of the search window, above any score including mates
+const INFINITY: i32 = MATE + 1;
+
+// Alpha-beta search with iterative deepening and a quiescence search of the captures at the leaves
+// NOTE: repetitions are not detected, since a board does not know the positions before it
+pub struct Searcher {
+    pawn_cache: PawnCache,
+    nodes: u64,
+}
+
+impl Default for Searcher {
+    fn default() -> Self {
+        Searcher::new()
+    }
+}
+
+impl Searcher {
+    pub fn new() -> Searcher {
+        Searcher {
+            pawn_cache: PawnCache::new(PAWN_CACHE_CAPACITY),
+            nodes: 0,
+        }
+    }
+
+    // Search the position to the given depth in plies, where each iteration starts with the best line of the previous
+    pub fn search(&mut self, board: &Board, depth: u8) -> SearchResult {
+        self.nodes = 0;
+        let mut value = 0;
+        let mut principal_variation = Vec::new();
+
+        // Depth zero only searches the captures
+        for iteration in 0..=depth {
+            (value, principal_variation) = self.negamax(board, iteration, 0, -INFINITY, INFINITY, &principal_variation);
+
+            // Nothing is left to search once a forced mate has been found, but the captures alone do not give a line
+            if iteration > 0 && matches!(Score::from_value(value), Score::Mate(_)) {
+                break;
+            }
+        }
+
+        SearchResult::new(Score::from_value(value), principal_variation, depth, self.nodes)
+    }
+
+    // Score of every legal move to the given depth, sorted from best to worst for the player to move
+    // Each move is searched with a full window, so the scores of the other moves are exact as well
+    pub fn search_moves(&mut self, board: &Board, depth: u8) -> Vec<SearchResult> {
+        let mut results: Vec<SearchResult> = board
+            .legal_moves()
+            .into_iter()
+            .map(|chess_move| {
+                let mut after = board.clone();
+                after
+                    .make_move(chess_move.clone())
+                    .expect("legal moves can be made");
+
+                let reply = self.search(&after, depth.saturating_sub(1));
+                let principal_variation = [vec![chess_move], reply.principal_variation().to_vec()].concat();
+
+                SearchResult::new(reply.score().from_previous_ply(), principal_variation, depth, reply.nodes())
+            })
+            .collect();
+
+        results.sort_by_key(|result| -rank(result.score()));
+        results
+    }
+
+    // Number of positions visited by the last search
+    pub fn nodes(&self) -> u64 {
+        self.nodes
+    }
+
+    // Value of the position for the player to move with the best line, where a mate is worth less the later it is
+    fn negamax(
+        &mut self, board: &Board, depth: u8, ply: i32, mut alpha: i32, beta: i32, previous: &[ChessMove],
+    ) -> (i32, Vec<ChessMove>) {
+        self.nodes += 1;
+        let mut principal_variation = Vec::new();
+
+        let moves = board.legal_moves();
+        if moves.is_empty() {
+            let value = if board.is_in_check(board.active_color()) {
+                -(MATE - ply)
+            } else {
+                0
+            };
+            return (value, principal_variation);
+        }
+        if board.is_insufficient_material() || board.halfmove_clock() >= 100 {
+            return (0, principal_variation);
+        }
+        if depth == 0 {
+            return (self.quiescence(board, ply, alpha, beta), principal_variation);
+        }
+
+        for chess_move in ordered(board, moves, previous.first()) {
+            let mut after = board.clone();
+            after
+                .make_move(chess_move.clone())
+                .expect("legal moves can be made");
+
+            // Only the moves of the best line of the previous iteration are searched first again
+            let continuation = match previous.first() {
+                Some(first) if is_same_move(first, &chess_move) => &previous[1..],
+                _ => &[],
+            };
+            let (value, line) = self.negamax(&after, depth - 1, ply + 1, -beta, -alpha, continuation);
+            let value = -value;
+
+            if value > alpha {
+                alpha = value;
+                principal_variation = [vec![chess_move], line].concat();
+
+                if alpha >= beta {
+                    break;
+                }
+            }
+        }
+
+        (alpha, principal_variation)
+    }
+
+    // Only search captures that do not lose material until the position is quiet
+    // A player in check cannot choose to stop, so all moves that get out of check are searched
+    fn quiescence(&mut self, board: &Board, ply: i32, mut alpha: i32, beta: i32) -> i32 {
+        self.nodes += 1;
+
+        let is_in_check = board.is_in_check(board.active_color());
+        let moves = board.legal_moves();
+        if is_in_check && moves.is_empty() {
+            return -(MATE - ply);
+        }
+
+        if !is_in_check {
+            let stand_pat = evaluate(board, &mut self.pawn_cache);
+            if stand_pat >= beta {
+                return stand_pat;
+            }
+            alpha = alpha.max(stand_pat);
+        }
+
+        let moves: Vec<ChessMove> = moves
+            .into_iter()
+            .filter(|chess_move| is_in_check || (is_tactical(chess_move) && board.see_ge(chess_move, 0.0)))
+            .collect();
+
+        for chess_move in ordered(board, moves, None) {
+            let mut after = board.clone();
+            after
+                .make_move(chess_move.clone())
+                .expect("legal moves can be made");
+
+            let value = -self.quiescence(&after, ply + 1, -beta, -alpha);
+            if value >= beta {
+                return value;
+            }
+            alpha = alpha.max(value);
+        }
+
+        alpha
+    }
+}
+
+// Best move of the previous iteration first, followed by captures and promotions with the best exchange
+fn ordered(board: &Board, moves: Vec<ChessMove>, first: Option<&ChessMove>) -> Vec<ChessMove> {
+    let mut moves: Vec<(i32, ChessMove)> = moves
+        .into_iter()
+        .map(|chess_move| {
+            let priority = if first.is_some_and(|first| is_same_move(first, &chess_move)) {
+                INFINITY
+            } else if is_tactical(&chess_move) {
+                (board.see(&chess_move) * 100.0) as i32 + piece_value(&Kind::Queen)
+            } else {
+                0
+            };
+            (priority, chess_move)
+        })
+        .collect();
+    moves.sort_by_key(|(priority, _)| -priority);
+
+    moves
+        .into_iter()
+        .map(|(_, chess_move)| chess_move)
+        .collect()
+}
+
+fn is_tactical(chess_move: &ChessMove) -> bool {
+    chess_move.is_capture() || matches!(chess_move.action(), Action::MovePromotion(_))
+}
+
+// Order of the scores, where a faster mate is better and a slower mate against is better
+fn rank(score: &Score) -> i32 {
+    match score {
+        Score::Centipawns(centipawns) => *centipawns,
+        Score::Mate(moves) if *moves > 0 => MATE - moves,
+        Score::Mate(moves) => -MATE - moves,
+    }
+}
@@ -0,0 +1,294 @@
+// External imports
+use std::collections::HashMap;
+// Absolute imports within crate
+use crate::board::{Action, Board, BoardError, ChessMove, Square, SquareList};
+use crate::piece::{Color, Kind, Piece};
+
+// Relative imports of sub modules
+pub use motif::Motif;
+pub use tactic::Tactic;
+mod motif;
+mod tactic;
+
+// All motifs the given color can use against the opponent in the position
+pub fn find(board: &Board, color: &Color) -> Vec<Tactic> {
+    let opponent = color.opposite();
+    let mut tactics = Vec::new();
+
+    tactics.append(&mut forks(board, color));
+    tactics.append(&mut skewers(board, color));
+
+    for pin in board.pins(&opponent) {
+        let squares = vec![
+            pin.pinner().copy(),
+            pin.pinned().copy(),
+            pin.target().copy(),
+        ];
+        tactics.push(Tactic::new(Motif::Pin, squares));
+    }
+
+    if board.active_color() == &opponent {
+        let checkers = board.checkers();
+        if checkers.len() >= 2 {
+            let king = king_square(board, &opponent).expect("king is in check");
+            let squares = [vec![king], sorted(checkers)].concat();
+            tactics.push(Tactic::new(Motif::DoubleCheck, squares));
+        }
+    }
+
+    let hanging_pieces = board.hanging_pieces(&opponent);
+    for square in sorted(hanging_pieces.clone()) {
+        tactics.push(Tactic::new(Motif::HangingPiece, vec![square]));
+    }
+    for square in pieces(board, &opponent) {
+        let is_under_defended =
+            board.attacker_count(&square, color) > board.defender_count(&square) && !hanging_pieces.contains(&square);
+        if is_under_defended && !is_king(board, &square) {
+            tactics.push(Tactic::new(Motif::UnderDefendedPiece, vec![square]));
+        }
+    }
+
+    if let Some(king) = back_rank_weakness(board, color) {
+        tactics.push(Tactic::new(Motif::BackRankWeakness, vec![king]));
+    }
+
+    tactics.append(&mut overloaded_defenders(board, color));
+    tactics
+}
+
+// Motifs created by the move, including discovered attacks and checks by pieces behind the moved piece
+pub fn find_after_move(board: &Board, chess_move: &ChessMove) -> Result<Vec<Tactic>, BoardError> {
+    let color = chess_move.piece().color();
+    let mut after = board.clone();
+    after.make_move(chess_move.clone())?;
+
+    let before = find(board, color);
+    let mut tactics: Vec<Tactic> = find(&after, color)
+        .into_iter()
+        .filter(|tactic| !before.contains(tactic))
+        .collect();
+
+    // Sliding pieces that attack more squares now the moved piece is out of the way
+    // NOTE: the rook of a castling move is moved itself, so it does not uncover an attack
+    let is_castling = matches!(chess_move.action(), Action::ShortCastle | Action::LongCastle);
+    for attacker in pieces(&after, color) {
+        if is_castling || &attacker == chess_move.destination_square() || !is_slider(&after, &attacker) {
+            continue;
+        }
+
+        let attacked_before = board.attacked_squares(&attacker);
+        for target in sorted(after.attacked_squares(&attacker)) {
+            let target_piece = match after.piece_placement().get(&target) {
+                Some(piece) if piece.color() != color && !attacked_before.contains(&target) => piece,
+                _ => {
+                    continue;
+                }
+            };
+
+            if target_piece.kind() == &Kind::King {
+                tactics.push(Tactic::new(Motif::DiscoveredCheck, vec![attacker.copy(), target]));
+            } else if value(target_piece) > value(&after.piece_placement()[&attacker])
+                || after.defender_count(&target) == 0
+            {
+                tactics.push(Tactic::new(Motif::DiscoveredAttack, vec![attacker.copy(), target]));
+            }
+        }
+    }
+
+    Ok(tactics)
+}
+
+// Pieces attacking multiple pieces that are more valuable, undefended or the king
+fn forks(board: &Board, color: &Color) -> Vec<Tactic> {
+    let hanging_pieces = board.hanging_pieces(color);
+    let mut forks = Vec::new();
+
+    for attacker in pieces(board, color) {
+        // A fork with a piece that can be captured for free does not win anything
+        if hanging_pieces.contains(&attacker) {
+            continue;
+        }
+
+        let attacker_value = value(&board.piece_placement()[&attacker]);
+        let targets: SquareList = sorted(board.attacked_squares(&attacker))
+            .into_iter()
+            .filter(|target| match board.piece_placement().get(target) {
+                Some(piece) if piece.color() != color => {
+                    piece.kind() == &Kind::King || value(piece) > attacker_value || board.defender_count(target) == 0
+                }
+                _ => false,
+            })
+            .collect();
+
+        if targets.len() >= 2 {
+            let has_kind = |kind: Kind| {
+                targets
+                    .iter()
+                    .any(|target| board.piece_placement()[target].kind() == &kind)
+            };
+            let motif = if has_kind(Kind::King) && has_kind(Kind::Queen) {
+                Motif::RoyalFork
+            } else {
+                Motif::Fork
+            };
+
+            forks.push(Tactic::new(motif, [vec![attacker], targets].concat()));
+        }
+    }
+
+    forks
+}
+
+// Sliding pieces attacking a piece with a less valuable piece behind it on the same line
+fn skewers(board: &Board, color: &Color) -> Vec<Tactic> {
+    let mut skewers = Vec::new();
+
+    for attacker in pieces(board, color) {
+        if !is_slider(board, &attacker) {
+            continue;
+        }
+
+        for front in sorted(board.attacked_squares(&attacker)) {
+            let front_piece = match board.piece_placement().get(&front) {
+                Some(piece) if piece.color() != color => piece,
+                _ => {
+                    continue;
+                }
+            };
+            let behind = match first_piece_behind(board, &attacker, &front) {
+                Some(behind) => behind,
+                None => {
+                    continue;
+                }
+            };
+            let behind_piece = &board.piece_placement()[&behind];
+
+            let is_skewer = behind_piece.color() != color
+                && (front_piece.kind() == &Kind::King || value(front_piece) > value(behind_piece));
+            if is_skewer {
+                skewers.push(Tactic::new(Motif::Skewer, vec![attacker.copy(), front, behind]));
+            }
+        }
+    }
+
+    skewers
+}
+
+// King of the opponent on its first rank, with all squares on the second rank in front of it taken by its own
+// pieces or attacked, while the given color has a rook or queen to give check along the rank
+fn back_rank_weakness(board: &Board, color: &Color) -> Option<Square> {
+    let opponent = color.opposite();
+    let king = king_square(board, &opponent)?;
+    let first_rank = opponent.get_first_rank();
+    let second_rank = opponent.get_second_rank();
+
+    if king.rank() != first_rank {
+        return None;
+    }
+
+    let has_heavy_piece = board
+        .piece_placement()
+        .values()
+        .any(|piece| piece.color() == color && matches!(piece.kind(), Kind::Rook | Kind::Queen));
+    let is_trapped = (king.file() - 1..=king.file() + 1)
+        .filter(|file| (1..=8).contains(file))
+        .map(|file| Square::new(file, second_rank))
+        .all(|square| {
+            board
+                .piece_placement()
+                .get(&square)
+                .is_some_and(|piece| piece.color() == &opponent)
+                || board.is_attacked(&square, color)
+        });
+
+    (has_heavy_piece && is_trapped).then_some(king)
+}
+
+// Pieces of the opponent that are the only defender of two or more attacked pieces
+fn overloaded_defenders(board: &Board, color: &Color) -> Vec<Tactic> {
+    let opponent = color.opposite();
+    let mut defended: HashMap<Square, SquareList> = HashMap::new();
+
+    for square in pieces(board, &opponent) {
+        if is_king(board, &square) || !board.is_attacked(&square, color) {
+            continue;
+        }
+
+        let defenders = board.attackers_of(&square, &opponent);
+        if let [defender] = defenders.as_slice() {
+            defended.entry(defender.copy()).or_default().push(square);
+        }
+    }
+
+    let mut overloaded_defenders: Vec<Tactic> = defended
+        .into_iter()
+        .filter(|(_, squares)| squares.len() >= 2)
+        .map(|(defender, squares)| Tactic::new(Motif::OverloadedDefender, [vec![defender], squares].concat()))
+        .collect();
+    overloaded_defenders.sort_by_key(|tactic| (tactic.squares()[0].file(), tactic.squares()[0].rank()));
+
+    overloaded_defenders
+}
+
+// First occupied square on the line from the attacker through the given square, beyond that square
+fn first_piece_behind(board: &Board, attacker: &Square, square: &Square) -> Option<Square> {
+    let file_step = (square.file() - attacker.file()).signum();
+    let rank_step = (square.rank() - attacker.rank()).signum();
+    let (mut file, mut rank) = (square.file() + file_step, square.rank() + rank_step);
+
+    while (1..=8).contains(&file) && (1..=8).contains(&rank) {
+        let behind = Square::new(file, rank);
+        if board.piece_placement().contains_key(&behind) {
+            return Some(behind);
+        }
+        file += file_step;
+        rank += rank_step;
+    }
+
+    None
+}
+
+// Squares of the pieces of the given color, sorted so results do not depend on the order of the piece placement
+fn pieces(board: &Board, color: &Color) -> SquareList {
+    sorted(
+        board
+            .piece_placement()
+            .iter()
+            .filter(|(_, piece)| piece.color() == color)
+            .map(|(square, _)| square.copy())
+            .collect(),
+    )
+}
+
+fn sorted(mut squares: SquareList) -> SquareList {
+    squares.sort_by_key(|square| (square.file(), square.rank()));
+    squares
+}
+
+fn king_square(board: &Board, color: &Color) -> Option<Square> {
+    let king = Piece::new(color.clone(), Kind::King);
+
+    board
+        .piece_placement()
+        .iter()
+        .find(|(_, piece)| **piece == king)
+        .map(|(square, _)| square.copy())
+}
+
+fn is_king(board: &Board, square: &Square) -> bool {
+    board
+        .piece_placement()
+        .get(square)
+        .is_some_and(|piece| piece.kind() == &Kind::King)
+}
+
+fn is_slider(board: &Board, square: &Square) -> bool {
+    board
+        .piece_placement()
+        .get(square)
+        .is_some_and(|piece| matches!(piece.kind(), Kind::Rook | Kind::Bishop | Kind::Queen))
+}
+
+fn value(piece: &Piece) -> f32 {
+    piece.kind().relative_value()
+}
@@ -0,0 +1,26 @@
+// Tactical pattern in a position, from the point of view of the player using it
+#[derive(Debug, PartialEq, Clone)]
+pub enum Motif {
+    // One piece attacks two or more pieces that cannot all be saved
+    Fork,
+    // Fork of the king and the queen
+    RoyalFork,
+    // Sliding piece attacks a piece that has to move away and expose a less valuable piece behind it
+    Skewer,
+    // Sliding piece attacks a piece that cannot move away without exposing a more valuable piece behind it
+    Pin,
+    // Moving a piece uncovers an attack of a sliding piece behind it
+    DiscoveredAttack,
+    // Moving a piece uncovers a check of a sliding piece behind it
+    DiscoveredCheck,
+    // The king is attacked by two pieces at once, so only a king move can escape
+    DoubleCheck,
+    // Piece that can be captured while winning material
+    HangingPiece,
+    // Piece that is attacked more often than it is defended
+    UnderDefendedPiece,
+    // King on its first rank that cannot escape to the second rank
+    BackRankWeakness,
+    // Piece that is the only defender of multiple attacked pieces
+    OverloadedDefender,
+}
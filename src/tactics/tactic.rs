@@ -0,0 +1,33 @@
+// Absolute imports within crate
+use crate::board::Square;
+// Imports from parent
+use super::Motif;
+
+// Motif with the squares of the pieces involved
+// The first square is the piece carrying out the motif, followed by the pieces it targets:
+// - Fork, RoyalFork: the forking piece and the forked pieces
+// - Skewer: the skewering piece, the piece in front and the piece behind it
+// - Pin: the pinning piece, the pinned piece and the piece behind it
+// - DiscoveredAttack, DiscoveredCheck: the uncovered sliding piece and the attacked piece
+// - DoubleCheck: the king in check and both checking pieces
+// - HangingPiece, UnderDefendedPiece, BackRankWeakness: the weak piece
+// - OverloadedDefender: the defender and the pieces it defends
+#[derive(Debug, PartialEq, Clone)]
+pub struct Tactic {
+    motif: Motif,
+    squares: Vec<Square>,
+}
+
+impl Tactic {
+    pub fn new(motif: Motif, squares: Vec<Square>) -> Tactic {
+        Tactic { motif, squares }
+    }
+
+    pub fn motif(&self) -> &Motif {
+        &self.motif
+    }
+
+    pub fn squares(&self) -> &[Square] {
+        &self.squares
+    }
+}
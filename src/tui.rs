@@ -0,0 +1,77 @@
+// External imports
+use crossterm::event::{self, Event, KeyCode, KeyEventKind, MouseButton, MouseEventKind};
+use crossterm::{cursor, execute, terminal};
+use std::io::{self, Write};
+use std::time::Duration;
+// Absolute imports within crate
+use crate::game::Game;
+
+// Relative imports of sub modules
+pub use app::App;
+pub use input::Input;
+pub use promotion_picker::PromotionPicker;
+pub use view::square_at;
+mod app;
+mod input;
+mod promotion_picker;
+mod view;
+
+// Time between redraws while waiting for input, so the clocks keep running on the screen
+const REFRESH: Duration = Duration::from_millis(200);
+
+// Run the full-screen interface until the player quits, and return the game as it was played
+pub fn run(mut app: App) -> io::Result<Game> {
+    let mut output = io::stdout();
+    terminal::enable_raw_mode()?;
+    execute!(output, terminal::EnterAlternateScreen, event::EnableMouseCapture, cursor::Hide)?;
+
+    let result = event_loop(&mut app, &mut output);
+
+    // Restore the terminal even when drawing failed
+    execute!(output, cursor::Show, event::DisableMouseCapture, terminal::LeaveAlternateScreen)?;
+    terminal::disable_raw_mode()?;
+
+    result.map(|_| app.into_game())
+}
+
+fn event_loop(app: &mut App, output: &mut impl Write) -> io::Result<()> {
+    while !app.is_quit() {
+        app.tick();
+        view::draw(app, output)?;
+
+        // The engine thinks after the screen shows the move of the player
+        if app.is_thinking() {
+            app.think();
+            continue;
+        }
+
+        if !event::poll(REFRESH)? {
+            continue;
+        }
+        if let Some(input) = input(event::read()?, app.is_flipped()) {
+            app.handle(input);
+        }
+    }
+
+    Ok(())
+}
+
+// Input for a key press or mouse click, ignoring all other events
+fn input(event: Event, is_flipped: bool) -> Option<Input> {
+    match event {
+        Event::Key(key) if key.kind != KeyEventKind::Release => match key.code {
+            KeyCode::Up => Some(Input::Up),
+            KeyCode::Down => Some(Input::Down),
+            KeyCode::Left => Some(Input::Left),
+            KeyCode::Right => Some(Input::Right),
+            KeyCode::Enter | KeyCode::Char(' ') => Some(Input::Select),
+            KeyCode::Esc => Some(Input::Cancel),
+            KeyCode::Char(character) => Some(Input::Char(character.to_ascii_lowercase())),
+            _ => None,
+        },
+        Event::Mouse(mouse) if mouse.kind == MouseEventKind::Down(MouseButton::Left) => {
+            square_at(mouse.column, mouse.row, is_flipped).map(Input::Click)
+        }
+        _ => None,
+    }
+}
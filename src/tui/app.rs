@@ -0,0 +1,289 @@
+// Absolute imports within crate
+use crate::board::{Action, Board, ChessMove, Square};
+use crate::game::Game;
+use crate::piece::{Color, Kind};
+use crate::search::{Score, Searcher};
+// Imports from parent
+use super::{Input, PromotionPicker};
+
+// State of the terminal interface, which changes with the input of the player and the moves of the engine
+pub struct App {
+    game: Game,
+    engine: Option<Color>,
+    depth: u8,
+    searcher: Searcher,
+    // The engine found no move in the current position, so it stops thinking until the position changes
+    is_engine_stuck: bool,
+    cursor: Square,
+    selected: Option<Square>,
+    promotion_picker: Option<PromotionPicker>,
+    is_flipped: bool,
+    // Evaluation from the point of view of white, with the position it belongs to
+    evaluation: Option<(Score, String)>,
+    message: Option<String>,
+    is_quit: bool,
+}
+
+impl App {
+    pub fn new(game: Game, engine: Option<Color>, depth: u8) -> App {
+        // Start with the cursor on the king pawn of the player, looking from their side of the board
+        let is_flipped = engine == Some(Color::White);
+        let cursor = if is_flipped {
+            Square::new(5, 7)
+        } else {
+            Square::new(5, 2)
+        };
+
+        App {
+            game,
+            engine,
+            // The engine needs to search at least one ply to find a move
+            depth: depth.max(1),
+            searcher: Searcher::new(),
+            is_engine_stuck: false,
+            cursor,
+            selected: None,
+            promotion_picker: None,
+            is_flipped,
+            evaluation: None,
+            message: None,
+            is_quit: false,
+        }
+    }
+
+    pub fn game(&self) -> &Game {
+        &self.game
+    }
+
+    pub fn into_game(self) -> Game {
+        self.game
+    }
+
+    pub fn board(&self) -> &Board {
+        self.game.board()
+    }
+
+    pub fn cursor(&self) -> &Square {
+        &self.cursor
+    }
+
+    pub fn selected(&self) -> Option<&Square> {
+        self.selected.as_ref()
+    }
+
+    pub fn promotion_picker(&self) -> Option<&PromotionPicker> {
+        self.promotion_picker.as_ref()
+    }
+
+    pub fn is_flipped(&self) -> bool {
+        self.is_flipped
+    }
+
+    pub fn message(&self) -> Option<&str> {
+        self.message.as_deref()
+    }
+
+    pub fn is_quit(&self) -> bool {
+        self.is_quit
+    }
+
+    // Evaluation of the current position from the point of view of white, once the engine has searched it
+    pub fn evaluation(&self) -> Option<&Score> {
+        self.evaluation
+            .as_ref()
+            .filter(|(_, position)| *position == self.board().to_forsyth_edwards_notation())
+            .map(|(score, _)| score)
+    }
+
+    // Squares the selected piece can move to
+    pub fn destinations(&self) -> Vec<Square> {
+        match &self.selected {
+            Some(selected) => self
+                .board()
+                .legal_moves()
+                .into_iter()
+                .filter(|chess_move| chess_move.origin_square() == selected)
+                .map(|chess_move| chess_move.destination_square().copy())
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    // Origin and destination square of the move that led to the current position
+    pub fn last_move(&self) -> Option<(Square, Square)> {
+        let ply = self.game.ply();
+        let played_move = self.game.moves().get(ply.checked_sub(1)?)?;
+        let chess_move = played_move.chess_move();
+
+        Some((chess_move.origin_square().copy(), chess_move.destination_square().copy()))
+    }
+
+    // Square of the king of the player to move when it is in check
+    pub fn checked_king(&self) -> Option<Square> {
+        let board = self.board();
+        if !board.is_in_check(board.active_color()) {
+            return None;
+        }
+
+        board
+            .piece_placement()
+            .iter()
+            .find(|(_, piece)| piece.color() == board.active_color() && piece.kind() == &Kind::King)
+            .map(|(square, _)| square.copy())
+    }
+
+    pub fn handle(&mut self, input: Input) {
+        self.message = None;
+
+        if let Some(picker) = &mut self.promotion_picker {
+            let chosen = match input {
+                Input::Left | Input::Up => {
+                    picker.previous();
+                    None
+                }
+                Input::Right | Input::Down => {
+                    picker.next();
+                    None
+                }
+                Input::Select => Some(picker.selected().clone()),
+                Input::Char(letter) => picker.by_letter(letter).cloned(),
+                Input::Cancel => {
+                    self.promotion_picker = None;
+                    None
+                }
+                Input::Click(_) => None,
+            };
+
+            if let Some(chess_move) = chosen {
+                self.make_move(chess_move);
+            }
+            return;
+        }
+
+        // Directions are on the screen, which is the other way around when the board is flipped
+        let direction = if self.is_flipped { -1 } else { 1 };
+        match input {
+            Input::Up => self.move_cursor(0, direction),
+            Input::Down => self.move_cursor(0, -direction),
+            Input::Left => self.move_cursor(-direction, 0),
+            Input::Right => self.move_cursor(direction, 0),
+            Input::Select => self.select(self.cursor.copy()),
+            Input::Click(square) => {
+                self.cursor = square.copy();
+                self.select(square);
+            }
+            Input::Cancel => self.selected = None,
+            Input::Char('f') => self.is_flipped = !self.is_flipped,
+            Input::Char('u') => self.undo(),
+            Input::Char('q') => self.is_quit = true,
+            Input::Char(_) => {}
+        }
+    }
+
+    // Whether the engine has to move or evaluate the current position
+    pub fn is_thinking(&self) -> bool {
+        (self.is_engine_turn() && !self.is_engine_stuck) || self.evaluation().is_none()
+    }
+
+    // Let the engine move when it is its turn, and evaluate the position for the evaluation bar
+    pub fn think(&mut self) {
+        let board = self.board().clone();
+
+        if self.is_engine_turn() && !self.is_engine_stuck {
+            let result = self.searcher.search(&board, self.depth);
+            match result.best_move() {
+                Some(best_move) => {
+                    self.make_move(best_move.clone());
+                    return;
+                }
+                None => {
+                    self.is_engine_stuck = true;
+                    self.message = Some(String::from("The engine found no move"));
+                }
+            }
+        }
+
+        if self.evaluation().is_none() {
+            let score = self.searcher.search(&board, self.depth).score().clone();
+            let score = match board.active_color() {
+                Color::White => score,
+                Color::Black => -score,
+            };
+            self.evaluation = Some((score, board.to_forsyth_edwards_notation()));
+        }
+    }
+
+    // Check the clock, which ends the game when the player to move has run out of time
+    pub fn tick(&mut self) {
+        self.game.check_time();
+    }
+
+    fn is_engine_turn(&self) -> bool {
+        self.engine.as_ref() == Some(self.board().active_color()) && !self.game.is_over()
+    }
+
+    fn move_cursor(&mut self, files: i8, ranks: i8) {
+        let file = (self.cursor.file() + files).clamp(1, 8);
+        let rank = (self.cursor.rank() + ranks).clamp(1, 8);
+        self.cursor = Square::new(file, rank);
+    }
+
+    // Select a piece of the player to move, or move the selected piece to the square
+    fn select(&mut self, square: Square) {
+        if self.game.is_over() || self.is_engine_turn() {
+            return;
+        }
+
+        if let Some(selected) = &self.selected {
+            let moves: Vec<ChessMove> = self
+                .board()
+                .legal_moves()
+                .into_iter()
+                .filter(|chess_move| {
+                    chess_move.origin_square() == selected && chess_move.destination_square() == &square
+                })
+                .collect();
+
+            let is_promotion = moves.iter().any(|chess_move| {
+                matches!(chess_move.action(), Action::MovePromotion(_) | Action::CapturePromotion(_))
+            });
+            if is_promotion {
+                self.promotion_picker = Some(PromotionPicker::new(moves));
+                return;
+            }
+            if let Some(chess_move) = moves.into_iter().next() {
+                self.make_move(chess_move);
+                return;
+            }
+        }
+
+        let is_own_piece = self
+            .board()
+            .piece_placement()
+            .get(&square)
+            .is_some_and(|piece| piece.color() == self.board().active_color());
+        self.selected = is_own_piece.then_some(square);
+    }
+
+    fn make_move(&mut self, chess_move: ChessMove) {
+        self.selected = None;
+        self.promotion_picker = None;
+        self.is_engine_stuck = false;
+
+        if let Err(error) = self.game.make_move(chess_move) {
+            self.message = Some(error.to_string());
+        }
+    }
+
+    // Take back the last move of the player, together with the moves of the engine after it
+    fn undo(&mut self) {
+        self.selected = None;
+        self.is_engine_stuck = false;
+
+        while self.game.undo() {
+            if self.engine.as_ref() != Some(self.board().active_color()) {
+                return;
+            }
+        }
+    }
+}
@@ -0,0 +1,18 @@
+// Absolute imports within crate
+use crate::board::Square;
+
+// Action of the player in the terminal interface, independent of the keys or mouse buttons used for it
+#[derive(Debug, PartialEq, Clone)]
+pub enum Input {
+    Up,
+    Down,
+    Left,
+    Right,
+    // Select the square under the cursor, or the highlighted piece of the promotion picker
+    Select,
+    // Clear the selection or close the promotion picker
+    Cancel,
+    // Select a square with the mouse
+    Click(Square),
+    Char(char),
+}
@@ -0,0 +1,60 @@
+// Absolute imports within crate
+use crate::board::{Action, ChessMove};
+use crate::piece::Kind;
+
+// Order of the pieces in the picker, starting with the piece that is almost always chosen
+const ORDER: [Kind; 4] = [Kind::Queen, Kind::Rook, Kind::Bishop, Kind::Knight];
+
+// Choice between the promotions of a pawn that has reached the last rank
+#[derive(Debug, Clone)]
+pub struct PromotionPicker {
+    moves: Vec<ChessMove>,
+    index: usize,
+}
+
+impl PromotionPicker {
+    // Picker for the promotions of a single pawn to the same square
+    pub fn new(mut moves: Vec<ChessMove>) -> PromotionPicker {
+        moves.sort_by_key(|chess_move| {
+            promotion_kind(chess_move)
+                .and_then(|kind| ORDER.iter().position(|ordered| *ordered == kind))
+                .unwrap_or(ORDER.len())
+        });
+
+        PromotionPicker { moves, index: 0 }
+    }
+
+    pub fn kinds(&self) -> Vec<Kind> {
+        self.moves.iter().filter_map(promotion_kind).collect()
+    }
+
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    pub fn selected(&self) -> &ChessMove {
+        &self.moves[self.index]
+    }
+
+    pub fn next(&mut self) {
+        self.index = (self.index + 1) % self.moves.len();
+    }
+
+    pub fn previous(&mut self) {
+        self.index = (self.index + self.moves.len() - 1) % self.moves.len();
+    }
+
+    // Promotion to the piece with the letter, like q for a queen
+    pub fn by_letter(&self, letter: char) -> Option<&ChessMove> {
+        self.moves.iter().find(|chess_move| {
+            promotion_kind(chess_move).is_some_and(|kind| kind.to_string().eq_ignore_ascii_case(&letter.to_string()))
+        })
+    }
+}
+
+fn promotion_kind(chess_move: &ChessMove) -> Option<Kind> {
+    match chess_move.action() {
+        Action::MovePromotion(kind) | Action::CapturePromotion(kind) => Some(kind.clone()),
+        _ => None,
+    }
+}
@@ -0,0 +1,201 @@
+// External imports
+use crossterm::cursor::MoveTo;
+use crossterm::queue;
+use crossterm::style::{Color as TerminalColor, Print, ResetColor, SetBackgroundColor};
+use crossterm::terminal::{Clear, ClearType};
+use std::io::{self, Write};
+use std::time::Duration;
+// Absolute imports within crate
+use crate::board::{Outcome, Square};
+use crate::piece::Color;
+use crate::render::{Layout, TextRenderer};
+// Imports from parent
+use super::App;
+
+// Position of the top left square of the board on the screen, leaving room for the evaluation bar and the ranks
+const BOARD_LEFT: u16 = 4;
+const BOARD_TOP: u16 = 1;
+const SQUARE_WIDTH: u16 = 3;
+const PANEL_LEFT: u16 = BOARD_LEFT + 8 * SQUARE_WIDTH + 3;
+// Number of full moves shown in the move list
+const MOVE_LIST_LINES: usize = 8;
+
+// Backgrounds of the squares out of the 256 colors of ANSI terminals
+const LAST_MOVE: u8 = 185;
+const SELECTED: u8 = 101;
+const DESTINATION: u8 = 144;
+const CHECK: u8 = 167;
+const CURSOR: u8 = 68;
+
+// Square under a position on the screen, such as the position of a mouse click
+pub fn square_at(column: u16, row: u16, is_flipped: bool) -> Option<Square> {
+    if column < BOARD_LEFT || row < BOARD_TOP {
+        return None;
+    }
+
+    let column = (column - BOARD_LEFT) / SQUARE_WIDTH;
+    let row = row - BOARD_TOP;
+    if column >= 8 || row >= 8 {
+        return None;
+    }
+
+    let (column, row) = (column as i8, row as i8);
+    Some(if is_flipped {
+        Square::new(8 - column, row + 1)
+    } else {
+        Square::new(column + 1, 8 - row)
+    })
+}
+
+// Draw the whole screen, which is cheap enough to do after every input
+pub fn draw(app: &App, output: &mut impl Write) -> io::Result<()> {
+    queue!(output, ResetColor, Clear(ClearType::All))?;
+
+    draw_board(app, output)?;
+    draw_evaluation_bar(app, output)?;
+    draw_panel(app, output)?;
+
+    let help = "arrows/mouse: select  enter: move  esc: cancel  f: flip  u: undo  q: quit";
+    queue!(output, MoveTo(0, BOARD_TOP + 10), Print(help))?;
+
+    output.flush()
+}
+
+fn draw_board(app: &App, output: &mut impl Write) -> io::Result<()> {
+    let orientation = if app.is_flipped() { Color::Black } else { Color::White };
+    let mut renderer = TextRenderer::new()
+        .layout(Layout::Compact)
+        .colors(true)
+        .orientation(orientation);
+
+    // Later backgrounds take precedence, so the cursor is always visible
+    if let Some((origin, destination)) = app.last_move() {
+        renderer = renderer
+            .background(origin, LAST_MOVE)
+            .background(destination, LAST_MOVE);
+    }
+    for square in app.destinations() {
+        renderer = renderer.background(square, DESTINATION);
+    }
+    if let Some(selected) = app.selected() {
+        renderer = renderer.background(selected.copy(), SELECTED);
+    }
+    if let Some(king) = app.checked_king() {
+        renderer = renderer.background(king, CHECK);
+    }
+    renderer = renderer.background(app.cursor().copy(), CURSOR);
+
+    // Every line starts with the rank in front of the squares, which are as wide as the squares of the screen
+    for (row, line) in renderer.render(app.board()).lines().enumerate() {
+        queue!(output, MoveTo(BOARD_LEFT - 2, BOARD_TOP + row as u16), Print(line))?;
+    }
+
+    Ok(())
+}
+
+// Bar next to the board that is filled from the side of white with their winning chances
+fn draw_evaluation_bar(app: &App, output: &mut impl Write) -> io::Result<()> {
+    let white_rows = app
+        .evaluation()
+        .map_or(4, |score| (score.win_percent() / 100.0 * 8.0).round() as u16);
+
+    for row in 0..8 {
+        // White is at the bottom of the board unless it is flipped
+        let is_white = if app.is_flipped() {
+            row < white_rows
+        } else {
+            8 - row <= white_rows
+        };
+        let background = if is_white {
+            TerminalColor::White
+        } else {
+            TerminalColor::DarkGrey
+        };
+        queue!(output, MoveTo(0, BOARD_TOP + row), SetBackgroundColor(background), Print("  "), ResetColor,)?;
+    }
+
+    Ok(())
+}
+
+fn draw_panel(app: &App, output: &mut impl Write) -> io::Result<()> {
+    let game = app.game();
+    let mut lines = Vec::new();
+
+    let status = if game.is_over() {
+        match game.outcome() {
+            Some(Outcome::Win(Color::White)) => String::from("White wins"),
+            Some(Outcome::Win(Color::Black)) => String::from("Black wins"),
+            Some(Outcome::Draw) => String::from("Draw"),
+            None => String::from("Game over"),
+        }
+    } else {
+        match app.board().active_color() {
+            Color::White => String::from("White to move"),
+            Color::Black => String::from("Black to move"),
+        }
+    };
+    lines.push(status);
+
+    if let Some(clock) = game.clock() {
+        lines.push(format!(
+            "White {}  Black {}",
+            clock_time(clock.remaining(&Color::White)),
+            clock_time(clock.remaining(&Color::Black))
+        ));
+    }
+
+    lines.push(match app.evaluation() {
+        Some(score) => format!("Evaluation {}", score),
+        None => String::from("Evaluation ..."),
+    });
+    lines.push(String::new());
+
+    // Move list with the most recent moves, where the moves after an undo are not shown
+    let moves: Vec<&str> = game.moves()[..game.ply()]
+        .iter()
+        .map(|played_move| played_move.standard_algebraic_notation())
+        .collect();
+    let first_number = game.initial_position().fullmove_number() as usize;
+    let is_black_first = game.initial_position().active_color() == &Color::Black;
+    let mut move_lines = Vec::new();
+    let mut index = 0;
+    while index < moves.len() {
+        let number = first_number + move_lines.len();
+        if index == 0 && is_black_first {
+            move_lines.push(format!("{:>3}. ...    {}", number, moves[0]));
+            index += 1;
+        } else {
+            let black = moves.get(index + 1).copied().unwrap_or_default();
+            move_lines.push(format!("{:>3}. {:<7} {}", number, moves[index], black));
+            index += 2;
+        }
+    }
+    let skipped = move_lines.len().saturating_sub(MOVE_LIST_LINES);
+    lines.extend(move_lines.into_iter().skip(skipped));
+
+    for (row, line) in lines.iter().enumerate() {
+        queue!(output, MoveTo(PANEL_LEFT, BOARD_TOP + row as u16), Print(line))?;
+    }
+
+    let mut row = BOARD_TOP + 9;
+    if let Some(picker) = app.promotion_picker() {
+        queue!(output, MoveTo(0, row), Print("Promote to: "))?;
+        for (index, kind) in picker.kinds().iter().enumerate() {
+            if index == picker.index() {
+                queue!(output, SetBackgroundColor(TerminalColor::AnsiValue(CURSOR)))?;
+            }
+            queue!(output, Print(format!(" {} ", kind)), ResetColor)?;
+        }
+        row += 2;
+    }
+    if let Some(message) = app.message() {
+        queue!(output, MoveTo(0, row), Print(message))?;
+    }
+
+    Ok(())
+}
+
+fn clock_time(duration: Duration) -> String {
+    let seconds = duration.as_secs();
+    format!("{}:{:02}", seconds / 60, seconds % 60)
+}
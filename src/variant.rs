@@ -0,0 +1,106 @@
+// Absolute imports within crate
+use crate::board::{Board, BoardError, ChessMove, MoveList, Outcome};
+use crate::parser::{self, ParseError};
+
+// Relative imports of sub modules
+pub use antichess::Antichess;
+pub use atomic::Atomic;
+pub use bughouse::Bughouse;
+pub use crazyhouse::Crazyhouse;
+pub use horde::Horde;
+pub use king_of_the_hill::KingOfTheHill;
+pub use racing_kings::RacingKings;
+pub use standard::Standard;
+pub use three_check::ThreeCheck;
+mod antichess;
+mod atomic;
+mod bughouse;
+mod crazyhouse;
+mod horde;
+mod king_of_the_hill;
+mod racing_kings;
+mod standard;
+mod three_check;
+
+// Rules of a chess variant on top of the regular board and movement of the pieces
+pub trait Variant {
+    // Name as used in the Variant tag of PGN
+    fn name(&self) -> &'static str;
+
+    fn starting_position(&self) -> Board {
+        Board::starting_position()
+    }
+
+    fn legal_moves(&self, board: &Board) -> MoveList;
+
+    fn make_move(&self, board: &mut Board, chess_move: ChessMove) -> Result<(), BoardError> {
+        board.make_move(chess_move)
+    }
+
+    // Returns the outcome if the game has ended according to the rules of the variant
+    fn outcome(&self, board: &Board) -> Option<Outcome>;
+
+    fn parse_forsyth_edwards_notation(&self, record: &str) -> Result<Board, ParseError> {
+        parser::parse_forsyth_edwards_notation(record)
+    }
+
+    fn to_forsyth_edwards_notation(&self, board: &Board) -> String {
+        board.to_forsyth_edwards_notation()
+    }
+
+    // Count the number of leaf nodes of the move tree up to the given depth
+    fn perft(&self, board: &Board, depth: u8) -> u64 {
+        if depth == 0 {
+            return 1;
+        }
+
+        let legal_moves = self.legal_moves(board);
+
+        // No need to make the moves on the last level
+        if depth == 1 {
+            return legal_moves.len() as u64;
+        }
+
+        legal_moves
+            .into_iter()
+            .map(|chess_move| {
+                let mut board = board.clone();
+                self.make_move(&mut board, chess_move)
+                    .expect("legal moves can always be made");
+                self.perft(&board, depth - 1)
+            })
+            .sum()
+    }
+}
+
+// Look up a variant by the name used in the Variant tag of PGN
+pub fn from_name(name: &str) -> Option<Box<dyn Variant>> {
+    let variant: Box<dyn Variant> = match name.to_lowercase().as_str() {
+        "standard" | "chess960" | "fischerandom" => Box::new(Standard),
+        "three-check" | "threecheck" => Box::new(ThreeCheck),
+        "king of the hill" | "kingofthehill" => Box::new(KingOfTheHill),
+        "antichess" | "giveaway" => Box::new(Antichess),
+        "atomic" => Box::new(Atomic),
+        "horde" => Box::new(Horde),
+        "racing kings" | "racingkings" => Box::new(RacingKings),
+        "crazyhouse" => Box::new(Crazyhouse),
+        _ => {
+            return None;
+        }
+    };
+
+    Some(variant)
+}
+
+// Checkmate or stalemate when the player to move has no legal moves left
+fn checkmate_or_stalemate(board: &Board, legal_moves: &MoveList) -> Option<Outcome> {
+    if !legal_moves.is_empty() {
+        return None;
+    }
+
+    if board.is_in_check(board.active_color()) {
+        Some(Outcome::Win(board.active_color().opposite()))
+    } else {
+        Some(Outcome::Draw)
+    }
+}
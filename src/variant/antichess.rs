@@ -0,0 +1,67 @@
+// Absolute imports within crate
+use crate::board::{Action, Board, ChessMove, MoveList, Outcome};
+use crate::piece::Kind;
+// Imports from parent
+use super::Variant;
+
+// Players try to lose all their pieces, capturing is mandatory and the king is a regular piece
+pub struct Antichess;
+
+impl Variant for Antichess {
+    fn name(&self) -> &'static str {
+        "Antichess"
+    }
+
+    fn starting_position(&self) -> Board {
+        Board::forsyth_edwards_notation("rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w - - 0 1")
+            .expect("starting position is a valid record")
+    }
+
+    fn legal_moves(&self, board: &Board) -> MoveList {
+        // There is no check, so all moves by the rules of the pieces are legal, except castling
+        let mut moves: MoveList = board
+            .pseudo_legal_moves()
+            .into_iter()
+            .filter(|chess_move| !matches!(chess_move.action(), Action::ShortCastle | Action::LongCastle))
+            .collect();
+
+        // Pawns can also promote to a king
+        let king_promotions: MoveList = moves
+            .iter()
+            .filter_map(|chess_move| {
+                let action = match chess_move.action() {
+                    Action::MovePromotion(Kind::Queen) => Action::MovePromotion(Kind::King),
+                    Action::CapturePromotion(Kind::Queen) => Action::CapturePromotion(Kind::King),
+                    _ => {
+                        return None;
+                    }
+                };
+
+                Some(ChessMove::new(
+                    chess_move.piece().clone(),
+                    chess_move.origin_square().clone(),
+                    action,
+                    chess_move.destination_square().clone(),
+                    chess_move.status().clone(),
+                ))
+            })
+            .collect();
+        moves.extend(king_promotions);
+
+        // Capturing is mandatory
+        if moves.iter().any(|chess_move| chess_move.is_capture()) {
+            moves.retain(|chess_move| chess_move.is_capture());
+        }
+
+        moves
+    }
+
+    fn outcome(&self, board: &Board) -> Option<Outcome> {
+        // Player without pieces or without moves wins
+        if self.legal_moves(board).is_empty() {
+            Some(Outcome::Win(board.active_color().clone()))
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,113 @@
+// Absolute imports within crate
+use crate::board::{Board, BoardError, ChessMove, MoveList, Outcome, Square};
+use crate::piece::{Color, Kind};
+// Imports from parent
+use super::Variant;
+
+// Captures cause an explosion that removes all pieces around the destination square, except pawns
+pub struct Atomic;
+
+impl Atomic {
+    fn king_square(&self, board: &Board, color: &Color) -> Option<Square> {
+        board
+            .piece_placement()
+            .iter()
+            .find(|(_, piece)| piece.color() == color && piece.kind() == &Kind::King)
+            .map(|(square, _)| square.clone())
+    }
+
+    // Kings next to each other cannot be in check, since capturing one would blow up the other
+    fn is_in_check(&self, board: &Board, color: &Color) -> bool {
+        match (self.king_square(board, color), self.king_square(board, &color.opposite())) {
+            (Some(king), Some(opposite_king)) => {
+                let is_adjacent =
+                    (king.file() - opposite_king.file()).abs() <= 1 && (king.rank() - opposite_king.rank()).abs() <= 1;
+
+                !is_adjacent && board.is_in_check(color)
+            }
+            _ => false,
+        }
+    }
+}
+
+impl Variant for Atomic {
+    fn name(&self) -> &'static str {
+        "Atomic"
+    }
+
+    fn legal_moves(&self, board: &Board) -> MoveList {
+        let color = board.active_color();
+        let opposite_color = color.opposite();
+
+        // No more moves after a king blew up
+        if self.king_square(board, color).is_none() || self.king_square(board, &opposite_color).is_none() {
+            return MoveList::new();
+        }
+
+        board
+            .pseudo_legal_moves()
+            .into_iter()
+            // Kings cannot capture, since they would blow up themselves
+            .filter(|chess_move| !(chess_move.piece().kind() == &Kind::King && chess_move.is_capture()))
+            .filter(|chess_move| {
+                let mut board = board.clone();
+                if self.make_move(&mut board, chess_move.clone()).is_err() {
+                    return false;
+                }
+
+                // Own king cannot blow up, but blowing up the opposite king ends the game
+                if self.king_square(&board, color).is_none() {
+                    return false;
+                }
+                if self.king_square(&board, &opposite_color).is_none() {
+                    return true;
+                }
+
+                !self.is_in_check(&board, color)
+            })
+            .collect()
+    }
+
+    fn make_move(&self, board: &mut Board, chess_move: ChessMove) -> Result<(), BoardError> {
+        let is_capture = chess_move.is_capture();
+        let destination_square = chess_move.destination_square().clone();
+
+        board.make_move(chess_move)?;
+
+        // The capturing piece and all pieces around it blow up, except for pawns
+        if is_capture {
+            board.remove_piece(&destination_square);
+
+            for square in destination_square.squares_on_king_move() {
+                let is_pawn = board
+                    .piece_placement()
+                    .get(&square)
+                    .is_some_and(|piece| piece.kind() == &Kind::Pawn);
+
+                if !is_pawn {
+                    board.remove_piece(&square);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn outcome(&self, board: &Board) -> Option<Outcome> {
+        // Player whose king blew up loses
+        for color in [Color::White, Color::Black] {
+            if self.king_square(board, &color).is_none() {
+                return Some(Outcome::Win(color.opposite()));
+            }
+        }
+
+        let color = board.active_color();
+        if !self.legal_moves(board).is_empty() {
+            None
+        } else if self.is_in_check(board, color) {
+            Some(Outcome::Win(color.opposite()))
+        } else {
+            Some(Outcome::Draw)
+        }
+    }
+}
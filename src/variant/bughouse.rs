@@ -0,0 +1,57 @@
+// Absolute imports within crate
+use crate::board::{Board, BoardError, ChessMove, MoveList, Outcome, Pocket};
+// Imports from parent
+use super::checkmate_or_stalemate;
+use super::crazyhouse::captured_piece;
+
+// Two games of Crazyhouse played side by side by two teams of two players
+// The player with white on the first board is partnered with the player with black on the second board
+// Captured pieces go to the partner, who plays with the opposite color and can drop them as they are
+#[derive(Debug, Clone)]
+pub struct Bughouse {
+    boards: [Board; 2],
+}
+
+impl Default for Bughouse {
+    fn default() -> Self {
+        Bughouse::new()
+    }
+}
+
+impl Bughouse {
+    pub fn new() -> Bughouse {
+        let mut board = Board::starting_position();
+        board.set_pocket(Some(Pocket::default()));
+
+        Bughouse {
+            boards: [board.clone(), board],
+        }
+    }
+
+    // Panics when the index is not 0 or 1
+    pub fn board(&self, index: usize) -> &Board {
+        &self.boards[index]
+    }
+
+    pub fn legal_moves(&self, index: usize) -> MoveList {
+        self.boards[index].legal_moves()
+    }
+
+    pub fn make_move(&mut self, index: usize, chess_move: ChessMove) -> Result<(), BoardError> {
+        let captured_piece = captured_piece(&self.boards[index], &chess_move);
+        self.boards[index].make_move(chess_move)?;
+
+        // The piece keeps its color, since the partner plays with the color of the captured piece
+        if let Some(piece) = captured_piece {
+            self.boards[1 - index].add_to_pocket(piece)?;
+        }
+
+        Ok(())
+    }
+
+    // Returns the outcome of the game on one of the boards, which decides the match for both teams
+    pub fn outcome(&self, index: usize) -> Option<Outcome> {
+        let board = &self.boards[index];
+        checkmate_or_stalemate(board, &board.legal_moves())
+    }
+}
@@ -0,0 +1,72 @@
+// Absolute imports within crate
+use crate::board::{Action, Board, BoardError, ChessMove, MoveList, Outcome, Pocket, Square};
+use crate::parser::ParseError;
+use crate::piece::{Kind, Piece};
+// Imports from parent
+use super::{checkmate_or_stalemate, Variant};
+
+// Regular chess, where captured pieces change sides and can be dropped back onto the board
+pub struct Crazyhouse;
+
+impl Variant for Crazyhouse {
+    fn name(&self) -> &'static str {
+        "Crazyhouse"
+    }
+
+    fn starting_position(&self) -> Board {
+        let mut board = Board::starting_position();
+        board.set_pocket(Some(Pocket::default()));
+        board
+    }
+
+    fn legal_moves(&self, board: &Board) -> MoveList {
+        board.legal_moves()
+    }
+
+    fn make_move(&self, board: &mut Board, chess_move: ChessMove) -> Result<(), BoardError> {
+        let captured_piece = captured_piece(board, &chess_move);
+        board.make_move(chess_move)?;
+
+        // The player who captured now owns the piece
+        if let Some(piece) = captured_piece {
+            board.add_to_pocket(Piece::new(piece.color().opposite(), piece.kind().clone()))?;
+        }
+
+        Ok(())
+    }
+
+    fn outcome(&self, board: &Board) -> Option<Outcome> {
+        checkmate_or_stalemate(board, &board.legal_moves())
+    }
+
+    fn parse_forsyth_edwards_notation(&self, record: &str) -> Result<Board, ParseError> {
+        let mut board = Board::forsyth_edwards_notation(record)?;
+
+        // Records without a pocket start with an empty one
+        if board.pocket().is_none() {
+            board.set_pocket(Some(Pocket::default()));
+        }
+
+        Ok(board)
+    }
+}
+
+// The piece that will be captured by the move, where promoted pieces turn back into pawns
+pub(super) fn captured_piece(board: &Board, chess_move: &ChessMove) -> Option<Piece> {
+    let destination_square = chess_move.destination_square();
+    let square = match chess_move.action() {
+        Action::Capture | Action::CapturePromotion(_) => destination_square.clone(),
+        // The captured pawn is next to the origin square
+        Action::EnPassant => Square::new(destination_square.file(), chess_move.origin_square().rank()),
+        _ => {
+            return None;
+        }
+    };
+
+    let piece = board.piece_placement().get(&square)?;
+    if board.is_promoted(&square) {
+        Some(Piece::new(piece.color().clone(), Kind::Pawn))
+    } else {
+        Some(piece.clone())
+    }
+}
@@ -0,0 +1,76 @@
+// Absolute imports within crate
+use crate::board::{Action, Board, BoardError, ChessMove, MoveList, MoveStatus, Outcome, Square};
+use crate::piece::{Color, Kind};
+// Imports from parent
+use super::{checkmate_or_stalemate, Variant};
+
+// White has 36 pawns and no king, black wins by capturing all of them
+pub struct Horde;
+
+impl Variant for Horde {
+    fn name(&self) -> &'static str {
+        "Horde"
+    }
+
+    fn starting_position(&self) -> Board {
+        // Since FEN break the spell checker, turn it of for the next line - cspell:disable-next
+        Board::forsyth_edwards_notation("rnbqkbnr/pppppppp/8/1PP2PP1/PPPPPPPP/PPPPPPPP/PPPPPPPP/PPPPPPPP w kq - 0 1")
+            .expect("starting position is a valid record")
+    }
+
+    fn legal_moves(&self, board: &Board) -> MoveList {
+        let mut moves = board.legal_moves();
+
+        // Pawns on the first rank can also move two squares forward
+        if board.active_color() == &Color::White {
+            for (square, piece) in board.piece_placement() {
+                if piece.color() != &Color::White || piece.kind() != &Kind::Pawn || square.rank() != 1 {
+                    continue;
+                }
+
+                let squares = square.squares_on_up_vertical();
+                if squares
+                    .iter()
+                    .take(2)
+                    .all(|square| !board.piece_placement().contains_key(square))
+                {
+                    let destination_square = Square::new(square.file(), 3);
+                    moves.push(ChessMove::new(
+                        piece.clone(),
+                        square.clone(),
+                        Action::Move,
+                        destination_square,
+                        MoveStatus::None,
+                    ));
+                }
+            }
+        }
+
+        moves
+    }
+
+    fn make_move(&self, board: &mut Board, chess_move: ChessMove) -> Result<(), BoardError> {
+        let is_from_first_rank = chess_move.piece().kind() == &Kind::Pawn && chess_move.origin_square().rank() == 1;
+        board.make_move(chess_move)?;
+
+        // Pawns that move two squares forward from the first rank cannot be captured en passant
+        if is_from_first_rank {
+            board.clear_en_passant_target();
+        }
+
+        Ok(())
+    }
+
+    fn outcome(&self, board: &Board) -> Option<Outcome> {
+        // Black wins by capturing all white pieces
+        let has_white_pieces = board
+            .piece_placement()
+            .values()
+            .any(|piece| piece.color() == &Color::White);
+        if !has_white_pieces {
+            return Some(Outcome::Win(Color::Black));
+        }
+
+        checkmate_or_stalemate(board, &self.legal_moves(board))
+    }
+}
@@ -0,0 +1,51 @@
+// Absolute imports within crate
+use crate::board::{Board, MoveList, Outcome, Square};
+use crate::piece::{Color, Kind};
+// Imports from parent
+use super::{checkmate_or_stalemate, Variant};
+
+// Regular chess, where a player also wins by bringing their king to one of the four center squares
+pub struct KingOfTheHill;
+
+impl KingOfTheHill {
+    // Player whose king has reached the center
+    fn winner(&self, board: &Board) -> Option<Color> {
+        let center = [
+            Square::new(4, 4),
+            Square::new(4, 5),
+            Square::new(5, 4),
+            Square::new(5, 5),
+        ];
+
+        center.iter().find_map(|square| {
+            board
+                .piece_placement()
+                .get(square)
+                .filter(|piece| piece.kind() == &Kind::King)
+                .map(|piece| piece.color().clone())
+        })
+    }
+}
+
+impl Variant for KingOfTheHill {
+    fn name(&self) -> &'static str {
+        "King of the Hill"
+    }
+
+    fn legal_moves(&self, board: &Board) -> MoveList {
+        // No more moves after a king reached the center
+        if self.winner(board).is_some() {
+            return MoveList::new();
+        }
+
+        board.legal_moves()
+    }
+
+    fn outcome(&self, board: &Board) -> Option<Outcome> {
+        if let Some(color) = self.winner(board) {
+            return Some(Outcome::Win(color));
+        }
+
+        checkmate_or_stalemate(board, &board.legal_moves())
+    }
+}
@@ -0,0 +1,90 @@
+// Absolute imports within crate
+use crate::board::{Board, MoveList, Outcome};
+use crate::piece::{Color, Kind};
+// Imports from parent
+use super::Variant;
+
+// Both players race their king to the eighth rank, and giving check is not allowed
+pub struct RacingKings;
+
+impl RacingKings {
+    fn has_reached_goal(&self, board: &Board, color: &Color) -> bool {
+        board
+            .piece_placement()
+            .iter()
+            .any(|(square, piece)| piece.color() == color && piece.kind() == &Kind::King && square.rank() == 8)
+    }
+
+    // Legal moves of regular chess, except for moves that give check
+    fn moves_without_check(&self, board: &Board) -> MoveList {
+        let opposite_color = board.active_color().opposite();
+
+        board
+            .legal_moves()
+            .into_iter()
+            .filter(|chess_move| {
+                let mut board = board.clone();
+                board.make_move(chess_move.clone()).is_ok() && !board.is_in_check(&opposite_color)
+            })
+            .collect()
+    }
+
+    // Outcome based on the kings that reached the eighth rank
+    fn race_outcome(&self, board: &Board) -> Option<Outcome> {
+        let white_reached_goal = self.has_reached_goal(board, &Color::White);
+        let black_reached_goal = self.has_reached_goal(board, &Color::Black);
+
+        match (white_reached_goal, black_reached_goal) {
+            (true, true) => Some(Outcome::Draw),
+            (false, true) => Some(Outcome::Win(Color::Black)),
+            (true, false) => {
+                // Black gets one more move to draw by also reaching the eighth rank
+                let black_can_reach_goal = board.active_color() == &Color::Black
+                    && self.moves_without_check(board).iter().any(|chess_move| {
+                        chess_move.piece().kind() == &Kind::King && chess_move.destination_square().rank() == 8
+                    });
+
+                if black_can_reach_goal {
+                    None
+                } else {
+                    Some(Outcome::Win(Color::White))
+                }
+            }
+            (false, false) => None,
+        }
+    }
+}
+
+impl Variant for RacingKings {
+    fn name(&self) -> &'static str {
+        "Racing Kings"
+    }
+
+    fn starting_position(&self) -> Board {
+        // Since FEN break the spell checker, turn it of for the next line - cspell:disable-next
+        Board::forsyth_edwards_notation("8/8/8/8/8/8/krbnNBRK/qrbnNBRQ w - - 0 1")
+            .expect("starting position is a valid record")
+    }
+
+    fn legal_moves(&self, board: &Board) -> MoveList {
+        // No more moves after the race is decided
+        if self.race_outcome(board).is_some() {
+            return MoveList::new();
+        }
+
+        self.moves_without_check(board)
+    }
+
+    fn outcome(&self, board: &Board) -> Option<Outcome> {
+        if let Some(outcome) = self.race_outcome(board) {
+            return Some(outcome);
+        }
+
+        // Nobody can be in check, so no legal moves means stalemate
+        if self.legal_moves(board).is_empty() {
+            Some(Outcome::Draw)
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,21 @@
+// Absolute imports within crate
+use crate::board::{Board, MoveList, Outcome};
+// Imports from parent
+use super::Variant;
+
+// Regular chess, which includes Chess960 positions
+pub struct Standard;
+
+impl Variant for Standard {
+    fn name(&self) -> &'static str {
+        "Standard"
+    }
+
+    fn legal_moves(&self, board: &Board) -> MoveList {
+        board.legal_moves()
+    }
+
+    fn outcome(&self, board: &Board) -> Option<Outcome> {
+        board.outcome()
+    }
+}
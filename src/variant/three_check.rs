@@ -0,0 +1,72 @@
+// Absolute imports within crate
+use crate::board::{Board, BoardError, ChessMove, MoveList, Outcome, RemainingChecks};
+use crate::parser::ParseError;
+use crate::piece::Color;
+// Imports from parent
+use super::{checkmate_or_stalemate, Variant};
+
+// Regular chess, where a player also wins by giving check three times
+pub struct ThreeCheck;
+
+impl ThreeCheck {
+    // Player who has given all the checks they needed
+    fn winner(&self, board: &Board) -> Option<Color> {
+        let remaining_checks = board.remaining_checks()?;
+
+        [Color::White, Color::Black]
+            .into_iter()
+            .find(|color| remaining_checks.get(color) == 0)
+    }
+}
+
+impl Variant for ThreeCheck {
+    fn name(&self) -> &'static str {
+        "Three-check"
+    }
+
+    fn starting_position(&self) -> Board {
+        let mut board = Board::starting_position();
+        board.set_remaining_checks(Some(RemainingChecks::default()));
+        board
+    }
+
+    fn legal_moves(&self, board: &Board) -> MoveList {
+        // No more moves after the third check
+        if self.winner(board).is_some() {
+            return MoveList::new();
+        }
+
+        board.legal_moves()
+    }
+
+    fn make_move(&self, board: &mut Board, chess_move: ChessMove) -> Result<(), BoardError> {
+        let color = board.active_color().clone();
+        board.make_move(chess_move)?;
+
+        // Count the check given by this move
+        if board.is_in_check(board.active_color()) {
+            board.decrement_remaining_checks(&color);
+        }
+
+        Ok(())
+    }
+
+    fn outcome(&self, board: &Board) -> Option<Outcome> {
+        if let Some(color) = self.winner(board) {
+            return Some(Outcome::Win(color));
+        }
+
+        checkmate_or_stalemate(board, &board.legal_moves())
+    }
+
+    fn parse_forsyth_edwards_notation(&self, record: &str) -> Result<Board, ParseError> {
+        let mut board = Board::forsyth_edwards_notation(record)?;
+
+        // Records without a check count start with three remaining checks
+        if board.remaining_checks().is_none() {
+            board.set_remaining_checks(Some(RemainingChecks::default()));
+        }
+
+        Ok(board)
+    }
+}
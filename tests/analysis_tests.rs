@@ -0,0 +1,49 @@
+use chess::analysis::{self, MoveClassification};
+use chess::piece::Color;
+
+// cspell:disable-next
+const SCHOLARS_MATE: &str =
+    "[Event \"Casual game\"]\n[Result \"1-0\"]\n\n1. e4 e5 2. Bc4 Nc6 3. Qh5 Nf6 4. Qxf7# 1-0\n";
+
+#[test]
+fn classify_blunder() {
+    let analysis = analysis::analyze_portable_game_notation(SCHOLARS_MATE, 1).expect("This should be a valid record");
+    let moves = analysis.moves();
+    assert_eq!(moves.len(), 7);
+
+    // Nf6 allows mate, while the queen on h5 attacks f7 and e5
+    let blunder = &moves[5];
+    assert_eq!(blunder.played_move().standard_algebraic_notation(), "Nf6");
+    assert_eq!(blunder.classification(), &MoveClassification::Blunder);
+    assert!(blunder.centipawn_loss() > 300);
+
+    let mate = &moves[6];
+    assert_eq!(mate.classification(), &MoveClassification::Best);
+    assert_eq!(mate.centipawn_loss(), 0);
+
+    assert_eq!(analysis.count(&Color::Black, &MoveClassification::Blunder), 1);
+    assert!(analysis.accuracy(&Color::White) > analysis.accuracy(&Color::Black));
+    assert!(analysis.average_centipawn_loss(&Color::Black) > analysis.average_centipawn_loss(&Color::White));
+}
+
+#[test]
+fn annotated_record() {
+    let analysis = analysis::analyze_portable_game_notation(SCHOLARS_MATE, 1).expect("This should be a valid record");
+    let record = analysis.to_portable_game_notation();
+
+    // The blunder gets a glyph and a comment, followed by the best line of the engine as a variation
+    assert!(record.contains("Nf6 $4 {Blunder."));
+    assert!(record.contains("was best.} ("));
+    assert!(record.contains("Qxf7#"));
+}
+
+#[test]
+fn classification_thresholds() {
+    assert_eq!(MoveClassification::from_win_percent_loss(1.0), MoveClassification::Excellent);
+    assert_eq!(MoveClassification::from_win_percent_loss(4.0), MoveClassification::Good);
+    assert_eq!(MoveClassification::from_win_percent_loss(8.0), MoveClassification::Inaccuracy);
+    assert_eq!(MoveClassification::from_win_percent_loss(15.0), MoveClassification::Mistake);
+    assert_eq!(MoveClassification::from_win_percent_loss(40.0), MoveClassification::Blunder);
+    assert_eq!(MoveClassification::Blunder.nag(), Some(4));
+    assert_eq!(MoveClassification::Good.nag(), None);
+}
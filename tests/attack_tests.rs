@@ -0,0 +1,67 @@
+use chess::board::{Board, Square, SquareList};
+use chess::piece::Color;
+
+fn sorted(mut squares: SquareList) -> Vec<String> {
+    squares.sort_by_key(|square| (square.file(), square.rank()));
+    squares.iter().map(|square| square.to_string()).collect()
+}
+
+#[test]
+fn attackers_of() {
+    // cspell:disable-next
+    let board = Board::forsyth_edwards_notation("4k3/8/2n5/8/3p4/2P1P3/8/3RK2B w - - 0 1")
+        .expect("This should be a valid record");
+    let d4 = Square::new(4, 4);
+
+    assert_eq!(sorted(board.attackers_of(&d4, &Color::White)), ["c3", "d1", "e3"]);
+    assert_eq!(sorted(board.attackers_of(&d4, &Color::Black)), ["c6"]);
+    assert_eq!(sorted(board.attackers_of(&Square::new(5, 5), &Color::Black)), ["c6"]);
+    assert!(board.is_attacked(&d4, &Color::White));
+    assert!(!board.is_attacked(&Square::new(4, 3), &Color::Black));
+    assert_eq!(board.attacker_count(&d4, &Color::White), 3);
+}
+
+#[test]
+fn starting_position() {
+    let board = Board::starting_position();
+
+    // Every square of the third rank is attacked, but none of the fourth rank
+    for file in 1..=8 {
+        assert!(board.is_attacked(&Square::new(file, 3), &Color::White));
+        assert!(!board.is_attacked(&Square::new(file, 4), &Color::White));
+    }
+    assert_eq!(board.attack_map(&Color::White).len(), 22);
+
+    // Every piece except the rooks is defended
+    let defence_map = board.defence_map(&Color::White);
+    assert_eq!(defence_map.len(), 14);
+    assert!(!defence_map.contains_key(&Square::new(1, 1)));
+    assert_eq!(board.defender_count(&Square::new(5, 2)), 4);
+    assert_eq!(board.defender_count(&Square::new(5, 4)), 0);
+}
+
+#[test]
+fn attacked_squares() {
+    // cspell:disable-next
+    let board =
+        Board::forsyth_edwards_notation("4k3/8/8/8/1p6/8/8/R3K3 w - - 0 1").expect("This should be a valid record");
+
+    assert_eq!(
+        sorted(board.attacked_squares(&Square::new(1, 1))),
+        ["a2", "a3", "a4", "a5", "a6", "a7", "a8", "b1", "c1", "d1", "e1"]
+    );
+    assert_eq!(sorted(board.attacked_squares(&Square::new(2, 4))), ["a3", "c3"]);
+    assert_eq!(sorted(board.attacked_squares(&Square::new(4, 4))), Vec::<String>::new());
+}
+
+#[test]
+fn xray_attackers() {
+    // Queen and rook battery on the d-file, with a bishop behind a pawn
+    // cspell:disable-next
+    let board = Board::forsyth_edwards_notation("3rk3/8/8/8/8/2P5/1B1Q4/3RK3 w - - 0 1")
+        .expect("This should be a valid record");
+
+    assert_eq!(sorted(board.xray_attackers_of(&Square::new(4, 8), &Color::White)), ["d1"]);
+    assert_eq!(sorted(board.xray_attackers_of(&Square::new(4, 4), &Color::White)), ["b2", "d1"]);
+    assert_eq!(sorted(board.xray_attackers_of(&Square::new(4, 2), &Color::Black)), Vec::<String>::new());
+}
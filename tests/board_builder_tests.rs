@@ -0,0 +1,102 @@
+use chess::board::{Board, BoardBuilder, CastlingAvailability, PositionError, Square};
+use chess::piece::{Color, Kind, Piece};
+
+#[test]
+fn build_position() {
+    let board = BoardBuilder::new()
+        .put(Square::new(5, 1), Piece::new(Color::White, Kind::King))
+        .put(Square::new(4, 4), Piece::new(Color::White, Kind::Queen))
+        .put(Square::new(5, 8), Piece::new(Color::Black, Kind::King))
+        .active_color(Color::Black)
+        .halfmove_clock(3)
+        .fullmove_number(20)
+        .build()
+        .expect("This should be a valid position");
+
+    // cspell:disable-next
+    assert_eq!(board.to_forsyth_edwards_notation(), "4k3/8/8/8/3Q4/8/8/4K3 b - - 3 20");
+}
+
+#[test]
+fn edit_existing_board() {
+    let board = BoardBuilder::from(&Board::starting_position())
+        .remove(&Square::new(4, 1))
+        .remove(&Square::new(2, 8))
+        .build()
+        .expect("This should be a valid position");
+
+    // cspell:disable-next
+    assert_eq!(board.to_forsyth_edwards_notation(), "r1bqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNB1KBNR w KQkq - 0 1");
+}
+
+#[test]
+fn infer_castling() {
+    let board = BoardBuilder::new()
+        .put(Square::new(5, 1), Piece::new(Color::White, Kind::King))
+        .put(Square::new(8, 1), Piece::new(Color::White, Kind::Rook))
+        .put(Square::new(1, 1), Piece::new(Color::White, Kind::Rook))
+        .put(Square::new(5, 8), Piece::new(Color::Black, Kind::King))
+        .put(Square::new(1, 8), Piece::new(Color::Black, Kind::Rook))
+        .put(Square::new(7, 8), Piece::new(Color::Black, Kind::Rook))
+        .build()
+        .expect("This should be a valid position");
+
+    assert_eq!(board.castling_availability(), &CastlingAvailability::new(true, true, false, true));
+
+    // Explicit castling availability is not inferred
+    let board = BoardBuilder::from(&board)
+        .castling_availability(CastlingAvailability::new(false, false, false, false))
+        .build()
+        .expect("This should be a valid position");
+    assert_eq!(board.castling_availability(), &CastlingAvailability::new(false, false, false, false));
+}
+
+#[test]
+fn mirror() {
+    // cspell:disable-next
+    let board = Board::forsyth_edwards_notation("rnbqkbnr/ppp1pppp/8/8/3pP3/8/PPPP1PPP/RNBQKBNR b Kq e3 0 3")
+        .expect("This should be a valid record");
+    let mirrored = BoardBuilder::from(&board)
+        .mirror()
+        .build()
+        .expect("This should be a valid position");
+
+    // cspell:disable-next
+    assert_eq!(mirrored.to_forsyth_edwards_notation(), "rnbqkbnr/pppp1ppp/8/3Pp3/8/8/PPP1PPPP/RNBQKBNR w Qk e6 0 3");
+}
+
+#[test]
+fn flip_colors() {
+    let board = BoardBuilder::new()
+        .put(Square::new(5, 1), Piece::new(Color::White, Kind::King))
+        .put(Square::new(5, 8), Piece::new(Color::Black, Kind::King))
+        .put(Square::new(1, 4), Piece::new(Color::White, Kind::Pawn))
+        .flip_colors()
+        .build()
+        .expect("This should be a valid position");
+
+    // cspell:disable-next
+    assert_eq!(board.to_forsyth_edwards_notation(), "4K3/8/8/8/p7/8/8/4k3 w - - 0 1");
+}
+
+#[test]
+fn invalid_positions() {
+    let result = BoardBuilder::new()
+        .put(Square::new(5, 1), Piece::new(Color::White, Kind::King))
+        .build();
+    assert_eq!(result.err(), Some(PositionError::MissingKing(Color::Black)));
+
+    let result = BoardBuilder::new()
+        .put(Square::new(5, 1), Piece::new(Color::White, Kind::King))
+        .put(Square::new(5, 8), Piece::new(Color::Black, Kind::King))
+        .put(Square::new(5, 5), Piece::new(Color::White, Kind::Rook))
+        .build();
+    assert_eq!(result.err(), Some(PositionError::OpponentInCheck(Color::Black)));
+
+    let result = BoardBuilder::new()
+        .put(Square::new(5, 1), Piece::new(Color::White, Kind::King))
+        .put(Square::new(5, 8), Piece::new(Color::Black, Kind::King))
+        .en_passant_target(Some(Square::new(4, 6)))
+        .build();
+    assert_eq!(result.err(), Some(PositionError::InvalidEnPassant(Square::new(4, 6))));
+}
@@ -0,0 +1,150 @@
+use chess::board::{Action, Board};
+use std::collections::HashSet;
+
+// Make the first legal move with the given action
+fn make_castling_move(board: &mut Board, action: Action) {
+    let chess_move = board
+        .legal_moves()
+        .into_iter()
+        .find(|chess_move| chess_move.action() == &action)
+        .expect("Castling should be legal");
+
+    board
+        .make_move(chess_move)
+        .expect("Castling should be possible");
+}
+
+#[test]
+fn starting_positions() {
+    // Number 518 is the starting position of regular chess
+    let board = Board::chess960_starting_position(518).expect("This should be a valid number");
+    assert_eq!(board.to_forsyth_edwards_notation(), Board::starting_position().to_forsyth_edwards_notation());
+
+    // Number 0 is the first position of the Scharnagl numbering - cspell:disable-next
+    let board = Board::chess960_starting_position(0).expect("This should be a valid number");
+    assert_eq!(
+        board.to_shredder_forsyth_edwards_notation(),
+        "bbqnnrkr/pppppppp/8/8/8/8/PPPPPPPP/BBQNNRKR w HFhf - 0 1"
+    );
+
+    // All positions are different
+    let records: HashSet<String> = (0..960)
+        .map(|number| {
+            Board::chess960_starting_position(number)
+                .expect("This should be a valid number")
+                .to_forsyth_edwards_notation()
+        })
+        .collect();
+    assert_eq!(records.len(), 960);
+
+    // Numbering starts at 0, so there is no position 960
+    assert!(Board::chess960_starting_position(960).is_none());
+}
+
+#[test]
+fn shredder_and_x_fen_records() {
+    // Castling with the outermost rooks is written as KQkq in X-FEN - cspell:disable-next
+    let board =
+        Board::forsyth_edwards_notation("4k3/8/8/8/8/8/8/RK5R w HA - 0 1").expect("This should be a valid record");
+    assert_eq!(board.to_forsyth_edwards_notation(), "4k3/8/8/8/8/8/8/RK5R w KQ - 0 1");
+    assert_eq!(board.to_shredder_forsyth_edwards_notation(), "4k3/8/8/8/8/8/8/RK5R w HA - 0 1");
+
+    // Castling with an inner rook uses its file in X-FEN - cspell:disable-next
+    let board =
+        Board::forsyth_edwards_notation("2r1k3/8/8/8/8/8/8/R1RK4 b Cc - 0 1").expect("This should be a valid record");
+    assert_eq!(board.to_forsyth_edwards_notation(), "2r1k3/8/8/8/8/8/8/R1RK4 b Cq - 0 1");
+    assert_eq!(board.to_shredder_forsyth_edwards_notation(), "2r1k3/8/8/8/8/8/8/R1RK4 b Cc - 0 1");
+}
+
+#[test]
+fn castling_execution() {
+    // Long castle with the king next to the rook - cspell:disable-next
+    let mut board =
+        Board::forsyth_edwards_notation("4k3/8/8/8/8/8/8/RK5R w HA - 0 1").expect("This should be a valid record");
+    make_castling_move(&mut board, Action::LongCastle);
+    assert_eq!(board.to_forsyth_edwards_notation(), "4k3/8/8/8/8/8/8/2KR3R b - - 1 1");
+
+    // Short castle with the king moving past the rook's destination - cspell:disable-next
+    let mut board =
+        Board::forsyth_edwards_notation("4k3/8/8/8/8/8/8/RK5R w HA - 0 1").expect("This should be a valid record");
+    make_castling_move(&mut board, Action::ShortCastle);
+    assert_eq!(board.to_forsyth_edwards_notation(), "4k3/8/8/8/8/8/8/R4RK1 b - - 1 1");
+
+    // Short castle where the king is already on its destination square - cspell:disable-next
+    let mut board =
+        Board::forsyth_edwards_notation("4k3/8/8/8/8/8/8/6KR w H - 0 1").expect("This should be a valid record");
+    make_castling_move(&mut board, Action::ShortCastle);
+    assert_eq!(board.to_forsyth_edwards_notation(), "4k3/8/8/8/8/8/8/5RK1 b - - 1 1");
+}
+
+#[test]
+fn castling_through_check() {
+    // Black rook attacks a square the king passes on the way to the G-file - cspell:disable-next
+    let board =
+        Board::forsyth_edwards_notation("4kr2/8/8/8/8/8/8/RK5R w HA - 0 1").expect("This should be a valid record");
+    let actions: Vec<Action> = board
+        .legal_moves()
+        .into_iter()
+        .map(|chess_move| chess_move.action().clone())
+        .collect();
+
+    assert!(!actions.contains(&Action::ShortCastle));
+    assert!(actions.contains(&Action::LongCastle));
+}
+
+// Source of all positions and node counts: https://www.chessprogramming.org/Chess960_Perft_Results
+
+#[test]
+fn perft_position_1() {
+    // Since FEN break the spell checker, turn it of for the next line - cspell:disable-next
+    let record = "bqnb1rkr/pp3ppp/3ppn2/2p5/5P2/P2P4/NPP1P1PP/BQ1BNRKR w HFhf - 2 9";
+    let board = Board::forsyth_edwards_notation(record).expect("This should be a valid record");
+
+    assert_eq!(board.perft(1), 21);
+    assert_eq!(board.perft(2), 528);
+    assert_eq!(board.perft(3), 12_189);
+}
+
+#[test]
+fn perft_position_2() {
+    // Since FEN break the spell checker, turn it of for the next line - cspell:disable-next
+    let record = "2nnrbkr/p1qppppp/8/1ppb4/6PP/3PP3/PPP2P2/BQNNRBKR w HEhe - 1 9";
+    let board = Board::forsyth_edwards_notation(record).expect("This should be a valid record");
+
+    assert_eq!(board.perft(1), 21);
+    assert_eq!(board.perft(2), 807);
+    assert_eq!(board.perft(3), 18_002);
+}
+
+#[test]
+fn perft_position_3() {
+    // Since FEN break the spell checker, turn it of for the next line - cspell:disable-next
+    let record = "b1q1rrkb/pppppppp/3nn3/8/P7/1PPP4/4PPPP/BQNNRKRB w GE - 1 9";
+    let board = Board::forsyth_edwards_notation(record).expect("This should be a valid record");
+
+    assert_eq!(board.perft(1), 20);
+    assert_eq!(board.perft(2), 479);
+    assert_eq!(board.perft(3), 10_471);
+}
+
+#[test]
+fn perft_position_4() {
+    // Since FEN break the spell checker, turn it of for the next line - cspell:disable-next
+    let record = "qbbnnrkr/2pp2pp/p7/1p2pp2/8/P3PP2/1PPP1KPP/QBBNNR1R w hf - 0 9";
+    let board = Board::forsyth_edwards_notation(record).expect("This should be a valid record");
+
+    assert_eq!(board.perft(1), 22);
+    assert_eq!(board.perft(2), 593);
+    assert_eq!(board.perft(3), 13_440);
+}
+
+#[test]
+fn perft_position_5() {
+    // Since FEN break the spell checker, turn it of for the next line - cspell:disable-next
+    let record = "1nbbnrkr/p1p1ppp1/3p4/1p3P1p/3Pq2P/8/PPP1P1P1/QNBBNRKR w HFhf - 0 9";
+    let board = Board::forsyth_edwards_notation(record).expect("This should be a valid record");
+
+    assert_eq!(board.perft(1), 28);
+    assert_eq!(board.perft(2), 1_120);
+    assert_eq!(board.perft(3), 31_058);
+}
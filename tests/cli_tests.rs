@@ -0,0 +1,79 @@
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+// Run the command line interface with the commands as input, returning everything it writes
+fn run(arguments: &[&str], commands: &str) -> String {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_chess"))
+        .args(arguments)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .expect("This should start the binary");
+
+    child
+        .stdin
+        .take()
+        .expect("This should have an input")
+        .write_all(commands.as_bytes())
+        .expect("This should write the commands");
+    let output = child.wait_with_output().expect("This should finish");
+
+    String::from_utf8(output.stdout).expect("This should be valid text")
+}
+
+#[test]
+fn play_moves() {
+    let output = run(&[], "e4\ne7e5\nfen\nundo\nfen\nquit\n");
+
+    // cspell:disable-next
+    assert!(output.contains("rnbqkbnr/pppp1ppp/8/4p3/4P3/8/PPPP1PPP/RNBQKBNR w KQkq e6 0 2"));
+    // cspell:disable-next
+    assert!(output.contains("rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1"));
+}
+
+#[test]
+fn announce_check_and_checkmate() {
+    let output = run(&[], "f3\ne5\ng4\nQh4#\npgn\n");
+
+    assert!(output.contains("Black wins by checkmate."));
+    assert!(output.contains("1. f3 e5 2. g4 Qh4# 0-1"));
+}
+
+#[test]
+fn start_from_position() {
+    // cspell:disable-next
+    let output = run(&["--fen", "4k3/8/8/8/8/8/4Q3/4K3 w - - 0 1"], "Qe7+\nmoves\nKxe7\n");
+
+    assert!(output.contains("Check!"));
+    // The king has to capture the queen
+    assert!(output.contains("Black> Kxe7\n"));
+    assert!(output.contains("Draw by insufficient material."));
+}
+
+#[test]
+fn illegal_move() {
+    let output = run(&[], "e5\n");
+    assert!(output.contains("illegal move: e5"));
+}
+
+#[test]
+fn engine() {
+    let output = run(&["--engine", "black", "--depth", "1"], "e4\nhint\nanalyze\n");
+
+    assert!(output.contains("The engine plays"));
+    assert!(output.contains("The engine would play"));
+    assert!(output.contains("Best line:"));
+}
+
+#[test]
+fn invalid_depth() {
+    let output = Command::new(env!("CARGO_BIN_EXE_chess"))
+        .args(["--depth", "0"])
+        .stdin(Stdio::null())
+        .output()
+        .expect("This should run the binary");
+
+    // The engine would never reply without searching
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("invalid depth: 0"));
+}
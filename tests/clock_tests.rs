@@ -0,0 +1,119 @@
+use std::time::Duration;
+
+use chess::board::{Board, Outcome};
+use chess::clock::{Clock, Delay, ManualTime, Stage, TimeControl};
+use chess::game::{Game, GameError};
+use chess::piece::Color;
+
+fn seconds(seconds: u64) -> Duration {
+    Duration::from_secs(seconds)
+}
+
+#[test]
+fn increment() {
+    let time = ManualTime::new();
+    let mut clock = Clock::with_time_source(TimeControl::increment(seconds(180), seconds(2)), time.clone());
+
+    // The first move starts the clock of the opponent
+    clock.press(&Color::White);
+    assert_eq!(clock.running(), Some(&Color::Black));
+
+    time.advance(seconds(10));
+    assert_eq!(clock.remaining(&Color::Black), seconds(170));
+    clock.press(&Color::Black);
+    assert_eq!(clock.remaining(&Color::Black), seconds(172));
+    assert_eq!(clock.running(), Some(&Color::White));
+}
+
+#[test]
+fn delays() {
+    // Bronstein adds back the time used, up to the delay
+    let time = ManualTime::new();
+    let mut clock = Clock::with_time_source(TimeControl::bronstein(seconds(60), seconds(5)), time.clone());
+    clock.start(Color::White);
+    time.advance(seconds(3));
+    clock.press(&Color::White);
+    assert_eq!(clock.remaining(&Color::White), seconds(60));
+
+    time.advance(seconds(8));
+    clock.press(&Color::Black);
+    assert_eq!(clock.remaining(&Color::Black), seconds(57));
+
+    // Simple delay only starts counting down after the delay
+    let time = ManualTime::new();
+    let mut clock = Clock::with_time_source(TimeControl::simple_delay(seconds(60), seconds(5)), time.clone());
+    clock.start(Color::White);
+    time.advance(seconds(3));
+    assert_eq!(clock.remaining(&Color::White), seconds(60));
+    time.advance(seconds(5));
+    assert_eq!(clock.remaining(&Color::White), seconds(57));
+    clock.press(&Color::White);
+    assert_eq!(clock.remaining(&Color::White), seconds(57));
+}
+
+#[test]
+fn multiple_stages() {
+    // 90 minutes for the first 40 moves, then 30 minutes for the rest, with 30 seconds per move
+    let time_control = TimeControl::new(vec![
+        Stage::new(Some(40), seconds(5400), Delay::Increment(seconds(30))),
+        Stage::new(None, seconds(1800), Delay::Increment(seconds(30))),
+    ]);
+    assert_eq!(time_control.to_string(), "40/5400+30:1800+30");
+    assert_eq!(TimeControl::portable_game_notation("40/5400+30:1800+30"), Ok(time_control.clone()));
+
+    let time = ManualTime::new();
+    let mut clock = Clock::with_time_source(time_control, time.clone());
+    for _ in 0..40 {
+        time.advance(seconds(60));
+        clock.press(&Color::White);
+        clock.press(&Color::Black);
+    }
+
+    // All moves of white took 60 seconds, except for the first move
+    assert_eq!(clock.remaining(&Color::White), seconds(5400 - 39 * 60 + 40 * 30 + 1800));
+}
+
+#[test]
+fn flag_fall() {
+    let time = ManualTime::new();
+    let mut game = Game::new();
+    game.set_clock(Clock::with_time_source(TimeControl::sudden_death(seconds(10)), time.clone()));
+    assert_eq!(game.tag("TimeControl"), Some("10"));
+
+    game.make_standard_algebraic_notation_move("e4")
+        .expect("This should be a legal move");
+    time.advance(seconds(11));
+
+    assert_eq!(game.make_standard_algebraic_notation_move("e5"), Err(GameError::GameOver));
+    assert_eq!(game.outcome(), Some(&Outcome::Win(Color::White)));
+    assert_eq!(game.tag("Termination"), Some("time forfeit"));
+}
+
+#[test]
+fn flag_fall_against_insufficient_material() {
+    // cspell:disable-next
+    let board =
+        Board::forsyth_edwards_notation("4k3/8/8/8/8/8/8/3QK3 w - - 0 1").expect("This should be a valid record");
+    let time = ManualTime::new();
+    let mut game = Game::from_position(board);
+    game.set_clock(Clock::with_time_source(TimeControl::sudden_death(seconds(10)), time.clone()));
+
+    game.make_standard_algebraic_notation_move("Qd2")
+        .expect("This should be a legal move");
+    game.make_standard_algebraic_notation_move("Kf7")
+        .expect("This should be a legal move");
+    time.advance(seconds(10));
+
+    // Black cannot checkmate with a lone king
+    assert_eq!(game.check_time(), Some(&Outcome::Draw));
+}
+
+#[test]
+fn send_and_sync() {
+    fn assert_send_and_sync<T: Send + Sync>() {}
+
+    // Games with a clock are played on other threads of a server
+    assert_send_and_sync::<Clock>();
+    assert_send_and_sync::<ManualTime>();
+    assert_send_and_sync::<Game>();
+}
@@ -0,0 +1,139 @@
+use chess::board::{Board, Pocket, PositionError};
+use chess::parser::ParseError;
+use chess::piece::{Color, Kind, Piece};
+use chess::variant::{Bughouse, Crazyhouse, Variant};
+
+// Find a legal move by its long algebraic notation
+fn find_move(legal_moves: chess::board::MoveList, notation: &str) -> chess::board::ChessMove {
+    legal_moves
+        .into_iter()
+        .find(|chess_move| chess_move.to_string() == notation)
+        .expect("This should be a legal move")
+}
+
+#[test]
+fn perft_starting_position() {
+    let board = Crazyhouse.starting_position();
+
+    assert_eq!(Crazyhouse.perft(&board, 1), 20);
+    assert_eq!(Crazyhouse.perft(&board, 2), 400);
+    assert_eq!(Crazyhouse.perft(&board, 3), 8_902);
+}
+
+#[test]
+fn drops() {
+    // Knight can be dropped on any empty square - cspell:disable-next
+    let board = Crazyhouse
+        .parse_forsyth_edwards_notation("4k3/8/8/8/8/8/8/4K3[N] w - - 0 1")
+        .expect("This should be a valid record");
+    assert_eq!(Crazyhouse.legal_moves(&board).len(), 5 + 62);
+
+    // Pawns cannot be dropped on the first and last rank - cspell:disable-next
+    let board = Crazyhouse
+        .parse_forsyth_edwards_notation("4k3/8/8/8/8/8/8/4K3[P] w - - 0 1")
+        .expect("This should be a valid record");
+    assert_eq!(Crazyhouse.legal_moves(&board).len(), 5 + 48);
+
+    // Check can be blocked by dropping a piece in between - cspell:disable-next
+    let board = Crazyhouse
+        .parse_forsyth_edwards_notation("4k3/8/8/8/8/8/8/r3K3[N] w - - 0 1")
+        .expect("This should be a valid record");
+    assert_eq!(Crazyhouse.legal_moves(&board).len(), 3 + 3);
+}
+
+#[test]
+fn make_drop() {
+    // cspell:disable-next
+    let mut board = Crazyhouse
+        .parse_forsyth_edwards_notation("4k3/8/8/8/8/8/8/4K3[Nn] w - - 0 1")
+        .expect("This should be a valid record");
+
+    let chess_move = find_move(Crazyhouse.legal_moves(&board), "N@f6");
+    assert_eq!(chess_move.uci(), "N@f6");
+    Crazyhouse
+        .make_move(&mut board, chess_move)
+        .expect("This should be a valid move");
+
+    // cspell:disable-next
+    assert_eq!(board.to_forsyth_edwards_notation(), "4k3/8/5N2/8/8/8/8/4K3[n] b - - 1 1");
+    assert_eq!(
+        board
+            .pocket()
+            .map(|pocket| pocket.count(&Color::White, &Kind::Knight)),
+        Some(0)
+    );
+}
+
+#[test]
+fn captured_pieces_change_sides() {
+    // Promoted queen is marked with a tilde - cspell:disable-next
+    let mut board = Crazyhouse
+        .parse_forsyth_edwards_notation("3Q~k3/8/8/8/8/8/8/4K3[R] b - - 0 1")
+        .expect("This should be a valid record");
+    // cspell:disable-next
+    assert_eq!(board.to_forsyth_edwards_notation(), "3Q~k3/8/8/8/8/8/8/4K3[R] b - - 0 1");
+
+    // The captured promoted queen turns back into a pawn
+    let chess_move = find_move(Crazyhouse.legal_moves(&board), "Ke8xd8");
+    Crazyhouse
+        .make_move(&mut board, chess_move)
+        .expect("This should be a valid move");
+
+    // cspell:disable-next
+    assert_eq!(board.to_forsyth_edwards_notation(), "3k4/8/8/8/8/8/8/4K3[Rp] w - - 0 2");
+}
+
+#[test]
+fn bughouse_passes_pieces_to_partner() {
+    let mut bughouse = Bughouse::new();
+
+    for notation in ["e2e4", "d7d5", "e4xd5"] {
+        let chess_move = find_move(bughouse.legal_moves(0), notation);
+        bughouse
+            .make_move(0, chess_move)
+            .expect("This should be a valid move");
+    }
+
+    // The black pawn goes to the partner of white, who plays black on the other board
+    let pocket = bughouse
+        .board(1)
+        .pocket()
+        .expect("This should have a pocket");
+    assert_eq!(pocket.count(&Color::Black, &Kind::Pawn), 1);
+    assert!(bughouse
+        .board(0)
+        .pocket()
+        .is_some_and(|pocket| pocket.is_empty()));
+
+    // The pawn can be dropped once it is black's turn on the other board
+    let chess_move = find_move(bughouse.legal_moves(1), "Ng1f3");
+    bughouse
+        .make_move(1, chess_move)
+        .expect("This should be a valid move");
+    assert!(bughouse
+        .legal_moves(1)
+        .iter()
+        .any(|chess_move| chess_move.to_string() == "P@e5"));
+}
+
+#[test]
+fn starting_position_record() {
+    let board = Crazyhouse.starting_position();
+
+    // cspell:disable-next
+    assert_eq!(board.to_forsyth_edwards_notation(), "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR[] w KQkq - 0 1");
+    assert!(Board::starting_position().pocket().is_none());
+}
+
+#[test]
+fn no_king_in_pocket() {
+    // Kings are never captured, so they cannot be dropped either - cspell:disable-next
+    let result = Crazyhouse.parse_forsyth_edwards_notation("4k3/8/8/8/8/8/8/4K3[K] w - - 0 1");
+    assert_eq!(result.err(), Some(ParseError::InvalidPosition(PositionError::KingInPocket(Color::White))));
+
+    let king = Piece::new(Color::Black, Kind::King);
+    assert_eq!(Pocket::new(vec![king.clone()]), Err(PositionError::KingInPocket(Color::Black)));
+    let mut pocket = Pocket::default();
+    assert_eq!(pocket.add(king), Err(PositionError::KingInPocket(Color::Black)));
+    assert!(pocket.is_empty());
+}
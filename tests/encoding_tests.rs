@@ -0,0 +1,171 @@
+use chess::board::Board;
+use chess::encoding::{self, EncodingError};
+use chess::game::Game;
+
+fn round_trip(record: &str) -> Vec<u8> {
+    let board = Board::forsyth_edwards_notation(record).expect("This should be a valid record");
+    let bytes = encoding::encode_position(&board).expect("This should be encoded");
+    let decoded = encoding::decode_position(&bytes).expect("This should be decoded");
+
+    assert_eq!(decoded.to_forsyth_edwards_notation(), record);
+    bytes
+}
+
+#[test]
+fn starting_position() {
+    let board = Board::starting_position();
+    let bytes = round_trip(&board.to_forsyth_edwards_notation());
+
+    assert_eq!(bytes.len(), 29);
+    assert_eq!(bytes[0], encoding::POSITION_VERSION);
+}
+
+#[test]
+fn positions() {
+    // cspell:disable
+    let records = [
+        "rnbqkbnr/pppp1ppp/8/4p3/4P3/8/PPPP1PPP/RNBQKBNR w KQkq e6 0 2",
+        "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1",
+        "r3k2r/8/8/8/8/8/8/R3K2R b Kq - 17 40",
+        "8/8/4k3/8/8/8/3K4/8 w - - 99 300",
+    ];
+    // cspell:enable
+
+    for record in records {
+        let bytes = round_trip(record);
+        assert!(bytes.len() <= 32);
+    }
+}
+
+#[test]
+fn chess960_castling() {
+    for number in [0, 518, 959] {
+        round_trip(
+            &Board::chess960_starting_position(number)
+                .expect("This should be a valid number")
+                .to_forsyth_edwards_notation(),
+        );
+    }
+}
+
+#[test]
+fn same_position_same_bytes() {
+    let mut board = Board::starting_position();
+    for notation in ["Nf3", "Nf6", "Ng1", "Ng8"] {
+        let chess_move = board
+            .parse_standard_algebraic_notation(notation)
+            .expect("This should be a legal move");
+        board
+            .make_move(chess_move)
+            .expect("This should be a legal move");
+    }
+
+    // cspell:disable-next
+    let record = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 4 3";
+    let parsed = Board::forsyth_edwards_notation(record).expect("This should be a valid record");
+    assert_eq!(encoding::encode_position(&board), encoding::encode_position(&parsed));
+}
+
+#[test]
+fn unsupported_positions() {
+    // cspell:disable-next
+    let board =
+        Board::forsyth_edwards_notation("4k3/8/8/8/8/8/8/4K3 w - - 300 200").expect("This should be a valid record");
+    assert!(matches!(encoding::encode_position(&board), Err(EncodingError::Unsupported(_))));
+}
+
+#[test]
+fn invalid_positions() {
+    let bytes = encoding::encode_position(&Board::starting_position()).expect("This should be encoded");
+
+    assert_eq!(encoding::decode_position(&bytes[..20]).err(), Some(EncodingError::UnexpectedEnd));
+
+    let mut newer = bytes.clone();
+    newer[0] = 2;
+    assert_eq!(encoding::decode_position(&newer).err(), Some(EncodingError::UnsupportedVersion(2)));
+
+    // Pawns that can be captured en passant have to be on the rank in front of their target square
+    let mut white_to_move = vec![encoding::POSITION_VERSION];
+    white_to_move.extend_from_slice(&(1u64 << 56).to_le_bytes());
+    white_to_move.extend_from_slice(&[7, 0, 0, 1, 0]);
+    assert_eq!(encoding::decode_position(&white_to_move).err(), Some(EncodingError::InvalidPiece(7)));
+
+    let mut black_to_move = vec![encoding::POSITION_VERSION];
+    black_to_move.extend_from_slice(&1u64.to_le_bytes());
+    black_to_move.extend_from_slice(&[15, 1, 0, 1, 0]);
+    assert_eq!(encoding::decode_position(&black_to_move).err(), Some(EncodingError::InvalidPiece(15)));
+
+    // Rooks that can castle have to be on the first rank, next to a king
+    let mut castling_rook = vec![encoding::POSITION_VERSION];
+    castling_rook.extend_from_slice(&((1u64 << 4) | (1u64 << 7)).to_le_bytes());
+    castling_rook.extend_from_slice(&[0x65, 0, 0, 1, 0]);
+    let board = encoding::decode_position(&castling_rook).expect("This should be decoded");
+    // cspell:disable-next
+    assert_eq!(board.to_forsyth_edwards_notation(), "8/8/8/8/8/8/8/4K2R w K - 0 1");
+
+    let mut off_first_rank = vec![encoding::POSITION_VERSION];
+    off_first_rank.extend_from_slice(&(1u64 << 36).to_le_bytes());
+    off_first_rank.extend_from_slice(&[6, 0, 0, 1, 0]);
+    assert_eq!(encoding::decode_position(&off_first_rank).err(), Some(EncodingError::InvalidPiece(6)));
+
+    let mut without_king = vec![encoding::POSITION_VERSION];
+    without_king.extend_from_slice(&(1u64 << 7).to_le_bytes());
+    without_king.extend_from_slice(&[6, 0, 0, 1, 0]);
+    assert_eq!(encoding::decode_position(&without_king).err(), Some(EncodingError::InvalidPiece(6)));
+
+    let mut active_color = bytes.clone();
+    active_color[25] = 2;
+    assert_eq!(encoding::decode_position(&active_color).err(), Some(EncodingError::InvalidActiveColor(2)));
+}
+
+#[test]
+fn games() {
+    let record = "1. e4 e5 2. Nf3 Nc6 3. Bb5 a6 4. O-O Nf6 5. d4 exd4 6. e5 Ne4 7. Qxd4 Nc5 8. Bxc6 dxc6 \
+                  9. Qxd8+ Kxd8 10. Rd1+ Bd7 11. e6 fxe6 12. Ng5 Ke8 13. Nxe6 Nxe6 14. Bg5 h6 15. Bxh6 Rxh6 *";
+    let game = Game::portable_game_notation(record).expect("This should be a valid record");
+
+    let bytes = encoding::encode_game(&game).expect("This should be encoded");
+    assert_eq!(bytes.len(), 2 + game.moves().len());
+
+    let decoded = encoding::decode_game(&bytes).expect("This should be decoded");
+    let notations = |game: &Game| -> Vec<String> {
+        game.moves()
+            .iter()
+            .map(|played_move| played_move.standard_algebraic_notation().to_string())
+            .collect()
+    };
+    assert_eq!(notations(&decoded), notations(&game));
+    assert_eq!(decoded.board().to_forsyth_edwards_notation(), game.board().to_forsyth_edwards_notation());
+}
+
+#[test]
+fn game_from_position() {
+    // cspell:disable-next
+    let board =
+        Board::forsyth_edwards_notation("4k3/1P6/8/8/8/8/8/4K3 w - - 0 1").expect("This should be a valid record");
+    let mut game = Game::from_position(board);
+    game.make_standard_algebraic_notation_move("b8=R+")
+        .expect("This should be a legal move");
+    game.make_standard_algebraic_notation_move("Kd7")
+        .expect("This should be a legal move");
+
+    let bytes = encoding::encode_game(&game).expect("This should be encoded");
+    let decoded = encoding::decode_game(&bytes).expect("This should be decoded");
+
+    assert_eq!(
+        decoded.initial_position().to_forsyth_edwards_notation(),
+        game.initial_position().to_forsyth_edwards_notation()
+    );
+    assert_eq!(decoded.moves()[0].standard_algebraic_notation(), "b8=R+");
+    assert_eq!(decoded.ply(), 2);
+}
+
+#[test]
+fn invalid_games() {
+    assert_eq!(
+        encoding::decode_game(&[encoding::GAME_VERSION, 0, 250]).err(),
+        Some(EncodingError::InvalidMoveIndex(250))
+    );
+    assert_eq!(encoding::decode_game(&[9, 0]).err(), Some(EncodingError::UnsupportedVersion(9)));
+    assert_eq!(encoding::decode_game(&[encoding::GAME_VERSION]).err(), Some(EncodingError::UnexpectedEnd));
+}
@@ -0,0 +1,144 @@
+use chess::board::{Board, Outcome};
+use chess::piece::Color;
+
+#[test]
+fn king_and_pawn_opposition() {
+    // Black has the opposition with white to move - cspell:disable-next
+    let board =
+        Board::forsyth_edwards_notation("8/4k3/8/4K3/4P3/8/8/8 w - - 0 1").expect("This should be a valid record");
+    assert_eq!(board.known_outcome(), Some(Outcome::Draw));
+
+    // White has the opposition with black to move - cspell:disable-next
+    let board =
+        Board::forsyth_edwards_notation("8/4k3/8/4K3/4P3/8/8/8 b - - 0 1").expect("This should be a valid record");
+    assert_eq!(board.known_outcome(), Some(Outcome::Win(Color::White)));
+
+    // King on the sixth rank in front of the pawn always wins - cspell:disable-next
+    let board =
+        Board::forsyth_edwards_notation("4k3/8/4K3/4P3/8/8/8/8 w - - 0 1").expect("This should be a valid record");
+    assert_eq!(board.known_outcome(), Some(Outcome::Win(Color::White)));
+
+    // Pawn on the sixth rank with the king behind it is drawn - cspell:disable-next
+    let board =
+        Board::forsyth_edwards_notation("4k3/8/4P3/4K3/8/8/8/8 b - - 0 1").expect("This should be a valid record");
+    assert_eq!(board.known_outcome(), Some(Outcome::Draw));
+}
+
+#[test]
+fn king_and_pawn_for_black() {
+    // Same opposition position as above, but with colors reversed and on the other wing - cspell:disable-next
+    let board =
+        Board::forsyth_edwards_notation("8/8/8/3p4/3k4/8/3K4/8 w - - 0 1").expect("This should be a valid record");
+    assert_eq!(board.known_outcome(), Some(Outcome::Win(Color::Black)));
+
+    // cspell:disable-next
+    let board =
+        Board::forsyth_edwards_notation("8/8/8/3p4/3k4/8/3K4/8 b - - 0 1").expect("This should be a valid record");
+    assert_eq!(board.known_outcome(), Some(Outcome::Draw));
+}
+
+#[test]
+fn king_and_rook_pawn() {
+    // Defending king in the corner draws against the rook pawn - cspell:disable-next
+    let board = Board::forsyth_edwards_notation("k7/8/8/8/8/8/P7/K7 w - - 0 1").expect("This should be a valid record");
+    assert!(board.is_known_draw());
+
+    // Defending king too far away to catch the pawn - cspell:disable-next
+    let board = Board::forsyth_edwards_notation("8/8/8/8/8/7k/P7/K7 w - - 0 1").expect("This should be a valid record");
+    assert!(board.is_known_win());
+}
+
+#[test]
+fn wrong_colored_bishop() {
+    // Dark squared bishop cannot drive the king away from the light promotion square - cspell:disable-next
+    let board =
+        Board::forsyth_edwards_notation("k7/8/8/8/8/8/P7/K1B5 w - - 0 1").expect("This should be a valid record");
+    assert!(board.is_known_draw());
+
+    // Light squared bishop controls the promotion square - cspell:disable-next
+    let board =
+        Board::forsyth_edwards_notation("k7/8/8/8/8/8/P7/KB6 w - - 0 1").expect("This should be a valid record");
+    assert!(!board.is_known_draw());
+}
+
+#[test]
+fn king_and_major_piece() {
+    // cspell:disable-next
+    let board =
+        Board::forsyth_edwards_notation("8/8/8/3k4/8/8/8/R3K3 w - - 0 1").expect("This should be a valid record");
+    assert_eq!(board.known_outcome(), Some(Outcome::Win(Color::White)));
+
+    // cspell:disable-next
+    let board =
+        Board::forsyth_edwards_notation("8/8/8/3K4/8/8/8/q3k3 b - - 0 1").expect("This should be a valid record");
+    assert_eq!(board.known_outcome(), Some(Outcome::Win(Color::Black)));
+
+    // The black king can capture the undefended rook - cspell:disable-next
+    let board =
+        Board::forsyth_edwards_notation("8/8/8/8/8/8/6kR/4K3 b - - 0 1").expect("This should be a valid record");
+    assert!(board.is_known_draw());
+
+    // The black king has no moves left - cspell:disable-next
+    let board =
+        Board::forsyth_edwards_notation("7k/5Q2/6K1/8/8/8/8/8 b - - 0 1").expect("This should be a valid record");
+    assert!(board.is_known_draw());
+}
+
+#[test]
+fn king_bishop_and_knight() {
+    // cspell:disable-next
+    let board =
+        Board::forsyth_edwards_notation("8/8/3k4/8/8/8/8/2B1KN2 w - - 0 1").expect("This should be a valid record");
+    assert_eq!(board.known_outcome(), Some(Outcome::Win(Color::White)));
+}
+
+#[test]
+fn insufficient_material() {
+    // Bare kings - cspell:disable-next
+    let board =
+        Board::forsyth_edwards_notation("8/8/3k4/8/8/8/8/4K3 w - - 0 1").expect("This should be a valid record");
+    assert!(board.is_known_draw());
+
+    // Single knight - cspell:disable-next
+    let board =
+        Board::forsyth_edwards_notation("8/8/3k4/8/8/8/8/4KN2 w - - 0 1").expect("This should be a valid record");
+    assert!(board.is_known_draw());
+
+    // Two knights - cspell:disable-next
+    let board =
+        Board::forsyth_edwards_notation("8/8/3k4/8/8/8/8/3NKN2 w - - 0 1").expect("This should be a valid record");
+    assert!(board.is_known_draw());
+
+    // Bishops on the same color - cspell:disable-next
+    let board =
+        Board::forsyth_edwards_notation("8/8/3k1b2/8/8/8/8/2B1K3 w - - 0 1").expect("This should be a valid record");
+    assert!(board.is_known_draw());
+
+    // Bishops on opposite colors can still checkmate with help - cspell:disable-next
+    let board =
+        Board::forsyth_edwards_notation("8/8/3kb3/8/8/8/8/2B1K3 w - - 0 1").expect("This should be a valid record");
+    assert_eq!(board.known_outcome(), None);
+}
+
+#[test]
+fn starting_position_unknown() {
+    let board = Board::starting_position();
+
+    assert!(!board.is_known_draw());
+    assert!(!board.is_known_win());
+}
+
+#[test]
+fn king_and_pawn_on_back_rank_unknown() {
+    let records = [
+        // cspell:disable-next
+        "4k3/8/8/8/8/8/8/P3K3 w - - 0 1",
+        // cspell:disable-next
+        "P3k3/8/8/8/8/8/8/4K3 w - - 0 1",
+    ];
+
+    for record in records {
+        let board = Board::forsyth_edwards_notation(record).expect("This should be a valid record");
+        assert_eq!(board.known_outcome(), None);
+    }
+}
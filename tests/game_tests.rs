@@ -0,0 +1,307 @@
+use chess::board::{Board, Outcome};
+use chess::game::{Game, GameError, KnownOutcomeAdjudicator, ScoreAdjudicator, Termination};
+use chess::piece::Color;
+
+fn play(game: &mut Game, notations: &[&str]) {
+    for notation in notations {
+        game.make_standard_algebraic_notation_move(notation)
+            .expect("This should be a legal move");
+    }
+}
+
+#[test]
+fn standard_algebraic_notation() {
+    // cspell:disable-next
+    let board = Board::forsyth_edwards_notation("r3k2r/1P6/8/3p4/4P3/8/8/RN1QK2R w KQkq - 0 1")
+        .expect("This should be a valid record");
+
+    let notations: Vec<String> = board
+        .legal_moves()
+        .iter()
+        .map(|chess_move| board.to_standard_algebraic_notation(chess_move))
+        .collect();
+
+    for notation in ["exd5", "Nd2", "bxa8=Q+", "b8=N", "Qd4", "Rxa8+", "O-O"] {
+        assert!(notations.contains(&notation.to_string()), "{} is missing", notation);
+    }
+
+    // Knights on b1 and f3 can both go to d2 - cspell:disable-next
+    let board =
+        Board::forsyth_edwards_notation("4k3/8/8/8/8/5N2/8/1N2K3 w - - 0 1").expect("This should be a valid record");
+    let chess_move = board
+        .parse_standard_algebraic_notation("Nbd2")
+        .expect("This should be a legal move");
+    assert_eq!(board.to_standard_algebraic_notation(&chess_move), "Nbd2");
+    assert!(board.parse_standard_algebraic_notation("Nd2").is_err());
+}
+
+#[test]
+fn checkmate_ends_game() {
+    let mut game = Game::new();
+    play(&mut game, &["e4", "e5", "Bc4", "Nc6", "Qh5", "Nf6", "Qxf7#"]);
+
+    assert_eq!(game.moves()[6].standard_algebraic_notation(), "Qxf7#");
+    assert_eq!(game.outcome(), Some(&Outcome::Win(Color::White)));
+    assert_eq!(game.make_standard_algebraic_notation_move("Ke7"), Err(GameError::GameOver));
+}
+
+#[test]
+fn undo_and_redo() {
+    let mut game = Game::new();
+    play(&mut game, &["e4", "e5", "Nf3"]);
+
+    assert!(game.undo());
+    assert!(game.undo());
+    assert_eq!(game.ply(), 1);
+    // cspell:disable-next
+    assert_eq!(
+        game.board().to_forsyth_edwards_notation(),
+        "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq e3 0 1"
+    );
+
+    assert!(game.redo());
+    assert_eq!(game.ply(), 2);
+    assert_eq!(game.moves().len(), 3);
+
+    // A new move replaces the moves that have been undone
+    play(&mut game, &["Nc3"]);
+    assert_eq!(game.moves().len(), 3);
+    assert!(!game.redo());
+
+    game.go_to_ply(0).expect("This should be a valid ply");
+    assert_eq!(game.board().to_forsyth_edwards_notation(), Board::starting_position().to_forsyth_edwards_notation());
+    assert_eq!(game.go_to_ply(4), Err(GameError::InvalidPly(4)));
+}
+
+#[test]
+fn repetition() {
+    let mut game = Game::new();
+    let knight_moves = ["Nf3", "Nf6", "Ng1", "Ng8"];
+
+    play(&mut game, &knight_moves);
+    assert_eq!(game.repetition_count(), 2);
+
+    play(&mut game, &knight_moves);
+    assert!(game.is_threefold_repetition());
+    assert_eq!(game.outcome(), None);
+
+    // The fifth occurrence is an automatic draw
+    play(&mut game, &knight_moves);
+    play(&mut game, &knight_moves);
+    assert!(game.is_fivefold_repetition());
+    assert_eq!(game.outcome(), Some(&Outcome::Draw));
+}
+
+#[test]
+fn read_portable_game_notation() {
+    let record = r#"[Event "Casual game"]
+[Site "?"]
+[Date "1858.??.??"]
+[Round "?"]
+[White "Paul Morphy"]
+[Black "Duke Karl / Count Isouard"]
+[Result "1-0"]
+
+1. e4 e5 2. Nf3 d6 3. d4 Bg4 {This is a weak move already.} 4. dxe5 Bxf3
+5. Qxf3 dxe5 6. Bc4 Nf6 7. Qb3 Qe7 8. Nc3 c6 9. Bg5 b5?! (9... Qc7) 10. Nxb5!
+cxb5 11. Bxb5+ Nbd7 12. O-O-O Rd8 13. Rxd7 Rxd7 14. Rd1 Qe6 15. Bxd7+ Nxd7
+16. Qb8+ Nxb8 17. Rd8# 1-0
+"#;
+    let game = Game::portable_game_notation(record).expect("This should be a valid record");
+
+    assert_eq!(game.tag("White"), Some("Paul Morphy"));
+    assert_eq!(game.moves().len(), 33);
+    assert_eq!(game.outcome(), Some(&Outcome::Win(Color::White)));
+
+    // Comments, variations and annotations are not kept in a linear game
+    let expected = r#"[Event "Casual game"]
+[Site "?"]
+[Date "1858.??.??"]
+[Round "?"]
+[White "Paul Morphy"]
+[Black "Duke Karl / Count Isouard"]
+[Result "1-0"]
+
+1. e4 e5 2. Nf3 d6 3. d4 Bg4 4. dxe5 Bxf3 5. Qxf3 dxe5 6. Bc4 Nf6 7. Qb3 Qe7
+8. Nc3 c6 9. Bg5 b5 10. Nxb5 cxb5 11. Bxb5+ Nbd7 12. O-O-O Rd8 13. Rxd7 Rxd7
+14. Rd1 Qe6 15. Bxd7+ Nxd7 16. Qb8+ Nxb8 17. Rd8# 1-0
+"#;
+    assert_eq!(game.to_portable_game_notation(), expected);
+}
+
+#[test]
+fn unbalanced_variations() {
+    // Moves after an unexpected end of a variation would otherwise be dropped from the main line
+    assert_eq!(
+        Game::portable_game_notation("1. e4 e5) 2. Nf3 *").map(|game| game.moves().len()),
+        Err(GameError::InvalidPortableGameNotation(String::from("unexpected ')'")))
+    );
+    assert_eq!(
+        Game::portable_game_notation("1. e4 e5 (1... c5 2. Nf3 *").map(|game| game.moves().len()),
+        Err(GameError::InvalidPortableGameNotation(String::from("missing ')'")))
+    );
+}
+
+#[test]
+fn portable_game_notation_from_position() {
+    // cspell:disable-next
+    let board =
+        Board::forsyth_edwards_notation("4k3/8/8/8/8/8/4P3/4K3 b - - 0 12").expect("This should be a valid record");
+    let mut game = Game::from_position(board);
+    game.set_tag("White", "Alice");
+    play(&mut game, &["Kd7", "e4"]);
+
+    let record = game.to_portable_game_notation();
+    // cspell:disable-next
+    assert!(record.contains("[FEN \"4k3/8/8/8/8/8/4P3/4K3 b - - 0 12\"]"));
+    assert!(record.contains("12... Kd7 13. e4 *"));
+
+    let game = Game::portable_game_notation(&record).expect("This should be a valid record");
+    assert_eq!(game.moves().len(), 2);
+    assert_eq!(game.tag("White"), Some("Alice"));
+}
+
+#[test]
+fn resignation() {
+    let mut game = Game::new();
+    play(&mut game, &["e4"]);
+
+    game.resign(&Color::White)
+        .expect("This should be a game in progress");
+    assert_eq!(game.outcome(), Some(&Outcome::Win(Color::Black)));
+    assert_eq!(game.termination(), Some(&Termination::Resignation));
+    assert_eq!(game.make_standard_algebraic_notation_move("e5"), Err(GameError::GameOver));
+
+    let record = game.to_portable_game_notation();
+    assert!(record.contains("[Result \"0-1\"]"));
+    assert!(record.contains("[Termination \"normal\"]"));
+
+    // Undoing a move does not take back the resignation
+    assert!(game.undo());
+    assert_eq!(game.make_standard_algebraic_notation_move("d4"), Err(GameError::GameOver));
+    assert!(game.is_over());
+    assert_eq!(game.termination(), Some(&Termination::Resignation));
+}
+
+#[test]
+fn draw_offers() {
+    let mut game = Game::new();
+    game.offer_draw(&Color::White)
+        .expect("This should be a game in progress");
+
+    // Players cannot accept their own offer
+    assert_eq!(game.accept_draw(&Color::White), Err(GameError::NoDrawOffer));
+    game.decline_draw(&Color::Black)
+        .expect("This should be an open offer");
+    assert_eq!(game.draw_offer(), None);
+
+    // Making a move declines the offer as well
+    play(&mut game, &["e4"]);
+    game.offer_draw(&Color::White)
+        .expect("This should be a game in progress");
+    play(&mut game, &["e5"]);
+    assert_eq!(game.draw_offer(), None);
+
+    game.offer_draw(&Color::White)
+        .expect("This should be a game in progress");
+    game.accept_draw(&Color::Black)
+        .expect("This should be an open offer");
+    assert_eq!(game.outcome(), Some(&Outcome::Draw));
+    assert_eq!(game.termination(), Some(&Termination::DrawAgreement));
+}
+
+#[test]
+fn draw_claims() {
+    let mut game = Game::new();
+    let knight_moves = ["Nf3", "Nf6", "Ng1", "Ng8"];
+
+    play(&mut game, &knight_moves);
+    assert_eq!(game.claim_draw(), Err(GameError::InvalidClaim(Termination::FiftyMoveRule)));
+
+    play(&mut game, &knight_moves);
+    game.claim_threefold_repetition()
+        .expect("This should be a valid claim");
+    assert_eq!(game.termination(), Some(&Termination::ThreefoldRepetition));
+
+    // cspell:disable-next
+    let board =
+        Board::forsyth_edwards_notation("4k3/8/8/8/8/8/4P3/R3K3 w - - 99 80").expect("This should be a valid record");
+    let mut game = Game::from_position(board);
+    assert_eq!(game.claim_fifty_move_rule(), Err(GameError::InvalidClaim(Termination::FiftyMoveRule)));
+
+    play(&mut game, &["Ra2"]);
+    game.claim_draw().expect("This should be a valid claim");
+    assert_eq!(game.outcome(), Some(&Outcome::Draw));
+    assert_eq!(game.termination(), Some(&Termination::FiftyMoveRule));
+}
+
+#[test]
+fn abort() {
+    let mut game = Game::new();
+    game.abort().expect("This should be a game in progress");
+
+    assert!(game.is_over());
+    assert_eq!(game.outcome(), None);
+    assert!(game
+        .to_portable_game_notation()
+        .contains("[Termination \"abandoned\"]"));
+    assert_eq!(game.abort(), Err(GameError::GameOver));
+}
+
+#[test]
+fn adjudication() {
+    // King and pawn against king, which is won for white - cspell:disable-next
+    let board =
+        Board::forsyth_edwards_notation("4k3/8/4K3/4P3/8/8/8/8 w - - 0 1").expect("This should be a valid record");
+    let mut game = Game::from_position(board);
+    assert_eq!(game.adjudicate(&KnownOutcomeAdjudicator), Ok(Some(&Outcome::Win(Color::White))));
+    assert_eq!(game.termination(), Some(&Termination::Adjudication));
+    assert_eq!(game.tag("Termination"), Some("adjudication"));
+
+    // Count material as a very simple evaluation
+    let mut game = Game::new();
+    let adjudicator = ScoreAdjudicator::new(500, |board: &Board| {
+        100 * (board.white_pieces().len() as i32 - board.black_pieces().len() as i32)
+    });
+    assert_eq!(game.adjudicate(&adjudicator), Ok(None));
+    assert!(!game.is_over());
+}
+
+#[test]
+fn read_portable_game_notation_collection() {
+    let records =
+        "[Event \"First\"]\n[Result \"1-0\"]\n\n1. e4 e5 {A comment\n[with a bracket]} 2. Qh5 Ke7 3. Qxe5# 1-0\n\n\
+                   [Event \"Second\"]\n[Result \"*\"]\n\n1. e4 e4 *\n\n\
+                   [Event \"Third\"]\n[Result \"*\"]\n\n1. d4 *\n";
+
+    let games = Game::portable_game_notation_collection(records);
+    assert_eq!(games.len(), 3);
+
+    let first = games[0].as_ref().expect("This should be a valid record");
+    assert_eq!(first.tag("Event"), Some("First"));
+    assert_eq!(first.moves().len(), 5);
+    assert!(games[1].is_err());
+    assert_eq!(
+        games[2]
+            .as_ref()
+            .expect("This should be a valid record")
+            .tag("Event"),
+        Some("Third")
+    );
+}
+
+#[test]
+fn braces_outside_comments_in_collection() {
+    let records = "[Event \"{First\"]\n[Result \"*\"]\n\n1. e4 ; {not a comment\n*\n\n\
+                   [Event \"Second\"]\n[Result \"*\"]\n\n1. d4 *\n";
+
+    let games = Game::portable_game_notation_collection(records);
+    assert_eq!(games.len(), 2);
+    assert_eq!(
+        games[1]
+            .as_ref()
+            .expect("This should be a valid record")
+            .tag("Event"),
+        Some("Second")
+    );
+}
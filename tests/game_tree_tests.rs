@@ -0,0 +1,127 @@
+use chess::board::{Board, Square};
+use chess::game::{AnnotationColor, Arrow, Game, GameError, GameTree, Highlight};
+
+// Moves of the main line in standard algebraic notation
+fn mainline(tree: &GameTree) -> Vec<String> {
+    tree.mainline()
+        .into_iter()
+        .skip(1)
+        .map(|id| {
+            tree.node(id)
+                .and_then(|node| node.played_move())
+                .map(|played_move| played_move.standard_algebraic_notation().to_string())
+                .expect("This should be a move")
+        })
+        .collect()
+}
+
+#[test]
+fn round_trip_portable_game_notation() {
+    let record = r#"[Event "Study"]
+[Site "?"]
+[Date "????.??.??"]
+[Round "?"]
+[White "?"]
+[Black "?"]
+[Result "*"]
+
+{Open games} 1. e4 e5 (1... c5 {Sicilian} 2. Nf3 (2. c3) 2... d6) (1... e6)
+2. Nf3 $1 {[%csl Gd4] [%cal Gf3e5,Rd8h4] Attacking e5} 2... Nc6 3. Bb5
+({Also good is} 3. Bc4 Bc5 $5) 3... a6 $6 *
+"#;
+    let tree = GameTree::portable_game_notation(record).expect("This should be a valid record");
+
+    assert_eq!(mainline(&tree), ["e4", "e5", "Nf3", "Nc6", "Bb5", "a6"]);
+    assert_eq!(tree.to_portable_game_notation(), record);
+}
+
+#[test]
+fn annotations() {
+    let record = "1. e4 {[%cal Ge2e4] [%clk 0:03:00] Best by test} $1 *";
+    let tree = GameTree::portable_game_notation(record).expect("This should be a valid record");
+
+    let node = tree
+        .node(tree.mainline()[1])
+        .expect("This should be a node");
+    let annotation = node.annotation();
+    assert_eq!(
+        annotation.arrows(),
+        [Arrow::new(
+            AnnotationColor::Green,
+            Square::new(5, 2),
+            Square::new(5, 4)
+        )]
+    );
+    assert_eq!(annotation.comment(), Some("[%clk 0:03:00] Best by test"));
+    assert_eq!(annotation.nags(), [1]);
+}
+
+#[test]
+fn edit_variations() {
+    let mut tree = GameTree::new();
+    let e4 = tree
+        .add_standard_algebraic_notation_move(tree.root(), "e4")
+        .expect("This should be a legal move");
+    let d4 = tree
+        .add_standard_algebraic_notation_move(tree.root(), "d4")
+        .expect("This should be a legal move");
+    let d5 = tree
+        .add_standard_algebraic_notation_move(d4, "d5")
+        .expect("This should be a legal move");
+
+    // Adding the same move again does not create a new variation
+    let again = tree
+        .add_standard_algebraic_notation_move(tree.root(), "e4")
+        .expect("This should be a legal move");
+    assert_eq!(again, e4);
+    assert_eq!(mainline(&tree), ["e4"]);
+
+    tree.promote_to_mainline(d5)
+        .expect("This should be a valid node");
+    assert_eq!(mainline(&tree), ["d4", "d5"]);
+
+    tree.annotation_mut(d5)
+        .expect("This should be a valid node")
+        .add_highlight(Highlight::new(AnnotationColor::Red, Square::new(4, 5)));
+    assert!(tree
+        .to_portable_game_notation()
+        .contains("1. d4 (1. e4) 1... d5 {[%csl Rd5]} *"));
+
+    tree.delete_subtree(d4)
+        .expect("This should be a valid node");
+    assert_eq!(mainline(&tree), ["e4"]);
+    assert!(tree.node(d5).is_none());
+    assert!(tree.delete_subtree(tree.root()).is_err());
+}
+
+#[test]
+fn convert_from_and_to_game() {
+    let game = Game::portable_game_notation("1. e4 e5 2. Nf3 (2. f4) 2... Nc6 1/2-1/2")
+        .expect("This should be a valid record");
+    let tree = GameTree::from(&game);
+
+    assert_eq!(mainline(&tree), ["e4", "e5", "Nf3", "Nc6"]);
+    assert_eq!(
+        tree.to_game()
+            .expect("This should be a valid game")
+            .to_portable_game_notation(),
+        game.to_portable_game_notation()
+    );
+}
+
+#[test]
+fn convert_to_game_after_end() {
+    // cspell:disable-next
+    let board =
+        Board::forsyth_edwards_notation("4k3/8/8/8/8/8/3p4/4K3 w - - 0 1").expect("This should be a valid record");
+    let mut tree = GameTree::from_position(board);
+
+    // Capturing the last pawn ends the game, but the tree still accepts a move after it
+    let capture = tree
+        .add_standard_algebraic_notation_move(tree.root(), "Kxd2")
+        .expect("This should be a legal move");
+    tree.add_standard_algebraic_notation_move(capture, "Ke7")
+        .expect("This should be a legal move");
+
+    assert_eq!(tree.to_game().err(), Some(GameError::GameOver));
+}
@@ -23,7 +23,7 @@ fn valid_record_end_game() {
             assert_eq!(board.legal_moves().len(), 45);
         }
         Err(_) => {
-            assert!(false);
+            panic!("This should be a valid record");
         }
     };
 }
@@ -116,3 +116,16 @@ fn valid_record_no_captures() {
     // Should still have 16 black pieces
     assert_eq!(board.black_pieces().len(), 16);
 }
+
+#[test]
+fn promoted_marker_at_start_of_rank() {
+    // Since FEN break the spell checker, turn it of for the next line - cspell:disable-next
+    let error =
+        Board::forsyth_edwards_notation("~7/8/8/8/8/8/8/4K2k w - - 0 1").expect_err("This should be an invalid record");
+    assert_eq!(error, ParseError::InvalidPiece('~'));
+
+    // A tilde has to follow the piece it marks as promoted - cspell:disable-next
+    let error = Board::forsyth_edwards_notation("rnbqkbnr/~ppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1")
+        .expect_err("This should be an invalid record");
+    assert_eq!(error, ParseError::InvalidPiece('~'));
+}
@@ -0,0 +1,141 @@
+use chess::board::{Board, Square};
+use chess::pawns::{self, PawnCache, PawnStructure};
+use chess::piece::Color;
+
+fn analyze(record: &str) -> PawnStructure {
+    PawnStructure::new(&Board::forsyth_edwards_notation(record).expect("This should be a valid record"))
+}
+
+#[test]
+fn starting_position() {
+    let structure = PawnStructure::new(&Board::starting_position());
+
+    assert_eq!(structure.pawns().len(), 16);
+    for pawn in structure.pawns() {
+        assert!(!pawn.is_passed());
+        assert!(!pawn.is_isolated());
+        assert!(!pawn.is_doubled());
+        assert!(!pawn.is_backward());
+        assert!(pawn.is_connected());
+        assert_eq!(pawn.island(), 0);
+    }
+    assert_eq!(structure.islands(&Color::White).len(), 1);
+    assert!(structure.chains(&Color::White).is_empty());
+}
+
+#[test]
+fn passed_isolated_and_doubled() {
+    // White: passed and protected d5 defended by c4, doubled isolated pawns on the g-file and an isolated a2
+    // Black: the a7 pawn is not passed because of the a2 pawn
+    // cspell:disable-next
+    let structure = analyze("4k3/p7/8/3P4/2P5/6P1/P5P1/4K3 w - - 0 1");
+    let pawn = |file, rank| {
+        structure
+            .pawn(&Square::new(file, rank))
+            .expect("This should be a pawn")
+    };
+
+    assert!(pawn(4, 5).is_passed());
+    assert!(pawn(4, 5).is_protected_passed());
+    assert!(pawn(3, 4).is_passed());
+    assert!(!pawn(3, 4).is_protected_passed());
+    assert!(pawn(3, 4).is_connected());
+
+    assert!(pawn(7, 2).is_doubled());
+    assert!(pawn(7, 3).is_doubled());
+    assert!(pawn(7, 2).is_isolated());
+    assert!(!pawn(1, 2).is_passed());
+    assert!(pawn(1, 2).is_isolated());
+    assert!(!pawn(1, 7).is_passed());
+
+    assert_eq!(
+        structure.islands(&Color::White),
+        [
+            vec![Square::new(1, 2)],
+            vec![Square::new(3, 4), Square::new(4, 5)],
+            vec![Square::new(7, 2), Square::new(7, 3)],
+        ]
+    );
+    assert_eq!(structure.chains(&Color::White), [vec![Square::new(3, 4), Square::new(4, 5)]]);
+}
+
+#[test]
+fn backward_and_candidate() {
+    // The d3 pawn cannot be defended and d4 is attacked by the pawn on e5
+    // cspell:disable-next
+    let structure = analyze("4k3/8/8/4p3/2P5/3P4/8/4K3 w - - 0 1");
+    let pawn = |file, rank| {
+        structure
+            .pawn(&Square::new(file, rank))
+            .expect("This should be a pawn")
+    };
+    assert!(pawn(4, 3).is_backward());
+    assert!(!pawn(3, 4).is_backward());
+
+    // The c-pawn has no pawn in front of it and one helper against one sentry
+    // cspell:disable-next
+    let structure = analyze("4k3/8/1p6/8/1PP5/8/8/4K3 w - - 0 1");
+    let pawn = |file, rank| {
+        structure
+            .pawn(&Square::new(file, rank))
+            .expect("This should be a pawn")
+    };
+    assert!(pawn(3, 4).is_candidate());
+    assert!(!pawn(2, 4).is_candidate());
+}
+
+#[test]
+fn rule_of_the_square() {
+    // cspell:disable-next
+    let board =
+        Board::forsyth_edwards_notation("8/8/8/k3P3/8/8/8/4K3 b - - 0 1").expect("This should be a valid record");
+    assert!(!pawns::is_unstoppable(&board, &Square::new(5, 5)));
+
+    // cspell:disable-next
+    let board =
+        Board::forsyth_edwards_notation("8/8/8/k3P3/8/8/8/4K3 w - - 0 1").expect("This should be a valid record");
+    assert!(pawns::is_unstoppable(&board, &Square::new(5, 5)));
+
+    // A pawn on its starting rank can move two squares
+    // cspell:disable-next
+    let board =
+        Board::forsyth_edwards_notation("8/8/k7/8/8/8/4P3/4K3 b - - 0 1").expect("This should be a valid record");
+    assert!(!pawns::is_unstoppable(&board, &Square::new(5, 2)));
+    // cspell:disable-next
+    let board =
+        Board::forsyth_edwards_notation("k7/8/8/8/8/8/7P/4K3 b - - 0 1").expect("This should be a valid record");
+    assert!(pawns::is_unstoppable(&board, &Square::new(8, 2)));
+}
+
+#[test]
+fn pawn_cache() {
+    let mut cache = PawnCache::new(2);
+    let board = Board::starting_position();
+    let mut moved = board.clone();
+    moved
+        .make_move(
+            moved
+                .parse_standard_algebraic_notation("Nf3")
+                .expect("This should be a legal move"),
+        )
+        .expect("This should be a legal move");
+
+    // Moving a piece does not change the pawn hash
+    assert_eq!(pawns::pawn_hash(&board), pawns::pawn_hash(&moved));
+    assert_eq!(cache.get(&board), &PawnStructure::new(&board));
+    cache.get(&moved);
+    assert_eq!(cache.len(), 1);
+
+    // cspell:disable-next
+    let other =
+        Board::forsyth_edwards_notation("4k3/8/8/8/8/8/4P3/4K3 w - - 0 1").expect("This should be a valid record");
+    assert_ne!(pawns::pawn_hash(&board), pawns::pawn_hash(&other));
+    cache.get(&other);
+    assert_eq!(cache.len(), 2);
+
+    // The cache is cleared when it is full
+    cache.get(
+        &Board::forsyth_edwards_notation("4k3/8/8/8/8/8/3P4/4K3 w - - 0 1").expect("This should be a valid record"),
+    );
+    assert_eq!(cache.len(), 1);
+}
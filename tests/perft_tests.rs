@@ -0,0 +1,54 @@
+use chess::board::Board;
+
+// Source of all positions and node counts: https://www.chessprogramming.org/Perft_Results
+
+#[test]
+fn starting_position() {
+    let board = Board::starting_position();
+
+    assert_eq!(board.perft(1), 20);
+    assert_eq!(board.perft(2), 400);
+    assert_eq!(board.perft(3), 8_902);
+}
+
+#[test]
+fn kiwipete() {
+    // Since FEN break the spell checker, turn it of for the next line - cspell:disable-next
+    let record = "r3k2r/p1ppqpb1/bn2pnp1/3PN3/1p2P3/2N2Q1p/PPPBBPPP/R3K2R w KQkq - 0 1";
+    let board = Board::forsyth_edwards_notation(record).expect("This should be a valid record");
+
+    assert_eq!(board.perft(1), 48);
+    assert_eq!(board.perft(2), 2_039);
+}
+
+#[test]
+fn en_passant_pins() {
+    // Since FEN break the spell checker, turn it of for the next line - cspell:disable-next
+    let record = "8/2p5/3p4/KP5r/1R3p1k/8/4P1P1/8 w - - 0 1";
+    let board = Board::forsyth_edwards_notation(record).expect("This should be a valid record");
+
+    assert_eq!(board.perft(1), 14);
+    assert_eq!(board.perft(2), 191);
+    assert_eq!(board.perft(3), 2_812);
+}
+
+#[test]
+fn promotions_and_castling() {
+    // Since FEN break the spell checker, turn it of for the next line - cspell:disable-next
+    let record = "r3k2r/Pppp1ppp/1b3nbN/nP6/BBP1P3/q4N2/Pp1P2PP/R2Q1RK1 w kq - 0 1";
+    let board = Board::forsyth_edwards_notation(record).expect("This should be a valid record");
+
+    assert_eq!(board.perft(1), 6);
+    assert_eq!(board.perft(2), 264);
+    assert_eq!(board.perft(3), 9_467);
+}
+
+#[test]
+fn discovered_checks() {
+    // Since FEN break the spell checker, turn it of for the next line - cspell:disable-next
+    let record = "rnbq1k1r/pp1Pbppp/2p5/8/2B5/8/PPP1NnPP/RNBQK2R w KQ - 1 8";
+    let board = Board::forsyth_edwards_notation(record).expect("This should be a valid record");
+
+    assert_eq!(board.perft(1), 44);
+    assert_eq!(board.perft(2), 1_486);
+}
@@ -0,0 +1,86 @@
+use chess::board::{Board, Square};
+use chess::piece::Color;
+
+#[test]
+fn absolute_pins() {
+    // Knight on e4 pinned by the rook on e8, bishop on d2 pinned by the bishop on a5
+    // cspell:disable-next
+    let board =
+        Board::forsyth_edwards_notation("4r1k1/8/8/b7/4N3/8/3B4/4K3 w - - 0 1").expect("This should be a valid record");
+
+    let mut pins = board.absolute_pins(&Color::White);
+    pins.sort_by_key(|pin| pin.pinned().file());
+    assert_eq!(pins.len(), 2);
+
+    assert_eq!(pins[0].pinned(), &Square::new(4, 2));
+    assert_eq!(pins[0].pinner(), &Square::new(1, 5));
+    assert_eq!(pins[0].target(), &Square::new(5, 1));
+    assert_eq!(
+        pins[0].line(),
+        [
+            Square::new(4, 2),
+            Square::new(3, 3),
+            Square::new(2, 4),
+            Square::new(1, 5)
+        ]
+    );
+
+    assert_eq!(pins[1].pinned(), &Square::new(5, 4));
+    assert_eq!(pins[1].pinner(), &Square::new(5, 8));
+
+    assert!(board.is_pinned(&Square::new(5, 4)));
+    assert!(!board.is_pinned(&Square::new(5, 1)));
+    assert!(board.relative_pins(&Color::White).is_empty());
+    assert!(board.pins(&Color::Black).is_empty());
+}
+
+#[test]
+fn relative_pins() {
+    // Knight on c6 pinned to the queen on e8
+    // cspell:disable-next
+    let board =
+        Board::forsyth_edwards_notation("r3q1k1/1b6/2n5/8/B7/8/8/K7 w - - 0 1").expect("This should be a valid record");
+
+    let pins = board.relative_pins(&Color::Black);
+    assert_eq!(pins.len(), 1);
+    assert_eq!(pins[0].pinned(), &Square::new(3, 6));
+    assert_eq!(pins[0].target(), &Square::new(5, 8));
+    assert!(board.absolute_pins(&Color::Black).is_empty());
+    assert!(!board.is_pinned(&Square::new(3, 6)));
+}
+
+#[test]
+fn checkers() {
+    assert!(Board::starting_position().checkers().is_empty());
+
+    // Double check by the rook and the knight
+    // cspell:disable-next
+    let board =
+        Board::forsyth_edwards_notation("4k3/8/3N4/8/8/8/8/4RK2 b - - 0 1").expect("This should be a valid record");
+    let mut checkers = board.checkers();
+    checkers.sort_by_key(|square| square.file());
+    assert_eq!(checkers, [Square::new(4, 6), Square::new(5, 1)]);
+}
+
+#[test]
+fn discovered_attacks() {
+    // Bishop on e4 uncovers a check by the rook, knight on d4 uncovers an attack on the queen
+    // cspell:disable-next
+    let board =
+        Board::forsyth_edwards_notation("4k3/6q1/8/8/3NB3/8/8/B3R2K w - - 0 1").expect("This should be a valid record");
+
+    let checks = board.discovered_checks(&Color::White);
+    assert_eq!(checks.len(), 1);
+    assert_eq!(checks[0].piece(), &Square::new(5, 4));
+    assert_eq!(checks[0].attacker(), &Square::new(5, 1));
+    assert_eq!(checks[0].target(), &Square::new(5, 8));
+
+    let mut attacks = board.discovered_attacks(&Color::White);
+    attacks.sort_by_key(|attack| attack.piece().file());
+    assert_eq!(attacks.len(), 2);
+    assert_eq!(attacks[0].piece(), &Square::new(4, 4));
+    assert_eq!(attacks[0].target(), &Square::new(7, 7));
+    assert!(!attacks[0].is_check());
+
+    assert!(board.discovered_attacks(&Color::Black).is_empty());
+}
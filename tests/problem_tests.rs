@@ -0,0 +1,99 @@
+use chess::board::Board;
+use chess::problem::{self, ProblemType, Variation};
+
+fn notation(variations: &[Variation]) -> Vec<&str> {
+    variations
+        .iter()
+        .map(|variation| variation.played_move().standard_algebraic_notation())
+        .collect()
+}
+
+#[test]
+fn directmate_in_one() {
+    // cspell:disable-next
+    let board =
+        Board::forsyth_edwards_notation("6k1/5ppp/8/8/8/8/8/R5K1 w - - 0 1").expect("This should be a valid record");
+    let solution = problem::solve(&board, &ProblemType::Directmate, 1);
+
+    assert!(solution.is_sound());
+    assert_eq!(notation(solution.keys()), ["Ra8#"]);
+    assert!(solution.keys()[0].replies().is_empty());
+}
+
+#[test]
+fn directmate_in_two() {
+    // cspell:disable-next
+    let board =
+        Board::forsyth_edwards_notation("k7/8/2K5/8/8/8/8/7R w - - 0 1").expect("This should be a valid record");
+    let solution = problem::solve(&board, &ProblemType::Directmate, 2);
+
+    // Two keys, so the problem is cooked
+    assert!(!solution.is_sound());
+    let keys = notation(solution.keys());
+    assert_eq!(keys.len(), 2);
+    assert!(keys.contains(&"Kb6") && keys.contains(&"Kc7"));
+
+    let key = solution
+        .keys()
+        .iter()
+        .find(|key| key.played_move().standard_algebraic_notation() == "Kb6")
+        .expect("Kb6 is a key");
+    assert_eq!(notation(key.threats()), ["Rh8#"]);
+    assert_eq!(notation(key.replies()), ["Kb8"]);
+    assert_eq!(notation(key.replies()[0].replies()), ["Rh8#"]);
+
+    // Checking the king lets it escape to the other corner
+    let tries: Vec<(&str, &str)> = solution
+        .tries()
+        .iter()
+        .map(|try_move| {
+            (
+                try_move.played_move().standard_algebraic_notation(),
+                try_move.refutation().standard_algebraic_notation(),
+            )
+        })
+        .collect();
+    assert!(tries.contains(&("Rh8+", "Ka7")));
+}
+
+#[test]
+fn no_solution() {
+    let solution = problem::solve(&Board::starting_position(), &ProblemType::Directmate, 1);
+    assert!(solution.keys().is_empty());
+
+    // cspell:disable-next
+    let board =
+        Board::forsyth_edwards_notation("6k1/5ppp/8/8/8/8/8/R5K1 w - - 0 1").expect("This should be a valid record");
+    assert!(problem::solve(&board, &ProblemType::Directmate, 0)
+        .keys()
+        .is_empty());
+}
+
+#[test]
+fn selfmate() {
+    // After the key black can only move the b-pawn, which uncovers the bishop on a8
+    // cspell:disable-next
+    let board =
+        Board::forsyth_edwards_notation("b5Bk/1p6/6PP/8/8/8/7P/6BK w - - 0 1").expect("This should be a valid record");
+    let solution = problem::solve(&board, &ProblemType::Selfmate, 1);
+
+    assert!(solution.is_sound());
+    assert_eq!(notation(solution.keys()), ["Bh7"]);
+    let mut replies = notation(solution.keys()[0].replies());
+    replies.sort();
+    assert_eq!(replies, ["b5#", "b6#"]);
+}
+
+#[test]
+fn helpmate() {
+    // Black helps by moving into the corner
+    // cspell:disable-next
+    let board =
+        Board::forsyth_edwards_notation("7k/5K2/8/8/8/8/8/6R1 b - - 0 1").expect("This should be a valid record");
+    let solution = problem::solve(&board, &ProblemType::Helpmate, 1);
+
+    assert!(solution.is_sound());
+    assert_eq!(notation(solution.keys()), ["Kh7"]);
+    assert_eq!(notation(solution.keys()[0].replies()), ["Rh1#"]);
+    assert!(solution.tries().is_empty());
+}
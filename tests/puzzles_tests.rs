@@ -0,0 +1,188 @@
+use chess::board::Board;
+use chess::game::Game;
+use chess::puzzles::{self, Attempt, AttemptStatus, Phase, Puzzle, PuzzleError, PuzzleGenerator, TrainingProfile};
+use chess::tactics::Motif;
+
+// cspell:disable-next
+const SCHOLARS_MATE: &str = "[Event \"Casual game\"]\n[Site \"https://example.com/1\"]\n[Result \"1-0\"]\n\n\
+                             1. e4 e5 2. Bc4 Nc6 3. Qh5 Nf6 4. Qxf7# 1-0\n";
+
+#[test]
+fn fork() {
+    // The knight forks the king and the rook on c7
+    // cspell:disable-next
+    let board =
+        Board::forsyth_edwards_notation("r3k3/pp6/8/1N6/8/8/PP6/4K3 w - - 0 1").expect("This should be a valid record");
+
+    let puzzle = PuzzleGenerator::new()
+        .depth(1)
+        .puzzle(&board)
+        .expect("This should be a puzzle");
+    let solution = puzzle.uci_solution();
+    assert_eq!(solution.len(), 3);
+    assert_eq!(solution[0], "b5c7");
+    assert_eq!(solution[2], "c7a8");
+    assert!(puzzle.motifs().contains(&Motif::Fork));
+    assert_eq!(puzzle.phase(), &Phase::Endgame);
+    assert!(!puzzle.is_mate());
+}
+
+#[test]
+fn at_least_one_move() {
+    // cspell:disable-next
+    let board =
+        Board::forsyth_edwards_notation("r3k3/pp6/8/1N6/8/8/PP6/4K3 w - - 0 1").expect("This should be a valid record");
+
+    let puzzle = PuzzleGenerator::new()
+        .depth(1)
+        .max_moves(0)
+        .puzzle(&board)
+        .expect("This should be a puzzle");
+    assert_eq!(puzzle.uci_solution(), ["b5c7"]);
+}
+
+#[test]
+fn no_puzzle_without_winning_move() {
+    assert!(PuzzleGenerator::new()
+        .depth(1)
+        .puzzle(&Board::starting_position())
+        .is_none());
+}
+
+#[test]
+fn find_in_game() {
+    let game = Game::portable_game_notation(SCHOLARS_MATE).expect("This should be a valid record");
+    let found = PuzzleGenerator::new().depth(1).find(&game);
+    assert_eq!(found.len(), 1);
+
+    let puzzle = &found[0];
+    assert_eq!(puzzle.ply(), 6);
+    assert_eq!(puzzle.uci_solution(), ["h5f7"]);
+    assert!(puzzle.is_mate());
+    assert_eq!(puzzle.source(), Some("https://example.com/1"));
+    assert_eq!(&puzzle.themes()[..2], ["opening", "mateIn1"]);
+}
+
+#[test]
+fn write_csv_and_json_lines() {
+    let found = PuzzleGenerator::new()
+        .depth(1)
+        .find_in_collection(SCHOLARS_MATE);
+
+    let csv = puzzles::to_csv(&found);
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(lines[0], puzzles::CSV_HEADER);
+    // cspell:disable-next
+    assert!(lines[1].starts_with("r1bqkb1r/pppp1ppp/2n2n2/4p2Q/2B1P3/8/PPPP1PPP/RNB1K1NR w KQkq - 4 4,h5f7,"));
+    assert!(lines[1].ends_with(",https://example.com/1,6"));
+
+    let json_lines = puzzles::to_json_lines(&found);
+    assert_eq!(json_lines.lines().count(), 1);
+    assert!(json_lines.contains("\"moves\":[\"h5f7\"]"));
+    assert!(json_lines.contains("\"source\":\"https://example.com/1\""));
+}
+
+#[test]
+fn read_csv() {
+    // cspell:disable-next
+    let record = "FEN,Moves,Difficulty,Themes,Source,Ply\n\
+                  r3k3/pp6/8/1N6/8/8/PP6/4K3 w - - 0 1,b5c7 e8d7 c7a8,1150,endgame fork,\"Club, round 2\",14\n\
+                  4k3/8/8/8/8/8/8/4K3 w - - 0 1,e1e3,800,endgame,,0\n";
+
+    let read = puzzles::read_csv(record);
+    assert_eq!(read.len(), 2);
+    assert!(read[1].is_err());
+
+    let puzzle = read[0].as_ref().expect("This should be a valid record");
+    assert_eq!(puzzle.uci_solution(), ["b5c7", "e8d7", "c7a8"]);
+    assert_eq!(puzzle.difficulty(), 1150);
+    assert_eq!(puzzle.motifs(), [Motif::Fork]);
+    assert_eq!(puzzle.source(), Some("Club, round 2"));
+    assert_eq!(puzzle.ply(), 14);
+
+    // Writing and reading the puzzle again gives the same record
+    let written = puzzle.to_csv_record();
+    let read_again = Puzzle::from_csv_record(&written).expect("This should be a valid record");
+    assert_eq!(read_again.to_csv_record(), written);
+}
+
+#[test]
+fn attempt() {
+    // cspell:disable-next
+    let board =
+        Board::forsyth_edwards_notation("r3k3/pp6/8/1N6/8/8/PP6/4K3 w - - 0 1").expect("This should be a valid record");
+    let puzzle = Puzzle::new(board, &["Nc7+", "Kd7", "Nxa8"]).expect("This should be a valid solution");
+
+    let mut attempt = Attempt::new(&puzzle);
+    match attempt.play("b5c7") {
+        Ok(AttemptStatus::Correct(reply)) => assert_eq!(reply.standard_algebraic_notation(), "Kd7"),
+        status => panic!("expected a correct move, got {:?}", status),
+    }
+    assert!(matches!(attempt.play("Nxa8"), Ok(AttemptStatus::Solved)));
+    assert!(attempt.is_solved());
+
+    let mut attempt = Attempt::new(&puzzle);
+    match attempt.play("a4") {
+        Ok(AttemptStatus::Incorrect(expected)) => assert_eq!(expected.standard_algebraic_notation(), "Nc7+"),
+        status => panic!("expected an incorrect move, got {:?}", status),
+    }
+    assert!(attempt.is_finished() && !attempt.is_solved());
+}
+
+#[test]
+fn alternative_mate() {
+    // Both rooks can mate on the back rank, while the solution only has one of them
+    // cspell:disable-next
+    let board =
+        Board::forsyth_edwards_notation("6k1/5ppp/8/8/8/8/8/R3R1K1 w - - 0 1").expect("This should be a valid record");
+    let puzzle = Puzzle::new(board, &["Ra8#"]).expect("This should be a valid solution");
+    assert!(puzzle.is_mate());
+
+    let mut attempt = Attempt::new(&puzzle);
+    assert!(matches!(attempt.play("Re8#"), Ok(AttemptStatus::Solved)));
+}
+
+#[test]
+fn solution_ends_with_solver() {
+    let error = Puzzle::new(Board::starting_position(), &["e4", "e5"]).expect_err("This should be an invalid solution");
+    assert!(matches!(error, PuzzleError::InvalidRecord(_)));
+}
+
+#[test]
+fn training_profile() {
+    let puzzle = Puzzle::new(Board::starting_position(), &["e4"]).expect("This should be a valid solution");
+    let mut profile = TrainingProfile::new();
+    assert_eq!(profile.rating(), 1500.0);
+
+    profile.record(&puzzle, true);
+    let rating = profile.rating();
+    assert!(rating > 1500.0);
+    profile.record(&puzzle, false);
+    assert!(profile.rating() < rating);
+
+    let read = TrainingProfile::from_csv(&profile.to_csv()).expect("This should be a valid profile");
+    assert_eq!(read.history().len(), 2);
+    assert!(read.history()[0].is_solved());
+    assert!((read.rating() - profile.rating()).abs() < 0.1);
+}
+
+#[test]
+fn train() {
+    // cspell:disable-next
+    let board =
+        Board::forsyth_edwards_notation("r3k3/pp6/8/1N6/8/8/PP6/4K3 w - - 0 1").expect("This should be a valid record");
+    let puzzle = Puzzle::new(board, &["Nc7+", "Kd7", "Nxa8"]).expect("This should be a valid solution");
+    let mut profile = TrainingProfile::new();
+
+    let mut input = "hint\nNc7+\nxyz\nc7a8\n".as_bytes();
+    let mut output = Vec::new();
+    puzzles::train(&[puzzle], &mut profile, &mut input, &mut output).expect("This should write to the output");
+
+    let output = String::from_utf8(output).expect("This should be valid text");
+    assert!(output.contains("White to move"));
+    assert!(output.contains("Move the piece on b5."));
+    assert!(output.contains("The opponent plays Kd7."));
+    assert!(output.contains("illegal move: xyz"));
+    assert!(output.contains("Solved!"));
+    assert_eq!(profile.history().len(), 1);
+}
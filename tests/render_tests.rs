@@ -0,0 +1,209 @@
+use chess::board::{Board, Square};
+use chess::clock::{Clock, TimeControl};
+use chess::game::{AnnotationColor, Arrow, Game, GameTree, Highlight};
+use chess::piece::Color;
+use chess::render::{Charset, Layout, PieceSet, SvgRenderer, TextRenderer, Theme};
+use std::time::Duration;
+
+// cspell:disable-next
+const POSITION: &str = "4k3/8/8/8/8/8/4P3/4K2R w K - 0 1";
+
+#[test]
+fn default_is_display() {
+    // Same board as written by Display before the renderer existed
+    let expected = "  ┏━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┯━━━┓\n\
+                    8 ┃ ♜ │ ♞ │ ♝ │ ♛ │ ♚ │ ♝ │ ♞ │ ♜ ┃\n\
+                    \x20 ┠───┼───┼───┼───┼───┼───┼───┼───┨\n\
+                    7 ┃ ♟ │ ♟ │ ♟ │ ♟ │ ♟ │ ♟ │ ♟ │ ♟ ┃\n\
+                    \x20 ┠───┼───┼───┼───┼───┼───┼───┼───┨\n\
+                    6 ┃   │   │   │   │   │   │   │   ┃\n\
+                    \x20 ┠───┼───┼───┼───┼───┼───┼───┼───┨\n\
+                    5 ┃   │   │   │   │   │   │   │   ┃\n\
+                    \x20 ┠───┼───┼───┼───┼───┼───┼───┼───┨\n\
+                    4 ┃   │   │   │   │   │   │   │   ┃\n\
+                    \x20 ┠───┼───┼───┼───┼───┼───┼───┼───┨\n\
+                    3 ┃   │   │   │   │   │   │   │   ┃\n\
+                    \x20 ┠───┼───┼───┼───┼───┼───┼───┼───┨\n\
+                    2 ┃ ♙ │ ♙ │ ♙ │ ♙ │ ♙ │ ♙ │ ♙ │ ♙ ┃\n\
+                    \x20 ┠───┼───┼───┼───┼───┼───┼───┼───┨\n\
+                    1 ┃ ♖ │ ♘ │ ♗ │ ♕ │ ♔ │ ♗ │ ♘ │ ♖ ┃\n\
+                    \x20 ┗━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┷━━━┛\n\
+                    \x20   A   B   C   D   E   F   G   H\n";
+
+    let board = Board::starting_position();
+    assert_eq!(board.to_string(), expected);
+    assert_eq!(TextRenderer::new().render(&board), expected);
+}
+
+#[test]
+fn compact_ascii() {
+    let board = Board::forsyth_edwards_notation(POSITION).expect("This should be a valid record");
+    let text = TextRenderer::new()
+        .charset(Charset::Ascii)
+        .layout(Layout::Compact)
+        .render(&board);
+
+    assert_eq!(
+        text,
+        "8 . . . . k . . .\n\
+         7 . . . . . . . .\n\
+         6 . . . . . . . .\n\
+         5 . . . . . . . .\n\
+         4 . . . . . . . .\n\
+         3 . . . . . . . .\n\
+         2 . . . . P . . .\n\
+         1 . . . . K . . R\n\
+        \x20 a b c d e f g h\n"
+    );
+}
+
+#[test]
+fn flipped_without_coordinates() {
+    let board = Board::forsyth_edwards_notation(POSITION).expect("This should be a valid record");
+    let text = TextRenderer::new()
+        .orientation(Color::Black)
+        .charset(Charset::Ascii)
+        .layout(Layout::Compact)
+        .coordinates(false)
+        .render(&board);
+
+    let lines: Vec<&str> = text.lines().collect();
+    assert_eq!(lines.len(), 8);
+    assert_eq!(lines[0], " R . . K . . . .");
+    assert_eq!(lines[7], " . . . k . . . .");
+}
+
+#[test]
+fn highlights() {
+    let board = Board::forsyth_edwards_notation(POSITION).expect("This should be a valid record");
+    let renderer = TextRenderer::new()
+        .charset(Charset::Ascii)
+        .highlight(Square::new(5, 2))
+        .highlight(Square::new(8, 1));
+
+    let boxed = renderer.clone().render(&board);
+    assert!(boxed.contains("2 |   |   |   |   |[P]|   |   |   |\n"));
+
+    let compact = renderer.layout(Layout::Compact).render(&board);
+    assert!(compact.contains("2 . . . .[P]. . .\n"));
+    assert!(compact.contains("1 . . . . K . .[R]\n"));
+}
+
+#[test]
+fn ascii_boxed() {
+    let text = TextRenderer::new()
+        .charset(Charset::Ascii)
+        .render(&Board::starting_position());
+
+    assert!(text.is_ascii());
+    assert!(text.starts_with("  +---+---+---+---+---+---+---+---+\n8 | r | n | b | q | k | b | n | r |\n"));
+}
+
+#[test]
+fn colors() {
+    let board = Board::starting_position();
+    let text = TextRenderer::new()
+        .layout(Layout::Compact)
+        .colors(true)
+        .render(&board);
+
+    // Squares on the top left and bottom right are light
+    assert!(text.starts_with("8 \x1b[30;48;5;180m ♜ \x1b[0m\x1b[30;48;5;137m ♞ \x1b[0m"));
+    assert!(text.contains("\x1b[30;48;5;180m ♖ \x1b[0m\n"));
+
+    // The last background of a square replaces the highlight and the earlier backgrounds
+    let text = TextRenderer::new()
+        .layout(Layout::Compact)
+        .colors(true)
+        .highlight(Square::new(1, 8))
+        .background(Square::new(1, 8), 68)
+        .background(Square::new(1, 8), 167)
+        .render(&board);
+    assert!(text.starts_with("8 \x1b[30;48;5;167m ♜ \x1b[0m"));
+}
+
+#[test]
+fn panel() {
+    let board = Board::forsyth_edwards_notation(POSITION).expect("This should be a valid record");
+    let clock = Clock::new(TimeControl::sudden_death(Duration::from_secs(300)));
+    let text = TextRenderer::new()
+        .layout(Layout::Compact)
+        .panel(true)
+        .clock(&clock)
+        .render(&board);
+
+    let lines: Vec<&str> = text.lines().collect();
+    assert!(lines[0].ends_with("   White to move"));
+    assert!(lines[1].ends_with("   Castling: K"));
+    assert!(lines[2].ends_with("   White: 5:00"));
+    assert!(lines[3].ends_with("   Black: 5:00"));
+}
+
+#[test]
+fn svg_board() {
+    let svg = SvgRenderer::new()
+        .size(400)
+        .render(&Board::starting_position());
+
+    assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 360 360\" width=\"400\""));
+    assert!(svg.ends_with("</svg>\n"));
+    assert_eq!(svg.matches("<rect").count(), 64);
+    assert_eq!(svg.matches("♟</text>").count(), 16);
+    // Square a8 in the top left corner is light
+    assert!(svg.contains("<rect x=\"0\" y=\"0\" width=\"45\" height=\"45\" fill=\"#f0d9b5\"/>"));
+    assert!(svg.contains(">a</text>"));
+    assert!(svg.contains(">8</text>"));
+}
+
+#[test]
+fn svg_options() {
+    let board = Board::starting_position();
+    let svg = SvgRenderer::new()
+        .orientation(Color::Black)
+        .theme(Theme::Blue)
+        .piece_set(PieceSet::Letter)
+        .coordinates(false)
+        .render(&board);
+
+    // Square h1 in the top left corner is light
+    assert!(svg.contains("<rect x=\"0\" y=\"0\" width=\"45\" height=\"45\" fill=\"#dee3e6\"/>"));
+    assert_eq!(svg.matches("<circle").count(), 32);
+    assert!(svg.contains(">N</text>"));
+    assert!(!svg.contains("font-size=\"11.25\""));
+}
+
+#[test]
+fn svg_markers() {
+    // cspell:disable-next
+    let board =
+        Board::forsyth_edwards_notation("4k3/8/8/8/8/8/8/4R1K1 b - - 1 1").expect("This should be a valid record");
+    let svg = SvgRenderer::new()
+        .last_move(Square::new(1, 1), Square::new(5, 1))
+        .arrow(Arrow::new(AnnotationColor::Green, Square::new(5, 8), Square::new(4, 8)))
+        .highlight(Highlight::new(AnnotationColor::Red, Square::new(5, 1)))
+        .render(&board);
+
+    assert_eq!(svg.matches("fill=\"#9bc700\" fill-opacity=\"0.4\"").count(), 2);
+    assert!(svg.contains("fill=\"#882020\" fill-opacity=\"0.5\""));
+    assert!(svg.contains("<polygon"));
+    // The king on e8 is in check
+    assert!(svg.contains("<circle cx=\"202.5\" cy=\"22.5\" r=\"22.5\" fill=\"url(#check)\"/>"));
+
+    let without_check = SvgRenderer::new().check(false).render(&board);
+    assert!(!without_check.contains("url(#check)\"/>"));
+}
+
+#[test]
+fn svg_frames() {
+    let game = Game::portable_game_notation("1. e4 e5 2. Qh5 *").expect("This should be a valid record");
+    let frames = SvgRenderer::new().render_game(&game);
+    assert_eq!(frames.len(), 4);
+    assert!(!frames[0].contains("#9bc700"));
+    assert!(frames[3].contains("#9bc700"));
+
+    let tree = GameTree::portable_game_notation("1. e4 { [%cal Gd2d4] } e5 *").expect("This should be a valid record");
+    let frames = SvgRenderer::new().render_tree(&tree);
+    assert_eq!(frames.len(), 3);
+    assert!(frames[1].contains("<polygon"));
+    assert!(!frames[2].contains("<polygon"));
+}
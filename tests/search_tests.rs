@@ -0,0 +1,68 @@
+use chess::board::{Board, Square};
+use chess::search::{Score, Searcher};
+
+#[test]
+fn mate_in_one() {
+    // cspell:disable-next
+    let board =
+        Board::forsyth_edwards_notation("6k1/5ppp/8/8/8/8/8/R5K1 w - - 0 1").expect("This should be a valid record");
+
+    let result = Searcher::new().search(&board, 2);
+    assert_eq!(result.score(), &Score::Mate(1));
+    let best_move = result.best_move().expect("White has legal moves");
+    assert_eq!(best_move.destination_square(), &Square::new(1, 8));
+}
+
+#[test]
+fn mated() {
+    // Black is checkmated, so there is no best move
+    // cspell:disable-next
+    let board =
+        Board::forsyth_edwards_notation("R5k1/5ppp/8/8/8/8/8/6K1 b - - 1 1").expect("This should be a valid record");
+
+    let result = Searcher::new().search(&board, 2);
+    assert_eq!(result.score(), &Score::Mate(0));
+    assert!(result.best_move().is_none());
+}
+
+#[test]
+fn stalemate_is_a_draw() {
+    // cspell:disable-next
+    let board =
+        Board::forsyth_edwards_notation("7k/5Q2/6K1/8/8/8/8/8 b - - 0 1").expect("This should be a valid record");
+
+    assert_eq!(Searcher::new().search(&board, 1).score(), &Score::Centipawns(0));
+}
+
+#[test]
+fn captures_hanging_queen() {
+    // cspell:disable-next
+    let board =
+        Board::forsyth_edwards_notation("4k3/8/8/3q4/8/8/3R4/4K3 w - - 0 1").expect("This should be a valid record");
+
+    let result = Searcher::new().search(&board, 1);
+    let best_move = result.best_move().expect("White has legal moves");
+    assert_eq!(best_move.destination_square(), &Square::new(4, 5));
+    assert!(result.score().centipawns() > 300);
+}
+
+#[test]
+fn search_moves() {
+    // cspell:disable-next
+    let board =
+        Board::forsyth_edwards_notation("6k1/5ppp/8/8/8/8/8/R5K1 w - - 0 1").expect("This should be a valid record");
+
+    let results = Searcher::new().search_moves(&board, 1);
+    assert_eq!(results.len(), board.legal_moves().len());
+    assert_eq!(results[0].score(), &Score::Mate(1));
+    assert!(matches!(results[1].score(), Score::Centipawns(_)));
+}
+
+#[test]
+fn score_display() {
+    assert_eq!(Score::Centipawns(35).to_string(), "0.35");
+    assert_eq!(Score::Centipawns(-120).to_string(), "-1.20");
+    assert_eq!(Score::Mate(3).to_string(), "#3");
+    assert_eq!(Score::Mate(-2).to_string(), "#-2");
+    assert_eq!(Score::Centipawns(0).win_percent(), 50.0);
+}
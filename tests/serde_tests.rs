@@ -0,0 +1,150 @@
+#![cfg(feature = "serde")]
+
+use chess::board::{self, Action, Board, CastlingAvailability, ChessMove, MoveStatus, Square};
+use chess::piece::{Color, Kind, Piece};
+use chess::search;
+use chess::variant::{ThreeCheck, Variant};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize)]
+struct Record {
+    #[serde(with = "board::structured")]
+    board: Board,
+}
+
+#[test]
+fn square() {
+    let json = serde_json::to_string(&Square::new(5, 4)).expect("This should serialize");
+    assert_eq!(json, "\"e4\"");
+    assert_eq!(serde_json::from_str::<Square>("\"h8\"").expect("This should deserialize"), Square::new(8, 8));
+
+    assert!(serde_json::from_str::<Square>("\"i1\"").is_err());
+    assert!(serde_json::from_str::<Square>("\"e44\"").is_err());
+}
+
+#[test]
+fn square_from_str() {
+    assert_eq!("a1".parse::<Square>(), Ok(Square::new(1, 1)));
+    assert!("a".parse::<Square>().is_err());
+}
+
+#[test]
+fn piece() {
+    let json = serde_json::to_string(&Piece::new(Color::White, Kind::Knight)).expect("This should serialize");
+    assert_eq!(json, "{\"color\":\"white\",\"kind\":\"knight\"}");
+    assert_eq!(
+        serde_json::from_str::<Piece>(&json).expect("This should deserialize"),
+        Piece::new(Color::White, Kind::Knight)
+    );
+}
+
+#[test]
+fn chess_move() {
+    let chess_move = ChessMove::new(
+        Piece::new(Color::White, Kind::Pawn),
+        Square::new(2, 7),
+        Action::CapturePromotion(Kind::Queen),
+        Square::new(1, 8),
+        MoveStatus::Check,
+    );
+
+    let json = serde_json::to_string(&chess_move).expect("This should serialize");
+    assert_eq!(
+        json,
+        "{\"piece\":{\"color\":\"white\",\"kind\":\"pawn\"},\"origin_square\":\"b7\",\
+         \"action\":{\"capture_promotion\":\"queen\"},\"destination_square\":\"a8\",\"status\":\"check\"}"
+    );
+
+    let deserialized: ChessMove = serde_json::from_str(&json).expect("This should deserialize");
+    assert!(search::is_same_move(&deserialized, &chess_move));
+    assert_eq!(deserialized.status(), &MoveStatus::Check);
+}
+
+#[test]
+fn castling_availability() {
+    let castling_availability = CastlingAvailability::new(true, false, false, true);
+    let json = serde_json::to_string(&castling_availability).expect("This should serialize");
+
+    assert_eq!(
+        serde_json::from_str::<CastlingAvailability>(&json).expect("This should deserialize"),
+        castling_availability
+    );
+}
+
+#[test]
+fn board_as_forsyth_edwards_notation() {
+    // cspell:disable-next
+    let record = "rnbqkbnr/pppp1ppp/8/4p3/4P3/8/PPPP1PPP/RNBQKBNR w KQkq e6 0 2";
+    let board = Board::forsyth_edwards_notation(record).expect("This should be a valid record");
+
+    let json = serde_json::to_string(&board).expect("This should serialize");
+    assert_eq!(json, format!("\"{}\"", record));
+
+    let deserialized: Board = serde_json::from_str(&json).expect("This should deserialize");
+    assert_eq!(deserialized.to_forsyth_edwards_notation(), record);
+
+    assert!(serde_json::from_str::<Board>("\"not a position\"").is_err());
+}
+
+#[test]
+fn structured_board() {
+    // cspell:disable-next
+    let record = "4k3/8/8/8/4P3/8/8/R3K3 b Q e3 0 1";
+    let board = Board::forsyth_edwards_notation(record).expect("This should be a valid record");
+
+    let json = serde_json::to_string(&Record { board }).expect("This should serialize");
+    assert!(json.starts_with(
+        "{\"board\":{\"piece_placement\":{\"e8\":{\"color\":\"black\",\"kind\":\"king\"},\
+         \"e4\":{\"color\":\"white\",\"kind\":\"pawn\"},"
+    ));
+    assert!(json.contains("\"active_color\":\"black\""));
+    assert!(json.contains("\"en_passant_target\":\"e3\""));
+
+    let deserialized: Record = serde_json::from_str(&json).expect("This should deserialize");
+    assert_eq!(deserialized.board.to_forsyth_edwards_notation(), record);
+}
+
+#[test]
+fn invalid_castling_availability() {
+    let json = "{\"white_kingside\":42,\"white_queenside\":null,\"black_kingside\":null,\"black_queenside\":null}";
+    assert!(serde_json::from_str::<CastlingAvailability>(json).is_err());
+
+    // The rook on h1 is missing for castling kingside
+    let json = "{\"board\":{\"piece_placement\":{\"e8\":{\"color\":\"black\",\"kind\":\"king\"},\
+                \"e1\":{\"color\":\"white\",\"kind\":\"king\"}},\"active_color\":\"white\",\
+                \"castling_availability\":{\"white_kingside\":8,\"white_queenside\":null,\
+                \"black_kingside\":null,\"black_queenside\":null},\
+                \"en_passant_target\":null,\"halfmove_clock\":0,\"fullmove_number\":1}}";
+    assert!(serde_json::from_str::<Record>(json).is_err());
+
+    let json = json.replace("\"e1\":", "\"h1\":{\"color\":\"white\",\"kind\":\"rook\"},\"e1\":");
+    let record: Record = serde_json::from_str(&json).expect("This should deserialize");
+    // cspell:disable-next
+    assert_eq!(record.board.to_forsyth_edwards_notation(), "4k3/8/8/8/8/8/8/4K2R w K - 0 1");
+}
+
+#[test]
+fn structured_variant_boards() {
+    // Pocket and promoted pieces of Crazyhouse - cspell:disable-next
+    let record = "4k3/8/8/8/8/8/8/4K2Q~[Nq] w - - 0 1";
+    let board = Board::forsyth_edwards_notation(record).expect("This should be a valid record");
+    let json = serde_json::to_string(&Record { board }).expect("This should serialize");
+    assert!(json.contains("\"promoted_squares\":[\"h1\"]"));
+    let deserialized: Record = serde_json::from_str(&json).expect("This should deserialize");
+    assert_eq!(deserialized.board.to_forsyth_edwards_notation(), record);
+
+    // Remaining checks of Three-check
+    let board = ThreeCheck.starting_position();
+    let json = serde_json::to_string(&Record { board: board.clone() }).expect("This should serialize");
+    let deserialized: Record = serde_json::from_str(&json).expect("This should deserialize");
+    assert_eq!(deserialized.board.remaining_checks(), board.remaining_checks());
+
+    assert!(json.contains("\"remaining_checks\":{\"white\":3,\"black\":3}"));
+
+    // Kings cannot be in a pocket
+    let json = json.replace(
+        "\"remaining_checks\":{\"white\":3,\"black\":3}",
+        "\"pocket\":[{\"color\":\"white\",\"kind\":\"king\"}]",
+    );
+    assert!(serde_json::from_str::<Record>(&json).is_err());
+}
@@ -0,0 +1,82 @@
+use chess::board::{Board, ChessMove};
+use chess::piece::Color;
+
+fn board(record: &str) -> Board {
+    Board::forsyth_edwards_notation(record).expect("This should be a valid record")
+}
+
+fn chess_move(board: &Board, notation: &str) -> ChessMove {
+    board
+        .parse_standard_algebraic_notation(notation)
+        .expect("This should be a legal move")
+}
+
+fn assert_see(record: &str, notation: &str, expected: f32) {
+    let board = board(record);
+    let see = board.see(&chess_move(&board, notation));
+    assert!((see - expected).abs() < 0.001, "{} in {}: {} != {}", notation, record, see, expected);
+}
+
+#[test]
+fn simple_exchanges() {
+    // Undefended pawn
+    // cspell:disable-next
+    assert_see("1k1r4/1pp4p/p7/4p3/8/P5P1/1PP4P/2K1R3 w - - 0 1", "Rxe5", 1.0);
+    // Pawn defended by a pawn
+    // cspell:disable-next
+    assert_see("1k1r4/1pp4p/p4p2/4p3/8/P5P1/1PP4P/2K1R3 w - - 0 1", "Rxe5", 1.0 - 5.63);
+    // Quiet moves to an attacked and a safe square
+    // cspell:disable-next
+    assert_see("4k3/8/8/8/2p5/8/8/2N1K3 w - - 0 1", "Nb3", -3.05);
+    // cspell:disable-next
+    assert_see("4k3/8/8/8/2p5/8/8/2N1K3 w - - 0 1", "Ne2", 0.0);
+}
+
+#[test]
+fn xray_attackers() {
+    // Rook behind the queen recaptures after the queen is taken
+    // cspell:disable-next
+    assert_see("1k1r3q/1ppn3p/p4b2/4p3/8/P2N2P1/1PP1R1BP/2K1Q3 w - - 0 1", "Nxe5", 1.0 - 3.05);
+    // Doubled rooks win the pawn defended by one rook
+    // cspell:disable-next
+    assert_see("4r1k1/8/8/4p3/8/8/4R3/4R1K1 w - - 0 1", "Rxe5", 1.0);
+}
+
+#[test]
+fn promotions_and_en_passant() {
+    // cspell:disable-next
+    assert_see("3r2k1/4P3/8/8/8/8/8/6K1 w - - 0 1", "exd8=Q", 5.63 + 9.5 - 1.0);
+    // cspell:disable-next
+    assert_see("4r1k1/3P4/8/8/8/8/8/6K1 w - - 0 1", "d8=Q", 9.5 - 1.0 - 9.5);
+    // cspell:disable-next
+    assert_see("4k3/8/8/3pP3/8/8/8/4K3 w - d6 0 1", "exd6", 1.0);
+}
+
+#[test]
+fn king_captures() {
+    // The king cannot recapture a defended piece
+    // cspell:disable-next
+    assert_see("3rk3/8/8/7q/8/8/8/3NK3 b - - 0 1", "Rxd1", 3.05);
+    // cspell:disable-next
+    assert_see("3rk3/8/8/8/8/8/8/3NK3 b - - 0 1", "Rxd1", 3.05 - 5.63);
+}
+
+#[test]
+fn see_ge() {
+    // cspell:disable-next
+    let board = board("1k1r4/1pp4p/p4p2/4p3/8/P5P1/1PP4P/2K1R3 w - - 0 1");
+    let capture = chess_move(&board, "Rxe5");
+
+    assert!(board.see_ge(&capture, -5.0));
+    assert!(!board.see_ge(&capture, 0.0));
+}
+
+#[test]
+fn hanging_pieces() {
+    // Knight on c6 is undefended, the bishop on e7 is defended by the king and the rook on a8 is not attacked
+    // cspell:disable-next
+    let board = board("r3k3/4b3/2n5/8/8/5B2/8/1R2K3 w - - 0 1");
+
+    assert_eq!(board.hanging_pieces(&Color::Black), [chess::board::Square::new(3, 6)]);
+    assert!(board.hanging_pieces(&Color::White).is_empty());
+}
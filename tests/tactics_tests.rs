@@ -0,0 +1,97 @@
+use chess::board::{Board, Square};
+use chess::piece::Color;
+use chess::tactics::{self, Motif, Tactic};
+
+fn board(record: &str) -> Board {
+    Board::forsyth_edwards_notation(record).expect("This should be a valid record")
+}
+
+fn after_move(record: &str, notation: &str) -> Vec<Tactic> {
+    let board = board(record);
+    let chess_move = board
+        .parse_standard_algebraic_notation(notation)
+        .expect("This should be a legal move");
+
+    tactics::find_after_move(&board, &chess_move).expect("This should be a legal move")
+}
+
+fn squares(squares: &[(i8, i8)]) -> Vec<Square> {
+    squares
+        .iter()
+        .map(|(file, rank)| Square::new(*file, *rank))
+        .collect()
+}
+
+#[test]
+fn forks() {
+    // cspell:disable-next
+    let tactics = after_move("q3k3/8/8/1N6/8/8/8/4K3 w - - 0 1", "Nc7");
+    assert!(tactics.contains(&Tactic::new(Motif::RoyalFork, squares(&[(3, 7), (1, 8), (5, 8)]))));
+
+    // cspell:disable-next
+    let tactics = after_move("r3r1k1/8/8/1N6/8/8/8/6K1 w - - 0 1", "Nc7");
+    assert!(tactics.contains(&Tactic::new(Motif::Fork, squares(&[(3, 7), (1, 8), (5, 8)]))));
+
+    // No fork if the forking piece can be captured
+    // cspell:disable-next
+    let tactics = after_move("r2qr1k1/8/8/1N6/8/8/8/6K1 w - - 0 1", "Nc7");
+    assert!(!tactics.iter().any(|tactic| *tactic.motif() == Motif::Fork));
+}
+
+#[test]
+fn skewers_and_pins() {
+    // cspell:disable-next
+    let tactics = after_move("6r1/8/8/3k4/8/8/8/1B5K w - - 0 1", "Ba2");
+    assert!(tactics.contains(&Tactic::new(Motif::Skewer, squares(&[(1, 2), (4, 5), (7, 8)]))));
+
+    // cspell:disable-next
+    let tactics = after_move("4k3/3n4/8/8/8/8/8/4KB2 w - - 0 1", "Bb5");
+    assert!(tactics.contains(&Tactic::new(Motif::Pin, squares(&[(2, 5), (4, 7), (5, 8)]))));
+}
+
+#[test]
+fn discovered_attacks_and_checks() {
+    // cspell:disable-next
+    let tactics = after_move("4k3/8/8/8/4N3/8/8/4R1K1 w - - 0 1", "Nc3");
+    assert!(tactics.contains(&Tactic::new(Motif::DiscoveredCheck, squares(&[(5, 1), (5, 8)]))));
+    assert!(!tactics
+        .iter()
+        .any(|tactic| *tactic.motif() == Motif::DoubleCheck));
+
+    // cspell:disable-next
+    let tactics = after_move("4k3/8/8/8/4N3/8/8/4R1K1 w - - 0 1", "Nf6");
+    assert!(tactics.contains(&Tactic::new(Motif::DiscoveredCheck, squares(&[(5, 1), (5, 8)]))));
+    assert!(tactics.contains(&Tactic::new(Motif::DoubleCheck, squares(&[(5, 8), (5, 1), (6, 6)]))));
+
+    // cspell:disable-next
+    let tactics = after_move("4k3/8/8/7r/8/8/4N3/3B2K1 w - - 0 1", "Nc3");
+    assert!(tactics.contains(&Tactic::new(Motif::DiscoveredAttack, squares(&[(4, 1), (8, 5)]))));
+    assert!(tactics.contains(&Tactic::new(Motif::HangingPiece, squares(&[(8, 5)]))));
+}
+
+#[test]
+fn weak_pieces() {
+    // cspell:disable-next
+    let tactics = tactics::find(&board("4k3/8/8/3p4/8/8/8/3RK3 w - - 0 1"), &Color::White);
+    assert_eq!(tactics, [Tactic::new(Motif::HangingPiece, squares(&[(4, 5)]))]);
+
+    // Pawn attacked by the knight and the rook, but only defended by a pawn
+    // cspell:disable-next
+    let tactics = tactics::find(&board("4k3/8/4p3/3p4/8/2N5/8/3RK3 w - - 0 1"), &Color::White);
+    assert_eq!(tactics, [Tactic::new(Motif::UnderDefendedPiece, squares(&[(4, 5)]))]);
+
+    // cspell:disable-next
+    let tactics = tactics::find(&board("6k1/5ppp/8/8/8/8/8/R5K1 w - - 0 1"), &Color::White);
+    assert_eq!(tactics, [Tactic::new(Motif::BackRankWeakness, squares(&[(7, 8)]))]);
+
+    // Queen is the only defender of both the bishop and the knight
+    // cspell:disable-next
+    let tactics = tactics::find(&board("7k/4q2p/3b1n2/8/8/8/8/3R1RK1 w - - 0 1"), &Color::White);
+    assert_eq!(
+        tactics,
+        [Tactic::new(
+            Motif::OverloadedDefender,
+            squares(&[(5, 7), (4, 6), (6, 6)])
+        )]
+    );
+}
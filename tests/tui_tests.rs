@@ -0,0 +1,126 @@
+#![cfg(feature = "tui")]
+
+use chess::board::{Board, Square};
+use chess::game::Game;
+use chess::piece::{Color, Kind};
+use chess::tui::{self, App, Input};
+
+#[test]
+fn select_piece_and_move() {
+    let mut app = App::new(Game::new(), None, 1);
+    assert_eq!(app.cursor(), &Square::new(5, 2));
+
+    app.handle(Input::Select);
+    assert_eq!(app.selected(), Some(&Square::new(5, 2)));
+    let destinations = app.destinations();
+    assert_eq!(destinations.len(), 2);
+    assert!(destinations.contains(&Square::new(5, 4)));
+
+    app.handle(Input::Up);
+    app.handle(Input::Up);
+    app.handle(Input::Select);
+    assert_eq!(app.game().ply(), 1);
+    assert_eq!(app.selected(), None);
+    assert_eq!(app.last_move(), Some((Square::new(5, 2), Square::new(5, 4))));
+}
+
+#[test]
+fn only_pieces_of_player_to_move_are_selected() {
+    let mut app = App::new(Game::new(), None, 1);
+
+    app.handle(Input::Click(Square::new(5, 7)));
+    assert_eq!(app.selected(), None);
+    app.handle(Input::Click(Square::new(4, 4)));
+    assert_eq!(app.selected(), None);
+
+    app.handle(Input::Click(Square::new(7, 1)));
+    assert_eq!(app.selected(), Some(&Square::new(7, 1)));
+    app.handle(Input::Cancel);
+    assert_eq!(app.selected(), None);
+}
+
+#[test]
+fn promotion_picker() {
+    // cspell:disable-next
+    let board =
+        Board::forsyth_edwards_notation("4k3/1P6/8/8/8/8/8/4K3 w - - 0 1").expect("This should be a valid record");
+    let mut app = App::new(Game::from_position(board), None, 1);
+
+    app.handle(Input::Click(Square::new(2, 7)));
+    app.handle(Input::Click(Square::new(2, 8)));
+    let picker = app.promotion_picker().expect("This should open the picker");
+    assert_eq!(picker.kinds(), vec![Kind::Queen, Kind::Rook, Kind::Bishop, Kind::Knight]);
+
+    app.handle(Input::Right);
+    assert_eq!(app.promotion_picker().map(|picker| picker.index()), Some(1));
+    app.handle(Input::Char('n'));
+    assert!(app.promotion_picker().is_none());
+    assert_eq!(app.game().moves()[0].standard_algebraic_notation(), "b8=N");
+}
+
+#[test]
+fn flip_and_undo() {
+    let mut app = App::new(Game::new(), None, 1);
+
+    app.handle(Input::Char('f'));
+    assert!(app.is_flipped());
+    // Up on the screen is down the board when it is flipped
+    app.handle(Input::Up);
+    assert_eq!(app.cursor(), &Square::new(5, 1));
+
+    app.handle(Input::Click(Square::new(7, 1)));
+    app.handle(Input::Click(Square::new(6, 3)));
+    assert_eq!(app.game().ply(), 1);
+    app.handle(Input::Char('u'));
+    assert_eq!(app.game().ply(), 0);
+}
+
+#[test]
+fn engine_replies_and_evaluates() {
+    let mut app = App::new(Game::new(), Some(Color::Black), 1);
+    assert!(!app.is_flipped());
+
+    app.handle(Input::Click(Square::new(5, 2)));
+    app.handle(Input::Click(Square::new(5, 4)));
+    assert!(app.is_thinking());
+    app.think();
+    assert_eq!(app.game().ply(), 2);
+    app.think();
+    assert!(app.evaluation().is_some());
+    assert!(!app.is_thinking());
+
+    // Undo takes back the reply of the engine together with the move of the player
+    app.handle(Input::Char('u'));
+    assert_eq!(app.game().ply(), 0);
+}
+
+#[test]
+fn checked_king() {
+    // cspell:disable-next
+    let board =
+        Board::forsyth_edwards_notation("4k3/8/8/8/8/8/8/R3K3 w - - 0 1").expect("This should be a valid record");
+    let mut app = App::new(Game::from_position(board), None, 1);
+    assert_eq!(app.checked_king(), None);
+
+    app.handle(Input::Click(Square::new(1, 1)));
+    app.handle(Input::Click(Square::new(1, 8)));
+    assert_eq!(app.checked_king(), Some(Square::new(5, 8)));
+}
+
+#[test]
+fn square_at_screen_position() {
+    assert_eq!(tui::square_at(4, 1, false), Some(Square::new(1, 8)));
+    assert_eq!(tui::square_at(26, 8, false), Some(Square::new(8, 1)));
+    assert_eq!(tui::square_at(4, 1, true), Some(Square::new(8, 1)));
+    assert_eq!(tui::square_at(2, 1, false), None);
+    assert_eq!(tui::square_at(28, 1, false), None);
+}
+
+#[test]
+fn engine_searches_at_least_one_ply() {
+    let mut app = App::new(Game::new(), Some(Color::White), 0);
+    assert!(app.is_thinking());
+
+    app.think();
+    assert_eq!(app.game().ply(), 1);
+}
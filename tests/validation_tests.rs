@@ -0,0 +1,91 @@
+use chess::board::{Board, PositionError, Square};
+use chess::parser::{ParseError, ParseMode};
+use chess::piece::Color;
+
+// Parse a record without validation and return the validation result
+fn validate(record: &str) -> Result<(), PositionError> {
+    Board::forsyth_edwards_notation(record)
+        .expect("This should be a valid record")
+        .validate()
+}
+
+#[test]
+fn valid_positions() {
+    assert_eq!(Board::starting_position().validate(), Ok(()));
+
+    // cspell:disable-next
+    assert_eq!(validate("rnbqkbnr/ppp1p1pp/8/3pPp2/8/8/PPPP1PPP/RNBQKBNR w KQkq f6 0 3"), Ok(()));
+    assert_eq!(
+        Board::chess960_starting_position(0)
+            .expect("This should be a valid number")
+            .validate(),
+        Ok(())
+    );
+}
+
+#[test]
+fn kings() {
+    // cspell:disable-next
+    assert_eq!(validate("8/8/8/8/8/8/8/4K3 w - - 0 1"), Err(PositionError::MissingKing(Color::Black)));
+    // cspell:disable-next
+    assert_eq!(validate("4k3/8/8/8/8/8/8/3KK3 w - - 0 1"), Err(PositionError::TooManyKings(Color::White)));
+}
+
+#[test]
+fn pawns_and_pieces() {
+    // cspell:disable-next
+    assert_eq!(validate("4k2P/8/8/8/8/8/8/4K3 w - - 0 1"), Err(PositionError::PawnOnBackRank(Square::new(8, 8))));
+    // cspell:disable-next
+    assert_eq!(validate("4k3/pppppppp/p7/8/8/8/8/4K3 w - - 0 1"), Err(PositionError::TooManyPawns(Color::Black)));
+    // Three queens with all pawns still on the board - cspell:disable-next
+    assert_eq!(
+        validate("4k3/8/8/8/8/8/PPPPPPPP/QQ2K3 w - - 0 1"),
+        Err(PositionError::TooManyPromotedPieces(Color::White))
+    );
+}
+
+#[test]
+fn castling() {
+    // cspell:disable-next
+    assert_eq!(
+        validate("r3k2r/8/8/8/8/8/4K3/R6R w KQkq - 0 1"),
+        Err(PositionError::CastlingWithoutKing(Color::White))
+    );
+    // cspell:disable-next
+    assert_eq!(
+        validate("r3k3/8/8/8/8/8/8/R3K2R w KQkq - 0 1"),
+        Err(PositionError::CastlingWithoutRook(Color::Black, 8))
+    );
+}
+
+#[test]
+fn en_passant() {
+    // No black pawn on d5 - cspell:disable-next
+    assert_eq!(validate("4k3/8/8/8/8/8/8/4K3 w - d6 0 1"), Err(PositionError::InvalidEnPassant(Square::new(4, 6))));
+    // Target square on the wrong rank for white to move - cspell:disable-next
+    assert_eq!(
+        validate("4k3/8/8/8/3P4/8/8/4K3 w - d3 0 1"),
+        Err(PositionError::InvalidEnPassant(Square::new(4, 3)))
+    );
+}
+
+#[test]
+fn opponent_in_check() {
+    // cspell:disable-next
+    assert_eq!(validate("4k3/8/8/8/8/8/8/4K2R w - - 0 1"), Ok(()));
+    // cspell:disable-next
+    assert_eq!(validate("4k3/8/8/8/8/8/8/4R1K1 w - - 0 1"), Err(PositionError::OpponentInCheck(Color::Black)));
+}
+
+#[test]
+fn strict_parser() {
+    // cspell:disable-next
+    let record = "8/8/8/8/8/8/8/4K3 w - - 0 1";
+
+    assert!(Board::forsyth_edwards_notation_with_mode(record, &ParseMode::Lenient).is_ok());
+    assert_eq!(
+        Board::forsyth_edwards_notation_with_mode(record, &ParseMode::Strict)
+            .expect_err("This should be an invalid position"),
+        ParseError::InvalidPosition(PositionError::MissingKing(Color::Black))
+    );
+}
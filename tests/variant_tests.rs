@@ -0,0 +1,190 @@
+use chess::board::{Action, Outcome};
+use chess::piece::Color;
+use chess::variant::{self, Antichess, Atomic, Horde, KingOfTheHill, RacingKings, ThreeCheck, Variant};
+
+// Make the move with the given notation in long algebraic notation
+fn make_move(variant: &dyn Variant, board: &mut chess::board::Board, notation: &str) {
+    let chess_move = variant
+        .legal_moves(board)
+        .into_iter()
+        .find(|chess_move| chess_move.to_string() == notation)
+        .expect("This should be a legal move");
+
+    variant
+        .make_move(board, chess_move)
+        .expect("This should be a valid move");
+}
+
+#[test]
+fn perft_starting_positions() {
+    let variants: [(&dyn Variant, [u64; 3]); 6] = [
+        (&ThreeCheck, [20, 400, 8_902]),
+        (&KingOfTheHill, [20, 400, 8_902]),
+        (&Antichess, [20, 400, 8_067]),
+        (&Atomic, [20, 400, 8_902]),
+        (&Horde, [8, 128, 1_274]),
+        (&RacingKings, [21, 421, 11_264]),
+    ];
+
+    for (variant, node_counts) in variants {
+        let board = variant.starting_position();
+
+        for (depth, node_count) in (1..).zip(node_counts) {
+            assert_eq!(variant.perft(&board, depth), node_count, "{} at depth {}", variant.name(), depth);
+        }
+    }
+}
+
+#[test]
+fn three_check() {
+    // White has given two checks already - cspell:disable-next
+    let mut board = ThreeCheck
+        .parse_forsyth_edwards_notation("4k3/8/8/8/8/8/8/4K2R w - - 0 1 +2+0")
+        .expect("This should be a valid record");
+    assert_eq!(ThreeCheck.to_forsyth_edwards_notation(&board), "4k3/8/8/8/8/8/8/4K2R w - - 1+3 0 1");
+    assert_eq!(ThreeCheck.outcome(&board), None);
+
+    // Third check wins the game
+    make_move(&ThreeCheck, &mut board, "Rh1h8");
+    assert_eq!(ThreeCheck.outcome(&board), Some(Outcome::Win(Color::White)));
+    assert!(ThreeCheck.legal_moves(&board).is_empty());
+}
+
+#[test]
+fn king_of_the_hill() {
+    // cspell:disable-next
+    let mut board = KingOfTheHill
+        .parse_forsyth_edwards_notation("4k3/8/8/8/8/4K3/8/8 w - - 0 1")
+        .expect("This should be a valid record");
+    assert_eq!(KingOfTheHill.outcome(&board), None);
+
+    // King reaching the center wins the game
+    make_move(&KingOfTheHill, &mut board, "Ke3e4");
+    assert_eq!(KingOfTheHill.outcome(&board), Some(Outcome::Win(Color::White)));
+}
+
+#[test]
+fn antichess() {
+    // Capturing the pawn with the bishop is mandatory - cspell:disable-next
+    let board = Antichess
+        .parse_forsyth_edwards_notation("rnbqkbnr/p1pppppp/8/1p6/8/4P3/PPPP1PPP/RNBQKBNR w - - 0 2")
+        .expect("This should be a valid record");
+    let legal_moves = Antichess.legal_moves(&board);
+    assert_eq!(legal_moves.len(), 1);
+    assert_eq!(legal_moves[0].to_string(), "Bf1xb5");
+
+    // Pawns can promote to a king - cspell:disable-next
+    let board = Antichess
+        .parse_forsyth_edwards_notation("8/P7/8/8/8/8/8/7k w - - 0 1")
+        .expect("This should be a valid record");
+    assert_eq!(Antichess.legal_moves(&board).len(), 5);
+
+    // Player without any pieces left wins - cspell:disable-next
+    let board = Antichess
+        .parse_forsyth_edwards_notation("8/8/8/8/8/8/8/7k w - - 0 1")
+        .expect("This should be a valid record");
+    assert_eq!(Antichess.outcome(&board), Some(Outcome::Win(Color::White)));
+}
+
+#[test]
+fn atomic() {
+    // Capture blows up both the knight and the queen - cspell:disable-next
+    let mut board = Atomic
+        .parse_forsyth_edwards_notation("4k3/8/8/3q4/8/4N3/8/4K3 w - - 0 1")
+        .expect("This should be a valid record");
+    make_move(&Atomic, &mut board, "Ne3xd5");
+    assert_eq!(board.pieces().len(), 2);
+
+    // Capture next to the king blows up the king - cspell:disable-next
+    let mut board = Atomic
+        .parse_forsyth_edwards_notation("4k3/3q4/8/8/8/8/8/3QK3 w - - 0 1")
+        .expect("This should be a valid record");
+    make_move(&Atomic, &mut board, "Qd1xd7");
+    assert_eq!(Atomic.outcome(&board), Some(Outcome::Win(Color::White)));
+
+    // Kings cannot capture and cannot be checked while next to each other - cspell:disable-next
+    let board = Atomic
+        .parse_forsyth_edwards_notation("4q3/8/8/8/8/3k4/4K3/R7 w - - 0 1")
+        .expect("This should be a valid record");
+    let legal_moves = Atomic.legal_moves(&board);
+    assert!(legal_moves
+        .iter()
+        .all(|chess_move| !chess_move.is_capture()));
+    assert!(legal_moves
+        .iter()
+        .any(|chess_move| chess_move.to_string() == "Ra1a2"));
+    assert_eq!(Atomic.outcome(&board), None);
+}
+
+#[test]
+fn horde() {
+    // Pawns on the first rank can move two squares forward - cspell:disable-next
+    let board = Horde
+        .parse_forsyth_edwards_notation("4k3/8/8/8/8/8/8/4P3 w - - 0 1")
+        .expect("This should be a valid record");
+    assert_eq!(Horde.legal_moves(&board).len(), 2);
+
+    // But they cannot be captured en passant afterwards - cspell:disable-next
+    let mut board = Horde
+        .parse_forsyth_edwards_notation("4k3/8/8/8/8/3p4/8/4P3 w - - 0 1")
+        .expect("This should be a valid record");
+    make_move(&Horde, &mut board, "e1e3");
+    assert_eq!(board.en_passant_target(), None);
+    assert!(Horde
+        .legal_moves(&board)
+        .iter()
+        .all(|chess_move| chess_move.action() != &Action::EnPassant));
+
+    // Black wins by capturing all white pieces - cspell:disable-next
+    let board = Horde
+        .parse_forsyth_edwards_notation("4k3/8/8/8/8/8/8/8 w - - 0 1")
+        .expect("This should be a valid record");
+    assert_eq!(Horde.outcome(&board), Some(Outcome::Win(Color::Black)));
+}
+
+#[test]
+fn racing_kings() {
+    // Black king cannot reach the eighth rank anymore - cspell:disable-next
+    let board = RacingKings
+        .parse_forsyth_edwards_notation("K7/8/k7/8/8/8/8/8 b - - 0 1")
+        .expect("This should be a valid record");
+    assert_eq!(RacingKings.outcome(&board), Some(Outcome::Win(Color::White)));
+
+    // Black king can still reach the eighth rank for a draw - cspell:disable-next
+    let mut board = RacingKings
+        .parse_forsyth_edwards_notation("K7/7k/8/8/8/8/8/8 b - - 0 1")
+        .expect("This should be a valid record");
+    assert_eq!(RacingKings.outcome(&board), None);
+    make_move(&RacingKings, &mut board, "Kh7h8");
+    assert_eq!(RacingKings.outcome(&board), Some(Outcome::Draw));
+
+    // Giving check is not allowed - cspell:disable-next
+    let board = RacingKings
+        .parse_forsyth_edwards_notation("8/8/8/k7/8/8/8/1R5K w - - 0 1")
+        .expect("This should be a valid record");
+    assert!(RacingKings
+        .legal_moves(&board)
+        .iter()
+        .all(|chess_move| chess_move.to_string() != "Rb1a1"));
+}
+
+#[test]
+fn variant_from_name() {
+    let names = [
+        "Standard",
+        "Three-check",
+        "King of the Hill",
+        "Antichess",
+        "Atomic",
+        "Horde",
+        "Racing Kings",
+        "Crazyhouse",
+    ];
+
+    for name in names {
+        let variant = variant::from_name(name).expect("This should be a known variant");
+        assert_eq!(variant.name(), name);
+    }
+
+    assert!(variant::from_name("Shogi").is_none());
+}